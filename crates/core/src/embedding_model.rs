@@ -0,0 +1,31 @@
+use crate::types::usage::Usage;
+use ai_providers::utils::errors::ProviderError;
+use async_trait::async_trait;
+
+/// The embeddings for one [`EmbeddingModel::embed`] call, in input order.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct EmbeddingResult {
+    pub embeddings: Vec<Vec<f32>>,
+    pub usage: Usage,
+}
+
+impl EmbeddingResult {
+    pub fn new(embeddings: Vec<Vec<f32>>, usage: Usage) -> Self {
+        Self { embeddings, usage }
+    }
+
+    pub fn first(&self) -> Option<&[f32]> {
+        self.embeddings.first().map(Vec::as_slice)
+    }
+}
+
+/// A text embedding model behind any provider.
+///
+/// Implementations are expected to split large inputs into as many provider calls
+/// as needed, so callers can pass any number of values.
+#[async_trait]
+pub trait EmbeddingModel: Send + Sync {
+    fn provider(&self) -> &str;
+    fn model_id(&self) -> &str;
+    async fn embed(&self, values: &[String]) -> Result<EmbeddingResult, ProviderError>;
+}
@@ -0,0 +1,325 @@
+use crate::generate_text::{GenerateTextOptions, Prompt};
+use crate::language_model::LanguageModel;
+use crate::types::{
+    finish_reason::FinishReason, message::Message, response_format::ResponseFormat,
+    result::GenerateTextResult, usage::Usage,
+};
+use ai_providers::utils::errors::ProviderError;
+use ai_providers::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum GenerateObjectError {
+    Provider(ProviderError),
+    /// The model declined to answer.
+    Refusal(String),
+    /// The output was cut off, for example by `max_output_tokens` or a content filter.
+    Incomplete {
+        finish_reason: FinishReason,
+        text: String,
+    },
+    /// The output is not valid JSON.
+    InvalidJson {
+        text: String,
+        message: String,
+    },
+    /// The output is valid JSON but does not match the target type.
+    SchemaMismatch {
+        value: Value,
+        message: String,
+    },
+}
+
+impl GenerateObjectError {
+    /// Whether re-prompting the model with the error could fix the output.
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            GenerateObjectError::InvalidJson { .. } | GenerateObjectError::SchemaMismatch { .. }
+        )
+    }
+}
+
+impl From<ProviderError> for GenerateObjectError {
+    fn from(error: ProviderError) -> Self {
+        GenerateObjectError::Provider(error)
+    }
+}
+
+impl fmt::Display for GenerateObjectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateObjectError::Provider(error) => write!(f, "{}", error),
+            GenerateObjectError::Refusal(refusal) => {
+                write!(f, "The model refused to answer: {}", refusal)
+            }
+            GenerateObjectError::Incomplete { finish_reason, .. } => {
+                write!(f, "The output is incomplete: {:?}", finish_reason)
+            }
+            GenerateObjectError::InvalidJson { message, .. } => {
+                write!(f, "The output is not valid JSON: {}", message)
+            }
+            GenerateObjectError::SchemaMismatch { message, .. } => {
+                write!(f, "The output does not match the schema: {}", message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GenerateObjectError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GenerateObjectError::Provider(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GenerateObjectOptions {
+    pub settings: GenerateTextOptions,
+    pub schema_name: Option<String>,
+    pub schema_description: Option<String>,
+    pub max_repairs: usize,
+}
+
+impl GenerateObjectOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sampling settings and system prompt; any response format set here is replaced.
+    pub fn settings(mut self, value: GenerateTextOptions) -> Self {
+        self.settings = value;
+        self
+    }
+
+    pub fn schema_name(mut self, value: impl Into<String>) -> Self {
+        self.schema_name = Some(value.into());
+        self
+    }
+
+    pub fn schema_description(mut self, value: impl Into<String>) -> Self {
+        self.schema_description = Some(value.into());
+        self
+    }
+
+    /// How many times the model is re-prompted with the validation error when its
+    /// output is not valid JSON or does not match the schema. Defaults to none.
+    pub fn max_repairs(mut self, value: usize) -> Self {
+        self.max_repairs = value;
+        self
+    }
+
+    pub(crate) fn response_format<T: JsonSchema>(&self) -> ResponseFormat {
+        match ResponseFormat::from_type::<T>() {
+            ResponseFormat::Json {
+                schema,
+                name,
+                description,
+                strict,
+            } => ResponseFormat::Json {
+                schema,
+                name: self.schema_name.clone().or(name),
+                description: self.schema_description.clone().or(description),
+                strict,
+            },
+            format => format,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerateObjectResult<T> {
+    object: T,
+    usage: Usage,
+    response: GenerateTextResult,
+}
+
+impl<T> GenerateObjectResult<T> {
+    pub fn new(object: T, usage: Usage, response: GenerateTextResult) -> Self {
+        Self {
+            object,
+            usage,
+            response,
+        }
+    }
+
+    pub fn object(&self) -> &T {
+        &self.object
+    }
+
+    pub fn into_object(self) -> T {
+        self.object
+    }
+
+    /// Token usage summed over the initial call and any repairs.
+    pub fn usage(&self) -> &Usage {
+        &self.usage
+    }
+
+    /// The raw result of the call that produced the object.
+    pub fn response(&self) -> &GenerateTextResult {
+        &self.response
+    }
+
+    pub fn response_id(&self) -> Option<&str> {
+        self.response.response_id()
+    }
+}
+
+/// Checks a finished generation and deserializes its output into `T`.
+pub fn parse_object<T: DeserializeOwned>(
+    result: &GenerateTextResult,
+) -> Result<T, GenerateObjectError> {
+    if let Some(refusal) = result.refusal() {
+        return Err(GenerateObjectError::Refusal(refusal.to_string()));
+    }
+
+    let text = result.text();
+
+    match result.finish_reason() {
+        FinishReason::Stop | FinishReason::Unknown => {}
+        finish_reason => {
+            return Err(GenerateObjectError::Incomplete {
+                finish_reason: finish_reason.clone(),
+                text,
+            })
+        }
+    }
+
+    let value: Value =
+        serde_json::from_str(&text).map_err(|e| GenerateObjectError::InvalidJson {
+            text: text.clone(),
+            message: e.to_string(),
+        })?;
+
+    serde_json::from_value(value.clone()).map_err(|e| GenerateObjectError::SchemaMismatch {
+        value,
+        message: e.to_string(),
+    })
+}
+
+/// Generates a value of type `T` using the JSON schema derived from it.
+///
+/// ```no_run
+/// # use ai_sdk::JsonSchema;
+/// #[derive(serde::Deserialize, JsonSchema)]
+/// struct Person {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// # async fn run(model: &ai_sdk::OpenAILanguageModel) -> Result<(), ai_sdk::GenerateObjectError> {
+/// let result = ai_sdk::generate_object::<Person>(
+///     model,
+///     "Alice is 30 years old.",
+///     ai_sdk::GenerateObjectOptions::new().max_repairs(1),
+/// )
+/// .await?;
+/// println!("{}", result.object().name);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn generate_object<T>(
+    model: &(impl LanguageModel + ?Sized),
+    prompt: impl Into<Prompt>,
+    options: GenerateObjectOptions,
+) -> Result<GenerateObjectResult<T>, GenerateObjectError>
+where
+    T: DeserializeOwned + JsonSchema,
+{
+    let mut request = options.settings.to_request(prompt.into());
+    request.response_format = Some(options.response_format::<T>());
+
+    let mut usage = Usage::default();
+    let mut repairs = 0;
+
+    loop {
+        let result = model.generate(&request).await?;
+        usage = usage + result.usage().clone();
+
+        match parse_object::<T>(&result) {
+            Ok(object) => return Ok(GenerateObjectResult::new(object, usage, result)),
+            Err(error) if error.is_repairable() && repairs < options.max_repairs => {
+                repairs += 1;
+                request.messages.push(result.to_message());
+                request.messages.push(Message::user(format!(
+                    "{}. Answer again with only the corrected JSON.",
+                    error
+                )));
+            }
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{message::Part, result::ResponseMetadata};
+    use serde::Deserialize;
+
+    #[allow(dead_code)]
+    #[derive(Debug, Deserialize, JsonSchema)]
+    struct Person {
+        name: String,
+        age: u32,
+    }
+
+    fn result(content: Vec<Part>, finish_reason: FinishReason) -> GenerateTextResult {
+        GenerateTextResult::new(
+            content,
+            finish_reason,
+            Usage::default(),
+            ResponseMetadata::new(),
+        )
+    }
+
+    #[test]
+    fn it_separates_output_failures() {
+        let refusal = result(
+            vec![Part::Refusal {
+                text: "No.".to_string(),
+            }],
+            FinishReason::Stop,
+        );
+        assert!(matches!(
+            parse_object::<Person>(&refusal),
+            Err(GenerateObjectError::Refusal(text)) if text == "No."
+        ));
+
+        let truncated = result(vec![Part::text("{\"name\":")], FinishReason::Length);
+        assert!(matches!(
+            parse_object::<Person>(&truncated),
+            Err(GenerateObjectError::Incomplete {
+                finish_reason: FinishReason::Length,
+                ..
+            })
+        ));
+
+        let invalid = result(vec![Part::text("not json")], FinishReason::Stop);
+        assert!(matches!(
+            parse_object::<Person>(&invalid),
+            Err(GenerateObjectError::InvalidJson { .. })
+        ));
+
+        let mismatch = result(vec![Part::text("{\"name\":\"Alice\"}")], FinishReason::Stop);
+        assert!(matches!(
+            parse_object::<Person>(&mismatch),
+            Err(GenerateObjectError::SchemaMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn it_parses_valid_output() {
+        let valid = result(
+            vec![Part::text("{\"name\":\"Alice\",\"age\":30}")],
+            FinishReason::Stop,
+        );
+
+        assert_eq!(parse_object::<Person>(&valid).unwrap().age, 30);
+    }
+}
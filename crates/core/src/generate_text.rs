@@ -0,0 +1,159 @@
+use crate::language_model::{LanguageModel, LanguageModelRequest};
+use crate::types::{
+    message::Message,
+    response_format::ResponseFormat,
+    result::GenerateTextResult,
+    tool::{ToolChoice, ToolDefinition},
+};
+use ai_providers::utils::errors::ProviderError;
+
+/// The input of a generation call: either a single user prompt or a full conversation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Prompt {
+    Text(String),
+    Messages(Vec<Message>),
+}
+
+impl Prompt {
+    pub fn into_messages(self) -> Vec<Message> {
+        match self {
+            Prompt::Text(text) => vec![Message::user(text)],
+            Prompt::Messages(messages) => messages,
+        }
+    }
+}
+
+impl From<&str> for Prompt {
+    fn from(value: &str) -> Self {
+        Prompt::Text(value.to_string())
+    }
+}
+
+impl From<String> for Prompt {
+    fn from(value: String) -> Self {
+        Prompt::Text(value)
+    }
+}
+
+impl From<Message> for Prompt {
+    fn from(value: Message) -> Self {
+        Prompt::Messages(vec![value])
+    }
+}
+
+impl From<Vec<Message>> for Prompt {
+    fn from(value: Vec<Message>) -> Self {
+        Prompt::Messages(value)
+    }
+}
+
+/// Settings shared by [`generate_text`] and [`stream_text`](crate::stream_text).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GenerateTextOptions {
+    pub system: Option<String>,
+    pub tools: Vec<ToolDefinition>,
+    pub tool_choice: Option<ToolChoice>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub max_output_tokens: Option<usize>,
+    pub response_format: Option<ResponseFormat>,
+}
+
+impl GenerateTextOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn system(mut self, value: impl Into<String>) -> Self {
+        self.system = Some(value.into());
+        self
+    }
+
+    pub fn add_tool(mut self, value: ToolDefinition) -> Self {
+        self.tools.push(value);
+        self
+    }
+
+    pub fn tool_choice(mut self, value: ToolChoice) -> Self {
+        self.tool_choice = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn max_output_tokens(mut self, value: usize) -> Self {
+        self.max_output_tokens = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    /// Builds the model request, putting the system prompt ahead of the conversation.
+    pub fn to_request(&self, prompt: Prompt) -> LanguageModelRequest {
+        let mut messages = vec![];
+        if let Some(system) = &self.system {
+            messages.push(Message::system(system.clone()));
+        }
+        messages.extend(prompt.into_messages());
+
+        LanguageModelRequest {
+            messages,
+            tools: self.tools.clone(),
+            tool_choice: self.tool_choice.clone(),
+            temperature: self.temperature,
+            top_p: self.top_p,
+            max_output_tokens: self.max_output_tokens,
+            response_format: self.response_format.clone(),
+        }
+    }
+}
+
+/// Generates text with any [`LanguageModel`].
+///
+/// ```no_run
+/// # async fn run(model: &ai_sdk::OpenAILanguageModel) -> Result<(), ai_sdk::ProviderError> {
+/// let result = ai_sdk::generate_text(model, "Say hi", ai_sdk::GenerateTextOptions::new()).await?;
+/// println!("{}", result.text());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn generate_text<M>(
+    model: &M,
+    prompt: impl Into<Prompt>,
+    options: GenerateTextOptions,
+) -> Result<GenerateTextResult, ProviderError>
+where
+    M: LanguageModel + ?Sized,
+{
+    model.generate(&options.to_request(prompt.into())).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::message::Role;
+
+    #[test]
+    fn it_puts_the_system_prompt_first() {
+        let request = GenerateTextOptions::new()
+            .system("Be brief.")
+            .temperature(0.2)
+            .to_request("Hello".into());
+
+        assert_eq!(request.messages.len(), 2);
+        assert_eq!(request.messages[0].role, Role::System);
+        assert_eq!(request.messages[1].text(), "Hello");
+        assert_eq!(request.temperature, Some(0.2));
+    }
+}
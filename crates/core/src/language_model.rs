@@ -0,0 +1,80 @@
+use crate::types::{
+    message::Message,
+    response_format::ResponseFormat,
+    result::GenerateTextResult,
+    stream_part::StreamPart,
+    tool::{ToolChoice, ToolDefinition},
+};
+use ai_providers::utils::errors::ProviderError;
+use async_trait::async_trait;
+use futures::Stream;
+use std::pin::Pin;
+
+pub type StreamParts = Pin<Box<dyn Stream<Item = Result<StreamPart, ProviderError>> + Send>>;
+
+/// Everything a [`LanguageModel`] needs for one call, independent of any provider.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LanguageModelRequest {
+    pub messages: Vec<Message>,
+    pub tools: Vec<ToolDefinition>,
+    pub tool_choice: Option<ToolChoice>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+    pub max_output_tokens: Option<usize>,
+    pub response_format: Option<ResponseFormat>,
+}
+
+impl LanguageModelRequest {
+    pub fn new(messages: Vec<Message>) -> Self {
+        Self {
+            messages,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_tool(mut self, value: ToolDefinition) -> Self {
+        self.tools.push(value);
+        self
+    }
+
+    pub fn tool_choice(mut self, value: ToolChoice) -> Self {
+        self.tool_choice = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn max_output_tokens(mut self, value: usize) -> Self {
+        self.max_output_tokens = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+}
+
+/// A chat-capable model behind any provider.
+///
+/// Implementations translate [`LanguageModelRequest`] into their provider's wire
+/// format and map the response back into a [`GenerateTextResult`], so application
+/// code never has to touch provider-specific types.
+#[async_trait]
+pub trait LanguageModel: Send + Sync {
+    fn provider(&self) -> &str;
+    fn model_id(&self) -> &str;
+    async fn generate(
+        &self,
+        request: &LanguageModelRequest,
+    ) -> Result<GenerateTextResult, ProviderError>;
+    async fn stream(&self, request: &LanguageModelRequest) -> Result<StreamParts, ProviderError>;
+}
@@ -1 +1,40 @@
+pub use ai_providers::utils::errors::ProviderError;
+pub use ai_providers::{schemars, JsonSchema};
+pub use embedding_model::{EmbeddingModel, EmbeddingResult};
+pub use generate_object::{
+    generate_object, GenerateObjectError, GenerateObjectOptions, GenerateObjectResult,
+};
+pub use generate_text::{generate_text, GenerateTextOptions, Prompt};
+pub use language_model::{LanguageModel, LanguageModelRequest, StreamParts};
+pub use providers::openai::{OpenAIEmbeddingModel, OpenAILanguageModel};
+pub use stream_object::{stream_object, StreamObjectResult};
+pub use stream_text::{stream_text, StreamTextResult};
+pub use types::{
+    finish_reason::FinishReason,
+    message::{MediaSource, Message, Part, Role},
+    response_format::ResponseFormat,
+    result::{GenerateTextResult, ResponseMetadata},
+    stream_part::StreamPart,
+    tool::{ToolCall, ToolChoice, ToolDefinition, ToolResult},
+    usage::Usage,
+};
 
+pub mod embedding_model;
+pub mod generate_object;
+pub mod generate_text;
+pub mod language_model;
+pub mod partial_json;
+pub mod providers {
+    pub mod openai;
+}
+pub mod stream_object;
+pub mod stream_text;
+pub mod types {
+    pub mod finish_reason;
+    pub mod message;
+    pub mod response_format;
+    pub mod result;
+    pub mod stream_part;
+    pub mod tool;
+    pub mod usage;
+}
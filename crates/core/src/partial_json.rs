@@ -0,0 +1,365 @@
+use serde_json::{Map, Number, Value};
+
+/// Parses a prefix of a JSON document into a best-effort [`Value`].
+///
+/// Open strings, arrays and objects are closed, a trailing partial literal such as
+/// `tru` is completed and an object member or array element without a value yet,
+/// such as a lone `-`, is dropped. Returns `None` when nothing can be parsed yet or
+/// when the input is not a prefix of valid JSON.
+pub fn parse_partial_json(input: &str) -> Option<Value> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        position: 0,
+    };
+
+    let value = parser.parse_value()?;
+    parser.skip_whitespace();
+
+    if parser.position < parser.chars.len() {
+        return None;
+    }
+
+    Some(value)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    position: usize,
+}
+
+enum Escape {
+    Char(char),
+    /// The input ends before the escape, or the low half of a surrogate pair.
+    Incomplete,
+    Invalid,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).copied()
+    }
+
+    fn at_end(&self) -> bool {
+        self.position >= self.chars.len()
+    }
+
+    /// Skips the rest of the input if all that is left is the sign of a number
+    /// whose digits have not arrived yet, which has no value to keep.
+    fn skip_dangling_sign(&mut self) -> bool {
+        if self.chars[self.position..] == ['-'] {
+            self.position += 1;
+            return true;
+        }
+
+        false
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.position += 1;
+        }
+    }
+
+    fn parse_value(&mut self) -> Option<Value> {
+        self.skip_whitespace();
+
+        match self.peek()? {
+            '{' => self.parse_object(),
+            '[' => self.parse_array(),
+            '"' => self.parse_string().map(|(value, _)| Value::String(value)),
+            't' => self.parse_literal("true", Value::Bool(true)),
+            'f' => self.parse_literal("false", Value::Bool(false)),
+            'n' => self.parse_literal("null", Value::Null),
+            '-' | '0'..='9' => self.parse_number(),
+            _ => None,
+        }
+    }
+
+    fn parse_object(&mut self) -> Option<Value> {
+        self.position += 1;
+        let mut object = Map::new();
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                None => return Some(Value::Object(object)),
+                Some('}') => {
+                    self.position += 1;
+                    return Some(Value::Object(object));
+                }
+                Some('"') => {}
+                Some(_) => return None,
+            }
+
+            let (key, complete) = self.parse_string()?;
+            if !complete {
+                return Some(Value::Object(object));
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Some(Value::Object(object)),
+                Some(':') => self.position += 1,
+                Some(_) => return None,
+            }
+
+            self.skip_whitespace();
+            if self.at_end() || self.skip_dangling_sign() {
+                return Some(Value::Object(object));
+            }
+
+            let value = self.parse_value()?;
+            object.insert(key, value);
+
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Some(Value::Object(object)),
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Some(Value::Object(object));
+                }
+                Some(_) => return None,
+            }
+        }
+    }
+
+    fn parse_array(&mut self) -> Option<Value> {
+        self.position += 1;
+        let mut array = vec![];
+
+        loop {
+            self.skip_whitespace();
+
+            match self.peek() {
+                None => return Some(Value::Array(array)),
+                Some(']') => {
+                    self.position += 1;
+                    return Some(Value::Array(array));
+                }
+                Some(_) if self.skip_dangling_sign() => return Some(Value::Array(array)),
+                Some(_) => array.push(self.parse_value()?),
+            }
+
+            self.skip_whitespace();
+            match self.peek() {
+                None => return Some(Value::Array(array)),
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Some(Value::Array(array));
+                }
+                Some(_) => return None,
+            }
+        }
+    }
+
+    /// Parses a string, returning what has been received so far and whether the
+    /// closing quote was seen.
+    fn parse_string(&mut self) -> Option<(String, bool)> {
+        self.position += 1;
+        let mut value = String::new();
+
+        while let Some(c) = self.peek() {
+            self.position += 1;
+
+            match c {
+                '"' => return Some((value, true)),
+                '\\' => {
+                    let escaped = match self.peek() {
+                        None => return Some((value, false)),
+                        Some(escaped) => escaped,
+                    };
+                    self.position += 1;
+
+                    match escaped {
+                        '"' | '\\' | '/' => value.push(escaped),
+                        'b' => value.push('\u{8}'),
+                        'f' => value.push('\u{c}'),
+                        'n' => value.push('\n'),
+                        'r' => value.push('\r'),
+                        't' => value.push('\t'),
+                        'u' => match self.parse_unicode_escape() {
+                            Escape::Char(c) => value.push(c),
+                            Escape::Incomplete => {
+                                self.position = self.chars.len();
+                                return Some((value, false));
+                            }
+                            Escape::Invalid => return None,
+                        },
+                        _ => return None,
+                    }
+                }
+                _ => value.push(c),
+            }
+        }
+
+        Some((value, false))
+    }
+
+    /// Decodes the hex digits of a `\\u` escape, combining a surrogate pair into one
+    /// `char`. Unpaired surrogates are replaced rather than rejected.
+    fn parse_unicode_escape(&mut self) -> Escape {
+        if self.chars.len() - self.position < 4 {
+            return Escape::Incomplete;
+        }
+        let Some(high) = self.parse_hex() else {
+            return Escape::Invalid;
+        };
+
+        if !(0xd800..=0xdbff).contains(&high) {
+            return Escape::Char(char::from_u32(high).unwrap_or('\u{fffd}'));
+        }
+
+        let rest = &self.chars[self.position..];
+        if !rest
+            .iter()
+            .zip(['\\', 'u'])
+            .all(|(c, expected)| *c == expected)
+        {
+            return Escape::Char('\u{fffd}');
+        }
+        if rest.len() < 6 {
+            return Escape::Incomplete;
+        }
+
+        self.position += 2;
+        let Some(low) = self.parse_hex() else {
+            return Escape::Invalid;
+        };
+
+        if !(0xdc00..=0xdfff).contains(&low) {
+            // the second escape is decoded on its own
+            self.position -= 6;
+            return Escape::Char('\u{fffd}');
+        }
+
+        let code = 0x10000 + ((high - 0xd800) << 10) + (low - 0xdc00);
+        char::from_u32(code).map_or(Escape::Invalid, Escape::Char)
+    }
+
+    fn parse_hex(&mut self) -> Option<u32> {
+        let hex: String = self.chars[self.position..self.position + 4]
+            .iter()
+            .collect();
+        self.position += 4;
+        u32::from_str_radix(&hex, 16).ok()
+    }
+
+    fn parse_literal(&mut self, literal: &str, value: Value) -> Option<Value> {
+        for expected in literal.chars() {
+            match self.peek() {
+                None => return Some(value),
+                Some(c) if c == expected => self.position += 1,
+                Some(_) => return None,
+            }
+        }
+
+        Some(value)
+    }
+
+    fn parse_number(&mut self) -> Option<Value> {
+        let start = self.position;
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_digit() || matches!(c, '-' | '+' | '.' | 'e' | 'E'))
+        {
+            self.position += 1;
+        }
+
+        let mut number: String = self.chars[start..self.position].iter().collect();
+        if self.at_end() {
+            // drop a dangling sign, decimal point or exponent
+            while number.ends_with(['-', '+', '.', 'e', 'E']) {
+                number.pop();
+            }
+        }
+
+        if let Ok(value) = number.parse::<i64>() {
+            return Some(Value::Number(value.into()));
+        }
+        if let Ok(value) = number.parse::<u64>() {
+            return Some(Value::Number(value.into()));
+        }
+
+        number
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_parses_complete_documents() {
+        let input = r#"{"name":"Alice","tags":["a","b"],"age":30,"ok":true,"x":null,"f":-1.5e3}"#;
+
+        assert_eq!(
+            parse_partial_json(input),
+            Some(serde_json::from_str(input).unwrap())
+        );
+    }
+
+    #[test]
+    fn it_closes_open_containers_and_strings() {
+        let cases = [
+            ("{", json!({})),
+            (r#"{"na"#, json!({})),
+            (r#"{"name""#, json!({})),
+            (r#"{"name":"#, json!({})),
+            (r#"{"name":"Al"#, json!({ "name": "Al" })),
+            (
+                r#"{"name":"Alice","tags":["a","#,
+                json!({ "name": "Alice", "tags": ["a"] }),
+            ),
+            (
+                r#"{"a":{"b":[1,{"c":tr"#,
+                json!({ "a": { "b": [1, { "c": true }] } }),
+            ),
+            (r#"{"n":12."#, json!({ "n": 12 })),
+            (r#"{"a":"x","n":-"#, json!({ "a": "x" })),
+            ("[1,-", json!([1])),
+            (r#"{"s":"line\"#, json!({ "s": "line" })),
+            (r#"{"s":"é\u00"#, json!({ "s": "é" })),
+            (r#"{"e":"a\ud83d"#, json!({ "e": "a" })),
+            (r#"{"e":"a\ud83d\"#, json!({ "e": "a" })),
+            (r#"{"e":"a\ud83d\ude"#, json!({ "e": "a" })),
+            ("[", json!([])),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(
+                parse_partial_json(input),
+                Some(expected),
+                "input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn it_decodes_surrogate_pairs() {
+        assert_eq!(
+            parse_partial_json(r#"{"e":"\ud83d\ude00"}"#),
+            Some(json!({ "e": "😀" }))
+        );
+        assert_eq!(
+            parse_partial_json(r#"{"e":"\ud83dx\ude00"}"#),
+            Some(json!({ "e": "\u{fffd}x\u{fffd}" }))
+        );
+    }
+
+    #[test]
+    fn it_rejects_input_that_is_not_a_json_prefix() {
+        assert_eq!(parse_partial_json(""), None);
+        assert_eq!(parse_partial_json("hello"), None);
+        assert_eq!(parse_partial_json(r#"{"a":1}}"#), None);
+        assert_eq!(parse_partial_json(r#"{"a" 1}"#), None);
+    }
+}
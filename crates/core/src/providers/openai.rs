@@ -0,0 +1,713 @@
+use crate::embedding_model::{EmbeddingModel, EmbeddingResult};
+use crate::language_model::{LanguageModel, LanguageModelRequest, StreamParts};
+use crate::types::{
+    finish_reason::FinishReason,
+    message::{MediaSource, Message, Part, Role},
+    response_format::ResponseFormat,
+    result::{GenerateTextResult, ResponseMetadata},
+    stream_part::StreamPart,
+    tool::{ToolCall, ToolChoice},
+    usage::Usage,
+};
+use ai_providers::openai::common::{
+    function_tool_call_item::FunctionToolCallItem,
+    output_message_item::OutputContent,
+    status::Status,
+    text::{
+        JsonObjectFormat, JsonSchemaFormat, ResponseFormat as OpenAIResponseFormat, Text,
+        TextFormat,
+    },
+    tool::FunctionTool,
+    tool_choice::{FunctionToolChoice, ToolChoice as OpenAIToolChoice, ToolChoiceMode},
+};
+use ai_providers::openai::constants::OpenAIModelId;
+use ai_providers::openai::embeddings::{EmbeddingRequest, EncodingFormat};
+use ai_providers::openai::request::input::{Input, InputItemList};
+use ai_providers::openai::request::input_models::{
+    common::{Content, FileContent, ImageContent, Role as OpenAIRole, TextContent},
+    input_message::{InputItemContentList, InputMessage, TextInput},
+    item::{FunctionToolCallOutputItem, Item},
+};
+use ai_providers::openai::response::{
+    events::streaming::{OpenAIStreamingEvent, StreamingResponse},
+    incomplete_details::IncompleteDetails,
+    response_output::ResponseOutput,
+    usage::Usage as OpenAIUsage,
+};
+use ai_providers::utils::errors::ProviderError;
+use ai_providers::{OpenAIProvider, OpenAIRequest, OpenAIResponse, ProviderStrategy};
+use async_trait::async_trait;
+use futures::StreamExt;
+use serde_json::Value;
+
+/// An OpenAI Responses API model exposed through the [`LanguageModel`] trait.
+#[derive(Debug, Clone)]
+pub struct OpenAILanguageModel {
+    provider: OpenAIProvider,
+    model: OpenAIModelId,
+}
+
+impl OpenAILanguageModel {
+    pub fn new(provider: OpenAIProvider, model: OpenAIModelId) -> Self {
+        Self { provider, model }
+    }
+
+    pub fn provider_client(&self) -> &OpenAIProvider {
+        &self.provider
+    }
+
+    /// Translates a provider-neutral request into an [`OpenAIRequest`].
+    pub fn to_request(
+        &self,
+        request: &LanguageModelRequest,
+    ) -> Result<OpenAIRequest, ProviderError> {
+        let mut items = vec![];
+        for message in &request.messages {
+            items.extend(to_input_items(message)?);
+        }
+
+        let mut openai_request = OpenAIRequest::new(self.model.clone(), Input::Messages(items));
+
+        for tool in &request.tools {
+            let mut function = FunctionTool::new(tool.name.clone(), tool.parameters.clone())
+                .strict(tool.strict.unwrap_or(false));
+
+            if let Some(description) = &tool.description {
+                function = function.description(description.clone());
+            }
+
+            openai_request = openai_request.add_tool(function.into());
+        }
+
+        if let Some(tool_choice) = &request.tool_choice {
+            openai_request = openai_request.tool_choice(to_tool_choice(tool_choice));
+        }
+
+        if let Some(temperature) = request.temperature {
+            openai_request = openai_request.temperature(temperature);
+        }
+
+        if let Some(top_p) = request.top_p {
+            openai_request = openai_request.top_p(top_p);
+        }
+
+        if let Some(max_output_tokens) = request.max_output_tokens {
+            openai_request = openai_request.max_output_tokens(max_output_tokens);
+        }
+
+        if let Some(response_format) = &request.response_format {
+            openai_request = openai_request
+                .text(Text::default().response_format(to_response_format(response_format)));
+        }
+
+        Ok(openai_request)
+    }
+}
+
+#[async_trait]
+impl LanguageModel for OpenAILanguageModel {
+    fn provider(&self) -> &str {
+        "openai"
+    }
+
+    fn model_id(&self) -> &str {
+        self.model.as_str()
+    }
+
+    async fn generate(
+        &self,
+        request: &LanguageModelRequest,
+    ) -> Result<GenerateTextResult, ProviderError> {
+        let openai_request = self.to_request(request)?;
+        let response = self.provider.generate(&openai_request).await?;
+
+        Ok(GenerateTextResult::from(&response))
+    }
+
+    async fn stream(&self, request: &LanguageModelRequest) -> Result<StreamParts, ProviderError> {
+        let openai_request = self.to_request(request)?;
+        let events = self.provider.stream(&openai_request).await?;
+
+        Ok(Box::pin(events.filter_map(|event| async move {
+            match event {
+                Ok(event) => to_stream_part(event),
+                Err(e) => Some(Err(e)),
+            }
+        })))
+    }
+}
+
+/// An OpenAI embeddings model exposed through the [`EmbeddingModel`] trait.
+#[derive(Debug, Clone)]
+pub struct OpenAIEmbeddingModel {
+    provider: OpenAIProvider,
+    model: OpenAIModelId,
+    dimensions: Option<usize>,
+}
+
+impl OpenAIEmbeddingModel {
+    pub fn new(provider: OpenAIProvider, model: OpenAIModelId) -> Self {
+        Self {
+            provider,
+            model,
+            dimensions: None,
+        }
+    }
+
+    pub fn dimensions(mut self, value: usize) -> Self {
+        self.dimensions = Some(value);
+        self
+    }
+
+    pub fn provider_client(&self) -> &OpenAIProvider {
+        &self.provider
+    }
+}
+
+#[async_trait]
+impl EmbeddingModel for OpenAIEmbeddingModel {
+    fn provider(&self) -> &str {
+        "openai"
+    }
+
+    fn model_id(&self) -> &str {
+        self.model.as_str()
+    }
+
+    async fn embed(&self, values: &[String]) -> Result<EmbeddingResult, ProviderError> {
+        if values.is_empty() {
+            return Ok(EmbeddingResult::default());
+        }
+
+        let mut request = EmbeddingRequest::new(self.model.clone(), values.to_vec())
+            .encoding_format(EncodingFormat::Base64);
+
+        if let Some(dimensions) = self.dimensions {
+            request = request.dimensions(dimensions);
+        }
+
+        let response = self.provider.embed(&request).await?;
+
+        Ok(EmbeddingResult::new(
+            response
+                .embeddings()
+                .into_iter()
+                .map(<[f32]>::to_vec)
+                .collect(),
+            Usage::new(response.usage.prompt_tokens, 0),
+        ))
+    }
+}
+
+fn to_tool_choice(tool_choice: &ToolChoice) -> OpenAIToolChoice {
+    match tool_choice {
+        ToolChoice::Auto => ToolChoiceMode::Auto.into(),
+        ToolChoice::None => ToolChoiceMode::None.into(),
+        ToolChoice::Required => ToolChoiceMode::Required.into(),
+        ToolChoice::Tool(name) => FunctionToolChoice::new(name.clone()).into(),
+    }
+}
+
+fn to_response_format(response_format: &ResponseFormat) -> OpenAIResponseFormat {
+    match response_format {
+        ResponseFormat::Text => TextFormat::new().into(),
+        ResponseFormat::Json { schema: None, .. } => JsonObjectFormat::new().into(),
+        ResponseFormat::Json {
+            schema: Some(schema),
+            name,
+            description,
+            strict,
+        } => {
+            let mut format = JsonSchemaFormat::new(
+                name.clone().unwrap_or_else(|| "response".to_string()),
+                schema.clone(),
+            );
+
+            if let Some(description) = description {
+                format = format.description(description.clone());
+            }
+
+            if strict.unwrap_or(false) {
+                format = format.strict();
+            }
+
+            format.into()
+        }
+    }
+}
+
+fn data_url(data: &str, media_type: Option<&str>, fallback: &str) -> String {
+    format!("data:{};base64,{}", media_type.unwrap_or(fallback), data)
+}
+
+fn to_content(part: &Part) -> Result<Content, ProviderError> {
+    match part {
+        Part::Text { text } => Ok(TextContent::new().text(text.clone()).into()),
+        Part::Image { source, media_type } => Ok(match source {
+            MediaSource::Url(url) => ImageContent::new().image_url(url.clone()),
+            MediaSource::Base64(data) => {
+                ImageContent::new().image_url(data_url(data, media_type.as_deref(), "image/png"))
+            }
+            MediaSource::FileId(file_id) => ImageContent::new().file_id(file_id.clone()),
+        }
+        .into()),
+        Part::File {
+            source,
+            media_type,
+            filename,
+        } => {
+            let mut file = match source {
+                MediaSource::Base64(data) => FileContent::new().file_data(data_url(
+                    data,
+                    media_type.as_deref(),
+                    "application/pdf",
+                )),
+                MediaSource::FileId(file_id) => FileContent::new().file_id(file_id.clone()),
+                MediaSource::Url(_) => {
+                    return Err(ProviderError::NotSupported(
+                        "file parts by URL are not supported by the OpenAI Responses API"
+                            .to_string(),
+                    ))
+                }
+            };
+
+            if let Some(filename) = filename {
+                file = file.filename(filename.clone());
+            }
+
+            Ok(file.into())
+        }
+        other => Err(ProviderError::ValidationError(format!(
+            "{} parts are not allowed in a user message",
+            other.kind()
+        ))),
+    }
+}
+
+fn text_input(role: OpenAIRole, content: String) -> InputItemList {
+    InputItemList::InputMessage(InputMessage::TextInput(TextInput {
+        role,
+        content,
+        type_field: None,
+    }))
+}
+
+fn to_input_items(message: &Message) -> Result<Vec<InputItemList>, ProviderError> {
+    match message.role {
+        Role::System => {
+            let text = message
+                .content
+                .iter()
+                .map(|part| match part {
+                    Part::Text { text } => Ok(text.as_str()),
+                    other => Err(ProviderError::ValidationError(format!(
+                        "{} parts are not allowed in a system message",
+                        other.kind()
+                    ))),
+                })
+                .collect::<Result<String, _>>()?;
+
+            Ok(vec![text_input(OpenAIRole::System, text)])
+        }
+        Role::User => {
+            let content = message
+                .content
+                .iter()
+                .map(to_content)
+                .collect::<Result<Vec<_>, _>>()?;
+
+            Ok(vec![InputItemList::InputMessage(
+                InputMessage::InputItemContentList(InputItemContentList {
+                    role: OpenAIRole::User,
+                    content,
+                    type_field: None,
+                }),
+            )])
+        }
+        Role::Assistant => {
+            let mut items = vec![];
+            let mut text = String::new();
+
+            for part in &message.content {
+                match part {
+                    Part::Text { text: value } => text.push_str(value),
+                    Part::ToolCall(call) => {
+                        if !text.is_empty() {
+                            items
+                                .push(text_input(OpenAIRole::Assistant, std::mem::take(&mut text)));
+                        }
+
+                        items.push(InputItemList::Item(Item::FunctionToolCall(
+                            FunctionToolCallItem::new(
+                                call.arguments.to_string(),
+                                call.id.clone(),
+                                call.name.clone(),
+                            )
+                            .insert_type(),
+                        )));
+                    }
+                    // reasoning and refusals cannot be replayed as input
+                    Part::Reasoning { .. } | Part::Refusal { .. } => {}
+                    other => {
+                        return Err(ProviderError::ValidationError(format!(
+                            "{} parts are not allowed in an assistant message",
+                            other.kind()
+                        )))
+                    }
+                }
+            }
+
+            if !text.is_empty() {
+                items.push(text_input(OpenAIRole::Assistant, text));
+            }
+
+            Ok(items)
+        }
+        Role::Tool => message
+            .content
+            .iter()
+            .map(|part| match part {
+                Part::ToolResult(result) => {
+                    let output = match &result.output {
+                        Value::String(output) => output.clone(),
+                        output => output.to_string(),
+                    };
+
+                    Ok(InputItemList::Item(Item::FunctionToolCallOutput(
+                        FunctionToolCallOutputItem::new(result.call_id.clone(), output),
+                    )))
+                }
+                other => Err(ProviderError::ValidationError(format!(
+                    "{} parts are not allowed in a tool message",
+                    other.kind()
+                ))),
+            })
+            .collect(),
+    }
+}
+
+/// Maps the status of a Responses API response onto a [`FinishReason`].
+pub fn finish_reason(
+    status: &Status,
+    incomplete_details: Option<&IncompleteDetails>,
+    has_tool_calls: bool,
+) -> FinishReason {
+    match status {
+        Status::Completed if has_tool_calls => FinishReason::ToolCalls,
+        Status::Completed => FinishReason::Stop,
+        Status::Incomplete => match incomplete_details.map(|details| details.reason.as_str()) {
+            Some("max_output_tokens") => FinishReason::Length,
+            Some("content_filter") => FinishReason::ContentFilter,
+            Some(reason) => FinishReason::Other(reason.to_string()),
+            None => FinishReason::Unknown,
+        },
+        Status::Failed => FinishReason::Error,
+        Status::Cancelled => FinishReason::Other("cancelled".to_string()),
+        Status::Queued | Status::InProgress => FinishReason::Unknown,
+    }
+}
+
+/// Parses tool call arguments, keeping them as a raw string when they are not JSON.
+pub fn parse_arguments(arguments: &str) -> Value {
+    serde_json::from_str(arguments).unwrap_or_else(|_| Value::String(arguments.to_string()))
+}
+
+pub fn to_parts(output: &[ResponseOutput]) -> Vec<Part> {
+    let mut parts = vec![];
+
+    for item in output {
+        match item {
+            ResponseOutput::OutputMessage(message) => {
+                for content in &message.content {
+                    parts.push(match content {
+                        OutputContent::OutputText(output) => Part::text(output.text.clone()),
+                        OutputContent::Refusal(refusal) => Part::Refusal {
+                            text: refusal.refusal.clone(),
+                        },
+                    });
+                }
+            }
+            ResponseOutput::FunctionToolCall(call) => parts.push(Part::ToolCall(ToolCall::new(
+                call.call_id.clone(),
+                call.name.clone(),
+                parse_arguments(&call.arguments),
+            ))),
+            ResponseOutput::Reasoning(reasoning) => {
+                for summary in &reasoning.summary {
+                    parts.push(Part::Reasoning {
+                        text: summary.text.clone(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    parts
+}
+
+pub fn to_usage(usage: &OpenAIUsage) -> Usage {
+    Usage {
+        input_tokens: usage.input_tokens,
+        output_tokens: usage.output_tokens,
+        total_tokens: usage.total_tokens,
+        reasoning_tokens: Some(usage.output_tokens_details.reasoning_tokens),
+        cached_input_tokens: Some(usage.input_tokens_details.cached_tokens),
+    }
+}
+
+/// Maps a streaming event onto a [`StreamPart`], skipping events that carry nothing
+/// beyond what the deltas and finished items already report.
+pub fn to_stream_part(event: OpenAIStreamingEvent) -> Option<Result<StreamPart, ProviderError>> {
+    match event {
+        OpenAIStreamingEvent::OutputTextDelta { delta, .. } => {
+            Some(Ok(StreamPart::TextDelta(delta)))
+        }
+        OpenAIStreamingEvent::ReasoningSummaryTextDelta { delta, .. } => {
+            Some(Ok(StreamPart::ReasoningDelta(delta)))
+        }
+        OpenAIStreamingEvent::RefusalDelta { delta, .. } => {
+            Some(Ok(StreamPart::RefusalDelta(delta)))
+        }
+        OpenAIStreamingEvent::OutputItemDone {
+            item: ResponseOutput::FunctionToolCall(call),
+            ..
+        } => Some(Ok(StreamPart::ToolCall(ToolCall::new(
+            call.call_id,
+            call.name,
+            parse_arguments(&call.arguments),
+        )))),
+        OpenAIStreamingEvent::Completed { response }
+        | OpenAIStreamingEvent::Incomplete { response }
+        | OpenAIStreamingEvent::Failed { response } => Some(Ok(finish_part(&response))),
+        OpenAIStreamingEvent::Error { code, message, .. } => {
+            Some(Err(ProviderError::Other(match code {
+                Some(code) => format!("{}: {}", code, message),
+                None => message,
+            })))
+        }
+        _ => None,
+    }
+}
+
+fn finish_part(response: &StreamingResponse) -> StreamPart {
+    let has_tool_calls = response
+        .output
+        .iter()
+        .any(|item| matches!(item, ResponseOutput::FunctionToolCall(_)));
+
+    StreamPart::Finish {
+        finish_reason: finish_reason(
+            &response.status,
+            response.incomplete_details.as_ref(),
+            has_tool_calls,
+        ),
+        usage: response.usage.as_ref().map(to_usage).unwrap_or_default(),
+        response: ResponseMetadata::new()
+            .id(response.id.clone())
+            .model_id(response.model.clone()),
+    }
+}
+
+impl From<&OpenAIResponse> for GenerateTextResult {
+    fn from(response: &OpenAIResponse) -> Self {
+        let content = to_parts(&response.output);
+        let has_tool_calls = content.iter().any(|part| matches!(part, Part::ToolCall(_)));
+
+        GenerateTextResult::new(
+            content,
+            finish_reason(
+                &response.status,
+                response.incomplete_details.as_ref(),
+                has_tool_calls,
+            ),
+            to_usage(&response.usage),
+            ResponseMetadata::new()
+                .id(response.id.clone())
+                .model_id(response.model.clone()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::tool::{ToolDefinition, ToolResult};
+    use serde_json::json;
+
+    fn model() -> OpenAILanguageModel {
+        OpenAILanguageModel::new(
+            OpenAIProvider::new("test-key".to_string()),
+            OpenAIModelId::Gpt4OMini,
+        )
+    }
+
+    #[test]
+    fn it_converts_messages_into_responses_input() {
+        let request = LanguageModelRequest::new(vec![
+            Message::system("Be brief."),
+            Message::user("Weather?").add_part(Part::image_base64("AAAA", "image/jpeg")),
+            Message::assistant("Checking.").add_part(ToolCall::new(
+                "call_1",
+                "get_weather",
+                json!({ "city": "Istanbul" }),
+            )),
+            Message::tool(vec![ToolResult::new(
+                "call_1",
+                "get_weather",
+                json!({ "celsius": 21 }),
+            )]),
+        ])
+        .add_tool(
+            ToolDefinition::new("get_weather", json!({ "type": "object" }))
+                .description("Current weather"),
+        )
+        .tool_choice(ToolChoice::Tool("get_weather".to_string()))
+        .temperature(0.5);
+
+        let openai_request = model().to_request(&request).unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&openai_request).unwrap(),
+            json!({
+                "model": "gpt-4o-mini",
+                "input": [
+                    { "role": "system", "content": "Be brief." },
+                    {
+                        "role": "user",
+                        "content": [
+                            { "type": "input_text", "text": "Weather?" },
+                            {
+                                "type": "input_image",
+                                "image_url": "data:image/jpeg;base64,AAAA",
+                                "detail": "auto"
+                            }
+                        ]
+                    },
+                    { "role": "assistant", "content": "Checking." },
+                    {
+                        "type": "function_call",
+                        "call_id": "call_1",
+                        "name": "get_weather",
+                        "arguments": "{\"city\":\"Istanbul\"}"
+                    },
+                    {
+                        "type": "function_call_output",
+                        "call_id": "call_1",
+                        "output": "{\"celsius\":21}"
+                    }
+                ],
+                "tools": [{
+                    "type": "function",
+                    "name": "get_weather",
+                    "parameters": { "type": "object" },
+                    "strict": false,
+                    "description": "Current weather"
+                }],
+                "tool_choice": { "type": "function", "name": "get_weather" },
+                "temperature": 0.5
+            })
+        );
+    }
+
+    #[test]
+    fn it_rejects_parts_that_do_not_fit_the_role() {
+        let request =
+            LanguageModelRequest::new(vec![Message::new(Role::User).add_part(ToolCall::new(
+                "call_1",
+                "get_weather",
+                json!({}),
+            ))]);
+
+        assert!(matches!(
+            model().to_request(&request),
+            Err(ProviderError::ValidationError(_))
+        ));
+
+        let request = LanguageModelRequest::new(vec![Message::system("Be brief.")
+            .add_part(Part::image_url("https://example.com/secret.png"))]);
+
+        match model().to_request(&request) {
+            Err(ProviderError::ValidationError(message)) => {
+                assert_eq!(message, "image parts are not allowed in a system message");
+            }
+            other => panic!("expected a validation error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn it_maps_responses_into_results() {
+        let response: OpenAIResponse = serde_json::from_value(json!({
+            "id": "resp_123",
+            "object": "response",
+            "created_at": 1741290958,
+            "status": "completed",
+            "model": "gpt-4o-mini-2024-07-18",
+            "output": [
+                {
+                    "type": "reasoning",
+                    "id": "rs_1",
+                    "summary": [{ "type": "summary_text", "text": "Need the weather." }]
+                },
+                {
+                    "type": "function_call",
+                    "id": "fc_1",
+                    "call_id": "call_1",
+                    "name": "get_weather",
+                    "arguments": "{\"city\":\"Istanbul\"}",
+                    "status": "completed"
+                }
+            ],
+            "parallel_tool_calls": true,
+            "text": { "format": { "type": "text" } },
+            "tool_choice": "auto",
+            "tools": [],
+            "usage": {
+                "input_tokens": 10,
+                "input_tokens_details": { "cached_tokens": 2 },
+                "output_tokens": 5,
+                "output_tokens_details": { "reasoning_tokens": 3 },
+                "total_tokens": 15
+            }
+        }))
+        .unwrap();
+
+        let result = GenerateTextResult::from(&response);
+
+        assert_eq!(result.response_id(), Some("resp_123"));
+        assert_eq!(result.finish_reason(), &FinishReason::ToolCalls);
+        assert_eq!(result.reasoning().as_deref(), Some("Need the weather."));
+        assert_eq!(
+            result.tool_calls(),
+            vec![&ToolCall::new(
+                "call_1",
+                "get_weather",
+                json!({ "city": "Istanbul" })
+            )]
+        );
+        assert_eq!(
+            result.usage(),
+            &Usage::new(10, 5).reasoning_tokens(3).cached_input_tokens(2)
+        );
+    }
+
+    #[test]
+    fn it_maps_incomplete_responses_to_finish_reasons() {
+        let details = IncompleteDetails::new("max_output_tokens");
+        assert_eq!(
+            finish_reason(&Status::Incomplete, Some(&details), false),
+            FinishReason::Length
+        );
+
+        let details = IncompleteDetails::new("content_filter");
+        assert_eq!(
+            finish_reason(&Status::Incomplete, Some(&details), false),
+            FinishReason::ContentFilter
+        );
+
+        assert_eq!(
+            finish_reason(&Status::Failed, None, false),
+            FinishReason::Error
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use crate::generate_object::{
+    parse_object, GenerateObjectError, GenerateObjectOptions, GenerateObjectResult,
+};
+use crate::generate_text::Prompt;
+use crate::language_model::LanguageModel;
+use crate::partial_json::parse_partial_json;
+use crate::stream_text::StreamTextResult;
+use crate::types::stream_part::StreamPart;
+use ai_providers::utils::errors::ProviderError;
+use ai_providers::JsonSchema;
+use futures::stream::{self, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::marker::PhantomData;
+use std::task::Poll;
+
+/// A streamed structured generation.
+///
+/// [`partial_stream`](Self::partial_stream) yields the object parsed so far each
+/// time a text delta changes it; [`result`](Self::result) waits for the end of the
+/// stream and validates the complete output as `T`.
+pub struct StreamObjectResult<T> {
+    inner: StreamTextResult,
+    text: String,
+    partial: Option<Value>,
+    object: PhantomData<fn() -> T>,
+}
+
+impl<T: DeserializeOwned> StreamObjectResult<T> {
+    pub fn new(inner: StreamTextResult) -> Self {
+        Self {
+            inner,
+            text: String::new(),
+            partial: None,
+            object: PhantomData,
+        }
+    }
+
+    /// Yields successive best-effort values of the object as it is streamed.
+    ///
+    /// Values are untyped because a partial object usually lacks required fields.
+    pub fn partial_stream(&mut self) -> impl Stream<Item = Result<Value, ProviderError>> + '_ {
+        stream::poll_fn(move |cx| loop {
+            match self.inner.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(StreamPart::TextDelta(delta)))) => {
+                    self.text.push_str(&delta);
+
+                    match parse_partial_json(&self.text) {
+                        Some(value) if self.partial.as_ref() != Some(&value) => {
+                            self.partial = Some(value.clone());
+                            return Poll::Ready(Some(Ok(value)));
+                        }
+                        _ => {}
+                    }
+                }
+                Poll::Ready(Some(Ok(_))) => {}
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        })
+    }
+
+    /// Drains the rest of the stream and deserializes the complete output.
+    pub async fn result(self) -> Result<GenerateObjectResult<T>, GenerateObjectError> {
+        let result = self.inner.result().await?;
+        let object = parse_object::<T>(&result)?;
+
+        Ok(GenerateObjectResult::new(
+            object,
+            result.usage().clone(),
+            result,
+        ))
+    }
+}
+
+/// Streams a value of type `T` using the JSON schema derived from it.
+///
+/// Unlike [`generate_object`](crate::generate_object), invalid output is not repaired
+/// because it has already been shown to the caller.
+pub async fn stream_object<T>(
+    model: &(impl LanguageModel + ?Sized),
+    prompt: impl Into<Prompt>,
+    options: GenerateObjectOptions,
+) -> Result<StreamObjectResult<T>, ProviderError>
+where
+    T: DeserializeOwned + JsonSchema,
+{
+    let mut request = options.settings.to_request(prompt.into());
+    request.response_format = Some(options.response_format::<T>());
+
+    let parts = model.stream(&request).await?;
+
+    Ok(StreamObjectResult::new(StreamTextResult::new(parts)))
+}
@@ -0,0 +1,199 @@
+use crate::generate_text::{GenerateTextOptions, Prompt};
+use crate::language_model::{LanguageModel, StreamParts};
+use crate::types::{
+    finish_reason::FinishReason,
+    message::Part,
+    result::{GenerateTextResult, ResponseMetadata},
+    stream_part::StreamPart,
+    tool::ToolCall,
+    usage::Usage,
+};
+use ai_providers::utils::errors::ProviderError;
+use futures::stream::{self, Fuse, Stream, StreamExt};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+#[derive(Debug, Default)]
+struct Accumulator {
+    reasoning: String,
+    text: String,
+    refusal: String,
+    tool_calls: Vec<ToolCall>,
+    finish: Option<(FinishReason, Usage, ResponseMetadata)>,
+}
+
+impl Accumulator {
+    fn push(&mut self, part: &StreamPart) {
+        match part {
+            StreamPart::TextDelta(delta) => self.text.push_str(delta),
+            StreamPart::ReasoningDelta(delta) => self.reasoning.push_str(delta),
+            StreamPart::RefusalDelta(delta) => self.refusal.push_str(delta),
+            StreamPart::ToolCall(call) => self.tool_calls.push(call.clone()),
+            StreamPart::Finish {
+                finish_reason,
+                usage,
+                response,
+            } => self.finish = Some((finish_reason.clone(), usage.clone(), response.clone())),
+        }
+    }
+
+    fn into_result(self) -> GenerateTextResult {
+        let mut content = vec![];
+        if !self.reasoning.is_empty() {
+            content.push(Part::Reasoning {
+                text: self.reasoning,
+            });
+        }
+        if !self.text.is_empty() {
+            content.push(Part::text(self.text));
+        }
+        if !self.refusal.is_empty() {
+            content.push(Part::Refusal { text: self.refusal });
+        }
+        content.extend(self.tool_calls.into_iter().map(Part::ToolCall));
+
+        let (finish_reason, usage, response) = self.finish.unwrap_or((
+            FinishReason::Unknown,
+            Usage::default(),
+            ResponseMetadata::new(),
+        ));
+
+        GenerateTextResult::new(content, finish_reason, usage, response)
+    }
+}
+
+/// A streamed generation.
+///
+/// The result is itself a stream of [`StreamPart`]s. Every part that passes through
+/// it, including those read via [`text_stream`](Self::text_stream), is folded into
+/// the final [`GenerateTextResult`] returned by [`result`](Self::result).
+pub struct StreamTextResult {
+    parts: Fuse<StreamParts>,
+    accumulator: Accumulator,
+}
+
+impl StreamTextResult {
+    pub fn new(parts: StreamParts) -> Self {
+        Self {
+            parts: parts.fuse(),
+            accumulator: Accumulator::default(),
+        }
+    }
+
+    /// Yields only the text deltas of the stream.
+    pub fn text_stream(&mut self) -> impl Stream<Item = Result<String, ProviderError>> + '_ {
+        stream::poll_fn(move |cx| loop {
+            match self.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(StreamPart::TextDelta(delta)))) => {
+                    return Poll::Ready(Some(Ok(delta)))
+                }
+                Poll::Ready(Some(Ok(_))) => {}
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            }
+        })
+    }
+
+    /// Drains whatever is left of the stream and returns the aggregated result.
+    pub async fn result(mut self) -> Result<GenerateTextResult, ProviderError> {
+        while let Some(part) = self.next().await {
+            part?;
+        }
+
+        Ok(self.accumulator.into_result())
+    }
+}
+
+impl Stream for StreamTextResult {
+    type Item = Result<StreamPart, ProviderError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.parts.poll_next_unpin(cx);
+
+        if let Poll::Ready(Some(Ok(part))) = &poll {
+            self.accumulator.push(part);
+        }
+
+        poll
+    }
+}
+
+/// Streams text from any [`LanguageModel`].
+///
+/// ```no_run
+/// # use futures::StreamExt;
+/// # async fn run(model: &ai_sdk::OpenAILanguageModel) -> Result<(), ai_sdk::ProviderError> {
+/// let mut stream = ai_sdk::stream_text(model, "Say hi", ai_sdk::GenerateTextOptions::new()).await?;
+///
+/// {
+///     let mut deltas = stream.text_stream();
+///     while let Some(delta) = deltas.next().await {
+///         print!("{}", delta?);
+///     }
+/// }
+///
+/// let result = stream.result().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn stream_text<M>(
+    model: &M,
+    prompt: impl Into<Prompt>,
+    options: GenerateTextOptions,
+) -> Result<StreamTextResult, ProviderError>
+where
+    M: LanguageModel + ?Sized,
+{
+    let parts = model.stream(&options.to_request(prompt.into())).await?;
+
+    Ok(StreamTextResult::new(parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(parts: Vec<StreamPart>) -> StreamParts {
+        Box::pin(stream::iter(parts.into_iter().map(Ok)))
+    }
+
+    #[tokio::test]
+    async fn it_aggregates_streamed_parts() {
+        let mut stream = StreamTextResult::new(parts(vec![
+            StreamPart::ReasoningDelta("Thinking".to_string()),
+            StreamPart::TextDelta("Hello".to_string()),
+            StreamPart::TextDelta(", world".to_string()),
+            StreamPart::ToolCall(ToolCall::new("call_1", "lookup", serde_json::json!({}))),
+            StreamPart::Finish {
+                finish_reason: FinishReason::ToolCalls,
+                usage: Usage::new(3, 4),
+                response: ResponseMetadata::new().id("resp_1"),
+            },
+        ]));
+
+        let deltas: Vec<String> = stream
+            .text_stream()
+            .map(|delta| delta.unwrap())
+            .collect()
+            .await;
+        assert_eq!(deltas, vec!["Hello", ", world"]);
+
+        let result = stream.result().await.unwrap();
+        assert_eq!(result.text(), "Hello, world");
+        assert_eq!(result.reasoning().as_deref(), Some("Thinking"));
+        assert_eq!(result.tool_calls().len(), 1);
+        assert_eq!(result.finish_reason(), &FinishReason::ToolCalls);
+        assert_eq!(result.usage().total_tokens, 7);
+        assert_eq!(result.response_id(), Some("resp_1"));
+    }
+
+    #[tokio::test]
+    async fn it_drains_unread_parts_into_the_result() {
+        let stream = StreamTextResult::new(parts(vec![StreamPart::TextDelta("Hi".to_string())]));
+
+        let result = stream.result().await.unwrap();
+        assert_eq!(result.text(), "Hi");
+        assert_eq!(result.finish_reason(), &FinishReason::Unknown);
+    }
+}
@@ -0,0 +1,198 @@
+use crate::types::tool::{ToolCall, ToolResult};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    System,
+    #[default]
+    User,
+    Assistant,
+    Tool,
+}
+
+/// Where the bytes of an image or file part come from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MediaSource {
+    Url(String),
+    Base64(String),
+    FileId(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Part {
+    Text {
+        text: String,
+    },
+    Image {
+        source: MediaSource,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        media_type: Option<String>,
+    },
+    File {
+        source: MediaSource,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        media_type: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        filename: Option<String>,
+    },
+    Reasoning {
+        text: String,
+    },
+    Refusal {
+        text: String,
+    },
+    ToolCall(ToolCall),
+    ToolResult(ToolResult),
+}
+
+impl Part {
+    pub fn text(value: impl Into<String>) -> Self {
+        Self::Text { text: value.into() }
+    }
+
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::Image {
+            source: MediaSource::Url(url.into()),
+            media_type: None,
+        }
+    }
+
+    pub fn image_base64(data: impl Into<String>, media_type: impl Into<String>) -> Self {
+        Self::Image {
+            source: MediaSource::Base64(data.into()),
+            media_type: Some(media_type.into()),
+        }
+    }
+
+    pub fn file(
+        source: MediaSource,
+        media_type: impl Into<String>,
+        filename: Option<String>,
+    ) -> Self {
+        Self::File {
+            source,
+            media_type: Some(media_type.into()),
+            filename,
+        }
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text { text } => Some(text),
+            _ => None,
+        }
+    }
+
+    /// The part's `type`, for error messages that should not echo its content.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Text { .. } => "text",
+            Self::Image { .. } => "image",
+            Self::File { .. } => "file",
+            Self::Reasoning { .. } => "reasoning",
+            Self::Refusal { .. } => "refusal",
+            Self::ToolCall(_) => "tool_call",
+            Self::ToolResult(_) => "tool_result",
+        }
+    }
+}
+
+impl From<ToolCall> for Part {
+    fn from(value: ToolCall) -> Self {
+        Self::ToolCall(value)
+    }
+}
+
+impl From<ToolResult> for Part {
+    fn from(value: ToolResult) -> Self {
+        Self::ToolResult(value)
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    pub role: Role,
+    pub content: Vec<Part>,
+}
+
+impl Message {
+    pub fn new(role: Role) -> Self {
+        Self {
+            role,
+            content: vec![],
+        }
+    }
+
+    pub fn system(text: impl Into<String>) -> Self {
+        Self::new(Role::System).add_part(Part::text(text))
+    }
+
+    pub fn user(text: impl Into<String>) -> Self {
+        Self::new(Role::User).add_part(Part::text(text))
+    }
+
+    pub fn assistant(text: impl Into<String>) -> Self {
+        Self::new(Role::Assistant).add_part(Part::text(text))
+    }
+
+    pub fn tool(results: Vec<ToolResult>) -> Self {
+        Self {
+            role: Role::Tool,
+            content: results.into_iter().map(Part::ToolResult).collect(),
+        }
+    }
+
+    pub fn add_part(mut self, part: impl Into<Part>) -> Self {
+        self.content.push(part.into());
+        self
+    }
+
+    /// Concatenates the text parts of the message.
+    pub fn text(&self) -> String {
+        self.content.iter().filter_map(Part::as_text).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_builds_multimodal_messages() {
+        let message = Message::user("What is in this image?")
+            .add_part(Part::image_url("https://example.com/cat.png"));
+
+        assert_eq!(message.role, Role::User);
+        assert_eq!(message.content.len(), 2);
+        assert_eq!(message.text(), "What is in this image?");
+    }
+
+    #[test]
+    fn test_json_values() {
+        let message = Message::assistant("Hi").add_part(ToolCall::new(
+            "call_1",
+            "get_weather",
+            json!({ "city": "Istanbul" }),
+        ));
+
+        assert_eq!(
+            serde_json::to_value(&message).unwrap(),
+            json!({
+                "role": "assistant",
+                "content": [
+                    { "type": "text", "text": "Hi" },
+                    {
+                        "type": "tool_call",
+                        "id": "call_1",
+                        "name": "get_weather",
+                        "arguments": { "city": "Istanbul" }
+                    }
+                ]
+            })
+        );
+    }
+}
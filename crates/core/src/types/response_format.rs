@@ -0,0 +1,57 @@
+use ai_providers::utils::schema::{schema_description, schema_name, strict_schema_for};
+use ai_providers::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The shape the model has to answer in.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ResponseFormat {
+    #[default]
+    Text,
+    Json {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        schema: Option<Value>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        strict: Option<bool>,
+    },
+}
+
+impl ResponseFormat {
+    /// Any valid JSON, without a schema.
+    pub fn json() -> Self {
+        Self::Json {
+            schema: None,
+            name: None,
+            description: None,
+            strict: None,
+        }
+    }
+
+    pub fn json_schema(name: impl Into<String>, schema: Value) -> Self {
+        Self::Json {
+            schema: Some(schema),
+            name: Some(name.into()),
+            description: None,
+            strict: None,
+        }
+    }
+
+    /// A strict schema derived from `T`, named after the type and described by its
+    /// doc comment.
+    pub fn from_type<T: JsonSchema>() -> Self {
+        let schema = strict_schema_for::<T>();
+        let description = schema_description(&schema);
+
+        Self::Json {
+            schema: Some(schema),
+            name: Some(schema_name::<T>()),
+            description,
+            strict: Some(true),
+        }
+    }
+}
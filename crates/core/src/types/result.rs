@@ -0,0 +1,124 @@
+use crate::types::{
+    finish_reason::FinishReason,
+    message::{Message, Part, Role},
+    tool::ToolCall,
+    usage::Usage,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_id: Option<String>,
+}
+
+impl ResponseMetadata {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, value: impl Into<String>) -> Self {
+        self.id = Some(value.into());
+        self
+    }
+
+    pub fn model_id(mut self, value: impl Into<String>) -> Self {
+        self.model_id = Some(value.into());
+        self
+    }
+}
+
+/// The provider-neutral outcome of a single text generation call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerateTextResult {
+    content: Vec<Part>,
+    finish_reason: FinishReason,
+    usage: Usage,
+    response: ResponseMetadata,
+}
+
+impl GenerateTextResult {
+    pub fn new(
+        content: Vec<Part>,
+        finish_reason: FinishReason,
+        usage: Usage,
+        response: ResponseMetadata,
+    ) -> Self {
+        Self {
+            content,
+            finish_reason,
+            usage,
+            response,
+        }
+    }
+
+    pub fn content(&self) -> &[Part] {
+        &self.content
+    }
+
+    /// Concatenates every text part of the output.
+    pub fn text(&self) -> String {
+        self.content.iter().filter_map(Part::as_text).collect()
+    }
+
+    pub fn reasoning(&self) -> Option<String> {
+        let reasoning: Vec<&str> = self
+            .content
+            .iter()
+            .filter_map(|part| match part {
+                Part::Reasoning { text } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+
+        (!reasoning.is_empty()).then(|| reasoning.join("\n"))
+    }
+
+    pub fn refusal(&self) -> Option<&str> {
+        self.content.iter().find_map(|part| match part {
+            Part::Refusal { text } => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    pub fn tool_calls(&self) -> Vec<&ToolCall> {
+        self.content
+            .iter()
+            .filter_map(|part| match part {
+                Part::ToolCall(call) => Some(call),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn finish_reason(&self) -> &FinishReason {
+        &self.finish_reason
+    }
+
+    pub fn usage(&self) -> &Usage {
+        &self.usage
+    }
+
+    pub fn response(&self) -> &ResponseMetadata {
+        &self.response
+    }
+
+    pub fn response_id(&self) -> Option<&str> {
+        self.response.id.as_deref()
+    }
+
+    /// The output as an assistant message, ready to be appended to a conversation.
+    pub fn to_message(&self) -> Message {
+        Message {
+            role: Role::Assistant,
+            content: self
+                .content
+                .iter()
+                .filter(|part| !matches!(part, Part::Reasoning { .. }))
+                .cloned()
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use crate::types::{
+    finish_reason::FinishReason, result::ResponseMetadata, tool::ToolCall, usage::Usage,
+};
+
+/// One provider-neutral event of a streamed generation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamPart {
+    TextDelta(String),
+    ReasoningDelta(String),
+    RefusalDelta(String),
+    ToolCall(ToolCall),
+    Finish {
+        finish_reason: FinishReason,
+        usage: Usage,
+        response: ResponseMetadata,
+    },
+}
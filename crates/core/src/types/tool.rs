@@ -0,0 +1,99 @@
+use ai_providers::utils::schema::strict_schema_for;
+use ai_providers::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolDefinition {
+    pub name: String,
+    pub parameters: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+impl ToolDefinition {
+    pub fn new(name: impl Into<String>, parameters: Value) -> Self {
+        Self {
+            name: name.into(),
+            parameters,
+            description: None,
+            strict: None,
+        }
+    }
+
+    /// Creates a strict tool whose parameters are the schema of `T`.
+    pub fn from_type<T: JsonSchema>(name: impl Into<String>) -> Self {
+        Self::new(name, strict_schema_for::<T>()).strict(true)
+    }
+
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn strict(mut self, value: bool) -> Self {
+        self.strict = Some(value);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToolChoice {
+    Auto,
+    None,
+    Required,
+    Tool(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: Value,
+}
+
+impl ToolCall {
+    pub fn new(id: impl Into<String>, name: impl Into<String>, arguments: Value) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            arguments,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ToolResult {
+    pub call_id: String,
+    pub name: String,
+    pub output: Value,
+    #[serde(default)]
+    pub is_error: bool,
+}
+
+impl ToolResult {
+    pub fn new(call_id: impl Into<String>, name: impl Into<String>, output: Value) -> Self {
+        Self {
+            call_id: call_id.into(),
+            name: name.into(),
+            output,
+            is_error: false,
+        }
+    }
+
+    pub fn error(
+        call_id: impl Into<String>,
+        name: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            call_id: call_id.into(),
+            name: name.into(),
+            output: Value::String(message.into()),
+            is_error: true,
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Usage {
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub total_tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning_tokens: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_input_tokens: Option<usize>,
+}
+
+impl Usage {
+    pub fn new(input_tokens: usize, output_tokens: usize) -> Self {
+        Self {
+            input_tokens,
+            output_tokens,
+            total_tokens: input_tokens + output_tokens,
+            reasoning_tokens: None,
+            cached_input_tokens: None,
+        }
+    }
+
+    pub fn reasoning_tokens(mut self, value: usize) -> Self {
+        self.reasoning_tokens = Some(value);
+        self
+    }
+
+    pub fn cached_input_tokens(mut self, value: usize) -> Self {
+        self.cached_input_tokens = Some(value);
+        self
+    }
+}
+
+impl std::ops::Add for Usage {
+    type Output = Usage;
+
+    fn add(self, other: Usage) -> Usage {
+        let sum = |a: Option<usize>, b: Option<usize>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+        };
+
+        Usage {
+            input_tokens: self.input_tokens + other.input_tokens,
+            output_tokens: self.output_tokens + other.output_tokens,
+            total_tokens: self.total_tokens + other.total_tokens,
+            reasoning_tokens: sum(self.reasoning_tokens, other.reasoning_tokens),
+            cached_input_tokens: sum(self.cached_input_tokens, other.cached_input_tokens),
+        }
+    }
+}
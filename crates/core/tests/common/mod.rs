@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+
+use ai_providers::openai::constants::OpenAIModelId;
+use ai_providers::OpenAIProvider;
+use ai_sdk::OpenAILanguageModel;
+use serde_json::{json, Value};
+use wiremock::MockServer;
+
+pub fn model(server: &MockServer) -> OpenAILanguageModel {
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    OpenAILanguageModel::new(provider, OpenAIModelId::Gpt4OMini)
+}
+
+pub fn response_json(status: &str, output: Value) -> Value {
+    json!({
+        "id": "resp_123",
+        "object": "response",
+        "created_at": 1741290958,
+        "status": status,
+        "error": null,
+        "incomplete_details": null,
+        "instructions": null,
+        "max_output_tokens": null,
+        "model": "gpt-4o-mini-2024-07-18",
+        "output": output,
+        "parallel_tool_calls": true,
+        "previous_response_id": null,
+        "reasoning": { "effort": null, "summary": null },
+        "store": true,
+        "temperature": 1.0,
+        "text": { "format": { "type": "text" } },
+        "tool_choice": "auto",
+        "tools": [],
+        "top_p": 1.0,
+        "truncation": "disabled",
+        "usage": {
+            "input_tokens": 37,
+            "input_tokens_details": { "cached_tokens": 0 },
+            "output_tokens": 11,
+            "output_tokens_details": { "reasoning_tokens": 0 },
+            "total_tokens": 48
+        },
+        "user": null,
+        "metadata": {}
+    })
+}
+
+pub fn message_output(text: &str) -> Value {
+    json!([{
+        "id": "msg_123",
+        "type": "message",
+        "status": "completed",
+        "role": "assistant",
+        "content": [{ "type": "output_text", "text": text, "annotations": [] }]
+    }])
+}
+
+pub fn sse(events: &[Value]) -> String {
+    events
+        .iter()
+        .map(|event| {
+            format!(
+                "event: {}\ndata: {}\n\n",
+                event["type"].as_str().unwrap(),
+                event
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,48 @@
+use ai_providers::openai::constants::OpenAIModelId;
+use ai_providers::OpenAIProvider;
+use ai_sdk::{EmbeddingModel, OpenAIEmbeddingModel};
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_embeds_values_through_the_embedding_model_trait() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_partial_json(json!({
+            "input": ["first", "second"],
+            "model": "text-embedding-3-small",
+            "dimensions": 2,
+            "encoding_format": "base64"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "object": "list",
+            "data": [
+                { "object": "embedding", "index": 1, "embedding": "AACAPwAAAMA=" },
+                { "object": "embedding", "index": 0, "embedding": "AAAAAAAAgD8=" }
+            ],
+            "model": "text-embedding-3-small",
+            "usage": { "prompt_tokens": 4, "total_tokens": 4 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+    let model =
+        OpenAIEmbeddingModel::new(provider, OpenAIModelId::TextEmbedding3Small).dimensions(2);
+
+    let result = model
+        .embed(&["first".to_string(), "second".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(model.model_id(), "text-embedding-3-small");
+    assert_eq!(result.embeddings, vec![vec![0.0, 1.0], vec![1.0, -2.0]]);
+    assert_eq!(result.usage.input_tokens, 4);
+}
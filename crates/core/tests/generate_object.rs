@@ -0,0 +1,152 @@
+mod common;
+
+use ai_sdk::{generate_object, GenerateObjectError, GenerateObjectOptions, JsonSchema};
+use common::{message_output, model, response_json};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+/// A person mentioned in the text.
+#[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+struct Person {
+    name: String,
+    age: u32,
+    email: Option<String>,
+}
+
+async fn mount(server: &MockServer, body: Value) {
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(body))
+        .up_to_n_times(1)
+        .mount(server)
+        .await;
+}
+
+#[tokio::test]
+async fn it_generates_a_typed_object() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .and(body_partial_json(json!({
+            "text": {
+                "format": {
+                    "type": "json_schema",
+                    "name": "Person",
+                    "description": "A person mentioned in the text.",
+                    "strict": true,
+                    "schema": {
+                        "required": ["age", "email", "name"],
+                        "additionalProperties": false
+                    }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json(
+            "completed",
+            message_output(r#"{"name":"Alice","age":30,"email":null}"#),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = generate_object::<Person>(
+        &model(&server),
+        "Alice is 30.",
+        GenerateObjectOptions::new(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result.object(),
+        &Person {
+            name: "Alice".to_string(),
+            age: 30,
+            email: None
+        }
+    );
+    assert_eq!(result.usage().total_tokens, 48);
+}
+
+#[tokio::test]
+async fn it_reports_refusals() {
+    let server = MockServer::start().await;
+    mount(
+        &server,
+        response_json(
+            "completed",
+            json!([{
+                "id": "msg_123",
+                "type": "message",
+                "status": "completed",
+                "role": "assistant",
+                "content": [{ "type": "refusal", "refusal": "I can't help with that." }]
+            }]),
+        ),
+    )
+    .await;
+
+    let error = generate_object::<Person>(&model(&server), "Hi", GenerateObjectOptions::new())
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(error, GenerateObjectError::Refusal(text) if text == "I can't help with that.")
+    );
+}
+
+#[tokio::test]
+async fn it_repairs_output_that_does_not_match_the_schema() {
+    let server = MockServer::start().await;
+    mount(
+        &server,
+        response_json("completed", message_output(r#"{"name":"Alice"}"#)),
+    )
+    .await;
+    mount(
+        &server,
+        response_json(
+            "completed",
+            message_output(r#"{"name":"Alice","age":30,"email":null}"#),
+        ),
+    )
+    .await;
+
+    let result = generate_object::<Person>(
+        &model(&server),
+        "Alice is 30.",
+        GenerateObjectOptions::new().max_repairs(1),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.object().age, 30);
+    assert_eq!(result.usage().total_tokens, 96);
+
+    let requests = server.received_requests().await.unwrap();
+    let repair: Value = requests[1].body_json().unwrap();
+    let input = repair["input"].as_array().unwrap();
+    assert_eq!(input.len(), 3);
+    assert_eq!(input[1]["role"], "assistant");
+    assert_eq!(input[2]["role"], "user");
+    assert!(input[2].to_string().contains("does not match the schema"));
+}
+
+#[tokio::test]
+async fn it_fails_without_repairs() {
+    let server = MockServer::start().await;
+    mount(
+        &server,
+        response_json("completed", message_output("Alice, 30")),
+    )
+    .await;
+
+    let error = generate_object::<Person>(&model(&server), "Hi", GenerateObjectOptions::new())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GenerateObjectError::InvalidJson { text, .. } if text == "Alice, 30"));
+}
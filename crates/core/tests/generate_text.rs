@@ -0,0 +1,104 @@
+mod common;
+
+use ai_sdk::{generate_text, stream_text, FinishReason, GenerateTextOptions, Message};
+use common::{message_output, model, response_json, sse};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_generates_text_from_a_prompt() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .and(body_partial_json(json!({
+            "input": [
+                { "role": "system", "content": "Be brief." },
+                { "role": "user" }
+            ]
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response_json("completed", message_output("Hi there!"))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = generate_text(
+        &model(&server),
+        "Hello",
+        GenerateTextOptions::new().system("Be brief."),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.text(), "Hi there!");
+    assert!(result.tool_calls().is_empty());
+    assert_eq!(result.usage().total_tokens, 48);
+    assert_eq!(result.finish_reason(), &FinishReason::Stop);
+    assert_eq!(result.response_id(), Some("resp_123"));
+}
+
+#[tokio::test]
+async fn it_streams_text_deltas_and_aggregates_the_result() {
+    let server = MockServer::start().await;
+
+    let completed = response_json(
+        "completed",
+        json!([
+            message_output("Hello, world")[0].clone(),
+            {
+                "type": "function_call",
+                "id": "fc_1",
+                "call_id": "call_1",
+                "name": "get_weather",
+                "arguments": "{\"city\":\"Paris\"}",
+                "status": "completed"
+            }
+        ]),
+    );
+
+    let body = sse(&[
+        json!({ "type": "response.output_text.delta", "item_id": "msg_123", "output_index": 0, "content_index": 0, "delta": "Hello" }),
+        json!({ "type": "response.output_text.delta", "item_id": "msg_123", "output_index": 0, "content_index": 0, "delta": ", world" }),
+        json!({ "type": "response.output_item.done", "output_index": 1, "item": completed["output"][1] }),
+        json!({ "type": "response.completed", "response": completed }),
+    ]);
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(body),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stream = stream_text(
+        &model(&server),
+        vec![Message::user("Hello")],
+        GenerateTextOptions::new(),
+    )
+    .await
+    .unwrap();
+
+    let deltas: Vec<String> = stream
+        .text_stream()
+        .map(|delta| delta.unwrap())
+        .collect()
+        .await;
+    assert_eq!(deltas, vec!["Hello", ", world"]);
+
+    let result = stream.result().await.unwrap();
+    assert_eq!(result.text(), "Hello, world");
+    assert_eq!(result.tool_calls()[0].name, "get_weather");
+    assert_eq!(result.tool_calls()[0].arguments, json!({ "city": "Paris" }));
+    assert_eq!(result.finish_reason(), &FinishReason::ToolCalls);
+    assert_eq!(result.usage().total_tokens, 48);
+    assert_eq!(result.response_id(), Some("resp_123"));
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use ai_sdk::{stream_object, GenerateObjectOptions, JsonSchema};
+use common::{message_output, model, response_json, sse};
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[derive(Debug, PartialEq, Deserialize, JsonSchema)]
+struct Person {
+    name: String,
+    hobbies: Vec<String>,
+}
+
+#[tokio::test]
+async fn it_streams_partial_objects_and_validates_the_final_one() {
+    let server = MockServer::start().await;
+
+    let text = r#"{"name":"Alice","hobbies":["chess","go"]}"#;
+    let deltas = [
+        r#"{"na"#,
+        r#"me":"Al"#,
+        r#"ice","#,
+        r#""hobbies":["ch"#,
+        r#"ess","go"]}"#,
+    ];
+
+    let mut events: Vec<Value> = deltas
+        .iter()
+        .map(|delta| {
+            json!({
+                "type": "response.output_text.delta",
+                "item_id": "msg_123",
+                "output_index": 0,
+                "content_index": 0,
+                "delta": delta
+            })
+        })
+        .collect();
+    events.push(json!({
+        "type": "response.completed",
+        "response": response_json("completed", message_output(text))
+    }));
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(sse(&events)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut stream =
+        stream_object::<Person>(&model(&server), "Alice", GenerateObjectOptions::new())
+            .await
+            .unwrap();
+
+    let partials: Vec<Value> = stream
+        .partial_stream()
+        .map(|partial| partial.unwrap())
+        .collect()
+        .await;
+
+    assert_eq!(
+        partials,
+        vec![
+            json!({}),
+            json!({ "name": "Al" }),
+            json!({ "name": "Alice" }),
+            json!({ "name": "Alice", "hobbies": ["ch"] }),
+            json!({ "name": "Alice", "hobbies": ["chess", "go"] }),
+        ]
+    );
+
+    let result = stream.result().await.unwrap();
+    assert_eq!(
+        result.into_object(),
+        Person {
+            name: "Alice".to_string(),
+            hobbies: vec!["chess".to_string(), "go".to_string()],
+        }
+    );
+}
@@ -0,0 +1,175 @@
+use crate::anthropic::constants::{ANTHROPIC_API_URL, ANTHROPIC_VERSION};
+use crate::anthropic::streaming::AnthropicStreamingEvent;
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, header_value, Auth, HttpConfig, HttpTransport},
+    provider_strategy::ProviderStrategy,
+};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, RequestBuilder};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+use super::types::{AnthropicRequest, AnthropicResponse};
+
+#[derive(Debug, Clone)]
+pub struct AnthropicProvider {
+    api_key: String,
+    http: HttpTransport,
+}
+
+impl AnthropicProvider {
+    pub fn new(api_key: String) -> Self {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "anthropic-version",
+            HeaderValue::from_static(ANTHROPIC_VERSION),
+        );
+
+        AnthropicProvider {
+            http: HttpTransport::new(ANTHROPIC_API_URL, auth(&api_key), headers),
+            api_key,
+        }
+    }
+
+    pub fn builder(api_key: impl Into<String>) -> AnthropicProviderBuilder {
+        AnthropicProviderBuilder::new(api_key)
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, path)
+    }
+}
+
+fn auth(api_key: &str) -> Auth {
+    Auth::Header(HeaderName::from_static("x-api-key"), api_key.to_string())
+}
+
+/// Builder for an [`AnthropicProvider`] with a custom endpoint, headers and HTTP client.
+///
+/// Beta features are enabled with [`AnthropicProviderBuilder::beta`], which may be
+/// called several times; the flags are sent together in the `anthropic-beta` header.
+#[derive(Debug)]
+pub struct AnthropicProviderBuilder {
+    api_key: String,
+    base_url: String,
+    version: String,
+    betas: Vec<String>,
+    http: HttpConfig,
+}
+
+impl AnthropicProviderBuilder {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: ANTHROPIC_API_URL.to_string(),
+            version: ANTHROPIC_VERSION.to_string(),
+            betas: vec![],
+            http: HttpConfig::default(),
+        }
+    }
+
+    pub fn base_url(mut self, value: impl Into<String>) -> Self {
+        self.base_url = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn version(mut self, value: impl Into<String>) -> Self {
+        self.version = value.into();
+        self
+    }
+
+    pub fn beta(mut self, value: impl Into<String>) -> Self {
+        self.betas.push(value.into());
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<AnthropicProvider, ProviderError> {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "anthropic-version",
+            header_value("anthropic-version", &self.version)?,
+        );
+
+        if !self.betas.is_empty() {
+            headers.insert(
+                "anthropic-beta",
+                header_value("anthropic-beta", &self.betas.join(","))?,
+            );
+        }
+
+        let http = self
+            .http
+            .build(self.base_url, auth(&self.api_key), headers)?;
+
+        Ok(AnthropicProvider {
+            api_key: self.api_key,
+            http,
+        })
+    }
+}
+
+#[async_trait]
+impl ProviderStrategy for AnthropicProvider {
+    type GenerationRequest = AnthropicRequest;
+    type StreamingRequest = AnthropicRequest;
+    type GenerationResponse = AnthropicResponse;
+    type StreamingResponse = AnthropicStreamingEvent;
+
+    fn get_base_url(&self) -> String {
+        self.http.base_url().to_string()
+    }
+
+    fn get_api_key(&self) -> String {
+        self.api_key.clone()
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        http::send(self.request(Method::POST, "/messages").json(request)).await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        http::send_sse(
+            self.http
+                .stream_request(Method::POST, "/messages")
+                .json(&request.wrap_for_streaming()),
+        )
+        .await
+    }
+}
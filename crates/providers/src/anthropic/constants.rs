@@ -0,0 +1,4 @@
+pub const ANTHROPIC_API_URL: &str = "https://api.anthropic.com/v1";
+
+/// Value of the `anthropic-version` header sent with every request.
+pub const ANTHROPIC_VERSION: &str = "2023-06-01";
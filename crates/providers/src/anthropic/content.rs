@@ -0,0 +1,248 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Marks a prompt prefix as cacheable.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CacheControl {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<String>,
+}
+
+impl CacheControl {
+    pub fn ephemeral() -> Self {
+        Self {
+            type_field: "ephemeral".to_string(),
+            ttl: None,
+        }
+    }
+
+    /// Cache lifetime such as `"5m"` or `"1h"`.
+    pub fn ttl(mut self, value: impl Into<String>) -> Self {
+        self.ttl = Some(value.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImageSource {
+    Base64 { media_type: String, data: String },
+    Url { url: String },
+    File { file_id: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DocumentSource {
+    Base64 { media_type: String, data: String },
+    Text { media_type: String, data: String },
+    Url { url: String },
+    File { file_id: String },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ToolResultContent {
+    Text(String),
+    Blocks(Vec<ContentBlock>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentBlock {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+    Image {
+        source: ImageSource,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+    Document {
+        source: DocumentSource,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        title: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: Value,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+    ToolResult {
+        tool_use_id: String,
+        content: ToolResultContent,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        is_error: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        cache_control: Option<CacheControl>,
+    },
+    Thinking {
+        thinking: String,
+        signature: String,
+    },
+    RedactedThinking {
+        data: String,
+    },
+    /// A block type this crate does not model yet, e.g. `server_tool_use` or
+    /// `web_search_tool_result`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl ContentBlock {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text {
+            text: text.into(),
+            cache_control: None,
+        }
+    }
+
+    pub fn image_base64(media_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self::Image {
+            source: ImageSource::Base64 {
+                media_type: media_type.into(),
+                data: data.into(),
+            },
+            cache_control: None,
+        }
+    }
+
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::Image {
+            source: ImageSource::Url { url: url.into() },
+            cache_control: None,
+        }
+    }
+
+    pub fn document(source: DocumentSource) -> Self {
+        Self::Document {
+            source,
+            title: None,
+            cache_control: None,
+        }
+    }
+
+    pub fn tool_use(id: impl Into<String>, name: impl Into<String>, input: Value) -> Self {
+        Self::ToolUse {
+            id: id.into(),
+            name: name.into(),
+            input,
+            cache_control: None,
+        }
+    }
+
+    pub fn tool_result(tool_use_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::ToolResult {
+            tool_use_id: tool_use_id.into(),
+            content: ToolResultContent::Text(content.into()),
+            is_error: None,
+            cache_control: None,
+        }
+    }
+
+    pub fn tool_error(tool_use_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::ToolResult {
+            tool_use_id: tool_use_id.into(),
+            content: ToolResultContent::Text(content.into()),
+            is_error: Some(true),
+            cache_control: None,
+        }
+    }
+
+    /// Sets a cache breakpoint after this block. Thinking and unknown blocks cannot
+    /// be cached directly and are returned unchanged.
+    pub fn cache_control(mut self, value: CacheControl) -> Self {
+        match &mut self {
+            Self::Text { cache_control, .. }
+            | Self::Image { cache_control, .. }
+            | Self::Document { cache_control, .. }
+            | Self::ToolUse { cache_control, .. }
+            | Self::ToolResult { cache_control, .. } => *cache_control = Some(value),
+            Self::Thinking { .. } | Self::RedactedThinking { .. } | Self::Unknown => {}
+        }
+        self
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Self::Text { text, .. } => Some(text),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_values() {
+        let blocks = vec![
+            ContentBlock::text("Describe this").cache_control(CacheControl::ephemeral()),
+            ContentBlock::image_base64("image/png", "aGVsbG8="),
+            ContentBlock::tool_use("toolu_1", "get_weather", json!({ "city": "Paris" })),
+            ContentBlock::tool_error("toolu_1", "timeout"),
+        ];
+
+        assert_eq!(
+            serde_json::to_value(&blocks).unwrap(),
+            json!([
+                { "type": "text", "text": "Describe this", "cache_control": { "type": "ephemeral" } },
+                { "type": "image", "source": { "type": "base64", "media_type": "image/png", "data": "aGVsbG8=" } },
+                { "type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": { "city": "Paris" } },
+                { "type": "tool_result", "tool_use_id": "toolu_1", "content": "timeout", "is_error": true }
+            ])
+        );
+    }
+
+    #[test]
+    fn it_deserializes_thinking_blocks() {
+        let block: ContentBlock = serde_json::from_value(json!({
+            "type": "thinking",
+            "thinking": "Let me think.",
+            "signature": "sig"
+        }))
+        .unwrap();
+
+        assert_eq!(
+            block,
+            ContentBlock::Thinking {
+                thinking: "Let me think.".to_string(),
+                signature: "sig".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn it_deserializes_unknown_blocks() {
+        let blocks: Vec<ContentBlock> = serde_json::from_value(json!([
+            {
+                "type": "server_tool_use",
+                "id": "srvtoolu_1",
+                "name": "web_search",
+                "input": { "query": "weather in Paris" }
+            },
+            { "type": "web_search_tool_result", "tool_use_id": "srvtoolu_1", "content": [] },
+            { "type": "text", "text": "It is sunny." }
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            blocks,
+            vec![
+                ContentBlock::Unknown,
+                ContentBlock::Unknown,
+                ContentBlock::text("It is sunny.")
+            ]
+        );
+    }
+}
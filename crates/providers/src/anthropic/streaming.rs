@@ -0,0 +1,105 @@
+use crate::anthropic::content::ContentBlock;
+use crate::anthropic::types::{AnthropicResponse, AnthropicUsage, StopReason};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnthropicStreamingEvent {
+    /// Carries the message with empty content; blocks follow as separate events.
+    MessageStart {
+        message: AnthropicResponse,
+    },
+    ContentBlockStart {
+        index: usize,
+        content_block: ContentBlock,
+    },
+    ContentBlockDelta {
+        index: usize,
+        delta: ContentDelta,
+    },
+    ContentBlockStop {
+        index: usize,
+    },
+    /// Top-level changes to the message; `usage` is cumulative.
+    MessageDelta {
+        delta: MessageDelta,
+        usage: AnthropicUsage,
+    },
+    MessageStop,
+    Ping,
+    Error {
+        error: StreamError,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentDelta {
+    TextDelta {
+        text: String,
+    },
+    /// A fragment of a `tool_use` block's input, which is only valid JSON once complete.
+    InputJsonDelta {
+        partial_json: String,
+    },
+    ThinkingDelta {
+        thinking: String,
+    },
+    SignatureDelta {
+        signature: String,
+    },
+    CitationsDelta {
+        citation: Value,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageDelta {
+    pub stop_reason: Option<StopReason>,
+    pub stop_sequence: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamError {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_deserializes_the_event_family() {
+        let events: Vec<AnthropicStreamingEvent> = [
+            json!({ "type": "content_block_start", "index": 1, "content_block": { "type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": {} } }),
+            json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "input_json_delta", "partial_json": "{\"city\":" } }),
+            json!({ "type": "content_block_stop", "index": 1 }),
+            json!({ "type": "message_delta", "delta": { "stop_reason": "tool_use", "stop_sequence": null }, "usage": { "output_tokens": 12 } }),
+            json!({ "type": "message_stop" }),
+            json!({ "type": "error", "error": { "type": "overloaded_error", "message": "Overloaded" } }),
+        ]
+        .into_iter()
+        .map(|event| serde_json::from_value(event).unwrap())
+        .collect();
+
+        assert_eq!(
+            events[1],
+            AnthropicStreamingEvent::ContentBlockDelta {
+                index: 1,
+                delta: ContentDelta::InputJsonDelta {
+                    partial_json: "{\"city\":".to_string()
+                }
+            }
+        );
+        assert!(matches!(
+            &events[3],
+            AnthropicStreamingEvent::MessageDelta { delta, usage }
+                if delta.stop_reason == Some(StopReason::ToolUse) && usage.output_tokens == 12
+        ));
+        assert_eq!(events[4], AnthropicStreamingEvent::MessageStop);
+    }
+}
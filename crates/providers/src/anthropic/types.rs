@@ -0,0 +1,425 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::anthropic::content::{CacheControl, ContentBlock};
+use crate::utils::schema::schema_for;
+use schemars::JsonSchema;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AnthropicRole {
+    User,
+    Assistant,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Text(String),
+    Blocks(Vec<ContentBlock>),
+}
+
+impl From<String> for MessageContent {
+    fn from(value: String) -> Self {
+        MessageContent::Text(value)
+    }
+}
+
+impl From<&str> for MessageContent {
+    fn from(value: &str) -> Self {
+        MessageContent::Text(value.to_string())
+    }
+}
+
+impl From<Vec<ContentBlock>> for MessageContent {
+    fn from(value: Vec<ContentBlock>) -> Self {
+        MessageContent::Blocks(value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicMessage {
+    pub role: AnthropicRole,
+    pub content: MessageContent,
+}
+
+impl AnthropicMessage {
+    pub fn user(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: AnthropicRole::User,
+            content: content.into(),
+        }
+    }
+
+    pub fn assistant(content: impl Into<MessageContent>) -> Self {
+        Self {
+            role: AnthropicRole::Assistant,
+            content: content.into(),
+        }
+    }
+}
+
+/// The system prompt, either plain text or text blocks that can carry cache breakpoints.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum SystemPrompt {
+    Text(String),
+    Blocks(Vec<ContentBlock>),
+}
+
+impl From<String> for SystemPrompt {
+    fn from(value: String) -> Self {
+        SystemPrompt::Text(value)
+    }
+}
+
+impl From<&str> for SystemPrompt {
+    fn from(value: &str) -> Self {
+        SystemPrompt::Text(value.to_string())
+    }
+}
+
+impl From<Vec<ContentBlock>> for SystemPrompt {
+    fn from(value: Vec<ContentBlock>) -> Self {
+        SystemPrompt::Blocks(value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicTool {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub input_schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_control: Option<CacheControl>,
+}
+
+impl AnthropicTool {
+    pub fn new(name: impl Into<String>, input_schema: Value) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            input_schema,
+            cache_control: None,
+        }
+    }
+
+    /// Builds a tool whose input schema is derived from `T`, keeping its doc comment
+    /// as the description.
+    pub fn from_type<T: JsonSchema>(name: impl Into<String>) -> Self {
+        let mut input_schema = schema_for::<T>();
+        let description = input_schema
+            .as_object_mut()
+            .and_then(|schema| {
+                schema.remove("$schema");
+                schema.remove("title");
+                schema.remove("description")
+            })
+            .and_then(|description| description.as_str().map(str::to_string));
+
+        Self {
+            name: name.into(),
+            description,
+            input_schema,
+            cache_control: None,
+        }
+    }
+
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn cache_control(mut self, value: CacheControl) -> Self {
+        self.cache_control = Some(value);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnthropicToolChoice {
+    Auto {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        disable_parallel_tool_use: Option<bool>,
+    },
+    Any {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        disable_parallel_tool_use: Option<bool>,
+    },
+    Tool {
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        disable_parallel_tool_use: Option<bool>,
+    },
+    None,
+}
+
+impl AnthropicToolChoice {
+    pub fn auto() -> Self {
+        Self::Auto {
+            disable_parallel_tool_use: None,
+        }
+    }
+
+    pub fn any() -> Self {
+        Self::Any {
+            disable_parallel_tool_use: None,
+        }
+    }
+
+    pub fn tool(name: impl Into<String>) -> Self {
+        Self::Tool {
+            name: name.into(),
+            disable_parallel_tool_use: None,
+        }
+    }
+}
+
+/// Extended thinking settings. `budget_tokens` counts towards `max_tokens`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ThinkingConfig {
+    Enabled { budget_tokens: usize },
+    Disabled,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicRequest {
+    model: String,
+    max_tokens: usize,
+    messages: Vec<AnthropicMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<SystemPrompt>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    metadata: Option<AnthropicMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    thinking: Option<ThinkingConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<AnthropicToolChoice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<AnthropicTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_k: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+}
+
+impl AnthropicRequest {
+    pub fn new(model: impl Into<String>, max_tokens: usize) -> Self {
+        Self {
+            model: model.into(),
+            max_tokens,
+            ..Default::default()
+        }
+    }
+
+    pub fn messages(mut self, value: Vec<AnthropicMessage>) -> Self {
+        self.messages = value;
+        self
+    }
+
+    pub fn add_message(mut self, value: AnthropicMessage) -> Self {
+        self.messages.push(value);
+        self
+    }
+
+    pub fn system(mut self, value: impl Into<SystemPrompt>) -> Self {
+        self.system = Some(value.into());
+        self
+    }
+
+    pub fn user_id(mut self, value: impl Into<String>) -> Self {
+        self.metadata = Some(AnthropicMetadata {
+            user_id: Some(value.into()),
+        });
+        self
+    }
+
+    pub fn add_stop_sequence(mut self, value: impl Into<String>) -> Self {
+        match self.stop_sequences {
+            Some(ref mut stop_sequences) => stop_sequences.push(value.into()),
+            None => self.stop_sequences = Some(vec![value.into()]),
+        }
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn thinking(mut self, budget_tokens: usize) -> Self {
+        self.thinking = Some(ThinkingConfig::Enabled { budget_tokens });
+        self
+    }
+
+    pub fn tool_choice(mut self, value: AnthropicToolChoice) -> Self {
+        self.tool_choice = Some(value);
+        self
+    }
+
+    pub fn add_tool(mut self, value: AnthropicTool) -> Self {
+        match self.tools {
+            Some(ref mut tools) => tools.push(value),
+            None => self.tools = Some(vec![value]),
+        }
+        self
+    }
+
+    pub fn top_k(mut self, value: usize) -> Self {
+        self.top_k = Some(value);
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn wrap_for_streaming(&self) -> impl Serialize + '_ {
+        struct Wrapper<'a> {
+            inner: &'a AnthropicRequest,
+        }
+
+        impl Serialize for Wrapper<'_> {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let mut original = serde_json::to_value(self.inner)
+                    .map_err(|e| serde::ser::Error::custom(e.to_string()))?;
+
+                if let Value::Object(ref mut map) = original {
+                    map.insert("stream".to_string(), json!(true));
+
+                    map.serialize(serializer)
+                } else {
+                    Err(serde::ser::Error::custom("Expected object"))
+                }
+            }
+        }
+
+        Wrapper { inner: self }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopReason {
+    EndTurn,
+    MaxTokens,
+    StopSequence,
+    ToolUse,
+    PauseTurn,
+    Refusal,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicUsage {
+    #[serde(default)]
+    pub input_tokens: usize,
+    #[serde(default)]
+    pub output_tokens: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_creation_input_tokens: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cache_read_input_tokens: Option<usize>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AnthropicResponse {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub role: AnthropicRole,
+    pub model: String,
+    pub content: Vec<ContentBlock>,
+    pub stop_reason: Option<StopReason>,
+    pub stop_sequence: Option<String>,
+    pub usage: AnthropicUsage,
+}
+
+impl AnthropicResponse {
+    /// The concatenated text blocks of the response.
+    pub fn text(&self) -> String {
+        self.content
+            .iter()
+            .filter_map(ContentBlock::as_text)
+            .collect()
+    }
+
+    pub fn tool_uses(&self) -> impl Iterator<Item = &ContentBlock> {
+        self.content
+            .iter()
+            .filter(|block| matches!(block, ContentBlock::ToolUse { .. }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[allow(dead_code)]
+    /// Looks up the weather.
+    #[derive(JsonSchema)]
+    struct GetWeather {
+        city: String,
+    }
+
+    #[test]
+    fn test_json_values() {
+        let request = AnthropicRequest::new("claude-sonnet-4-5", 1024)
+            .system(vec![
+                ContentBlock::text("You are terse.").cache_control(CacheControl::ephemeral())
+            ])
+            .add_message(AnthropicMessage::user("Weather in Paris?"))
+            .add_tool(AnthropicTool::from_type::<GetWeather>("get_weather"))
+            .tool_choice(AnthropicToolChoice::auto())
+            .thinking(512);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "claude-sonnet-4-5",
+                "max_tokens": 1024,
+                "system": [
+                    { "type": "text", "text": "You are terse.", "cache_control": { "type": "ephemeral" } }
+                ],
+                "messages": [{ "role": "user", "content": "Weather in Paris?" }],
+                "tools": [{
+                    "name": "get_weather",
+                    "description": "Looks up the weather.",
+                    "input_schema": {
+                        "type": "object",
+                        "properties": { "city": { "type": "string" } },
+                        "required": ["city"]
+                    }
+                }],
+                "tool_choice": { "type": "auto" },
+                "thinking": { "type": "enabled", "budget_tokens": 512 }
+            })
+        );
+    }
+
+    #[test]
+    fn it_deserializes_unknown_stop_reasons() {
+        let stop_reason: StopReason =
+            serde_json::from_value(json!("model_context_window_exceeded")).unwrap();
+
+        assert_eq!(stop_reason, StopReason::Unknown);
+    }
+}
@@ -0,0 +1,229 @@
+use crate::azure::constants::{resource_endpoint, AZURE_OPENAI_API_VERSION};
+use crate::azure::types::{AzureOpenAIResponse, AzureOpenAIStreamingEvent};
+use crate::openai::types::OpenAIRequest;
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, Auth, HttpConfig, HttpTransport},
+    provider_strategy::ProviderStrategy,
+};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Method, RequestBuilder};
+use serde_json::{json, Value};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// How requests to Azure OpenAI are authenticated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AzureAuth {
+    /// A resource key, sent in the `api-key` header.
+    ApiKey(String),
+    /// A Microsoft Entra ID access token, sent as a bearer token.
+    EntraToken(String),
+}
+
+/// Provider for an Azure OpenAI deployment.
+///
+/// Requests are the same [`OpenAIRequest`]s sent to OpenAI; their model is replaced
+/// with the deployment name, which is what Azure routes on. Since the deployment
+/// does not say which model it serves, requests are validated without model
+/// capability checks.
+#[derive(Debug, Clone)]
+pub struct AzureOpenAIProvider {
+    auth: AzureAuth,
+    deployment: String,
+    api_version: String,
+    http: HttpTransport,
+}
+
+impl AzureOpenAIProvider {
+    /// Starts a builder for `deployment` on a resource endpoint such as
+    /// `https://my-resource.openai.azure.com`.
+    pub fn builder(
+        endpoint: impl Into<String>,
+        deployment: impl Into<String>,
+    ) -> AzureOpenAIProviderBuilder {
+        AzureOpenAIProviderBuilder::new(endpoint, deployment)
+    }
+
+    /// Starts a builder for `deployment` on the resource named `resource`, served at
+    /// `https://{resource}.openai.azure.com`.
+    pub fn for_resource(
+        resource: &str,
+        deployment: impl Into<String>,
+    ) -> AzureOpenAIProviderBuilder {
+        AzureOpenAIProviderBuilder::new(resource_endpoint(resource), deployment)
+    }
+
+    pub fn deployment(&self) -> &str {
+        &self.deployment
+    }
+
+    pub fn api_version(&self) -> &str {
+        &self.api_version
+    }
+
+    fn stream_request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .stream_request(method, path)
+            .query(&[("api-version", &self.api_version)])
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http
+            .request(method, path)
+            .query(&[("api-version", &self.api_version)])
+    }
+
+    fn body(&self, request: &OpenAIRequest, stream: bool) -> Result<Value, ProviderError> {
+        let mut body = serde_json::to_value(request)
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+        if let Value::Object(ref mut map) = body {
+            map.insert("model".to_string(), json!(self.deployment));
+
+            if stream {
+                map.insert("stream".to_string(), json!(true));
+            }
+        }
+
+        Ok(body)
+    }
+}
+
+/// Builder for an [`AzureOpenAIProvider`].
+///
+/// Either [`AzureOpenAIProviderBuilder::api_key`] or
+/// [`AzureOpenAIProviderBuilder::entra_token`] must be set before building.
+#[derive(Debug)]
+pub struct AzureOpenAIProviderBuilder {
+    auth: Option<AzureAuth>,
+    endpoint: String,
+    deployment: String,
+    api_version: String,
+    http: HttpConfig,
+}
+
+impl AzureOpenAIProviderBuilder {
+    pub fn new(endpoint: impl Into<String>, deployment: impl Into<String>) -> Self {
+        Self {
+            auth: None,
+            endpoint: endpoint.into().trim_end_matches('/').to_string(),
+            deployment: deployment.into(),
+            api_version: AZURE_OPENAI_API_VERSION.to_string(),
+            http: HttpConfig::default(),
+        }
+    }
+
+    pub fn api_key(mut self, value: impl Into<String>) -> Self {
+        self.auth = Some(AzureAuth::ApiKey(value.into()));
+        self
+    }
+
+    pub fn entra_token(mut self, value: impl Into<String>) -> Self {
+        self.auth = Some(AzureAuth::EntraToken(value.into()));
+        self
+    }
+
+    pub fn api_version(mut self, value: impl Into<String>) -> Self {
+        self.api_version = value.into();
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<AzureOpenAIProvider, ProviderError> {
+        let auth = self.auth.ok_or_else(|| {
+            ProviderError::ValidationError("an API key or Entra ID token is required".to_string())
+        })?;
+
+        let http = self.http.build(
+            format!("{}/openai", self.endpoint),
+            match &auth {
+                AzureAuth::ApiKey(api_key) => {
+                    Auth::Header(HeaderName::from_static("api-key"), api_key.clone())
+                }
+                AzureAuth::EntraToken(token) => Auth::Bearer(token.clone()),
+            },
+            HeaderMap::new(),
+        )?;
+
+        Ok(AzureOpenAIProvider {
+            auth,
+            deployment: self.deployment,
+            api_version: self.api_version,
+            http,
+        })
+    }
+}
+
+#[async_trait]
+impl ProviderStrategy for AzureOpenAIProvider {
+    type GenerationRequest = OpenAIRequest;
+    type StreamingRequest = OpenAIRequest;
+    type GenerationResponse = AzureOpenAIResponse;
+    type StreamingResponse = AzureOpenAIStreamingEvent;
+
+    fn get_base_url(&self) -> String {
+        self.http.base_url().to_string()
+    }
+
+    fn get_api_key(&self) -> String {
+        match &self.auth {
+            AzureAuth::ApiKey(value) | AzureAuth::EntraToken(value) => value.clone(),
+        }
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        request.validate_with(None)?;
+
+        http::send(
+            self.request(Method::POST, "/responses")
+                .json(&self.body(request, false)?),
+        )
+        .await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        request.validate_with(None)?;
+
+        http::send_sse(
+            self.stream_request(Method::POST, "/responses")
+                .json(&self.body(request, true)?),
+        )
+        .await
+    }
+}
@@ -0,0 +1,6 @@
+pub const AZURE_OPENAI_API_VERSION: &str = "2025-04-01-preview";
+
+/// The endpoint of an Azure OpenAI resource, e.g. `https://my-resource.openai.azure.com`.
+pub fn resource_endpoint(resource: &str) -> String {
+    format!("https://{}.openai.azure.com", resource)
+}
@@ -0,0 +1,115 @@
+use crate::utils::errors::ApiError;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContentFilterSeverity {
+    Safe,
+    Low,
+    Medium,
+    High,
+    #[serde(other)]
+    Unknown,
+}
+
+/// The outcome of one content filter category.
+///
+/// Harm categories report a `severity`; detection models such as `jailbreak` or
+/// `protected_material_text` report `detected` instead.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ContentFilterResult {
+    #[serde(default)]
+    pub filtered: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<ContentFilterSeverity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detected: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContentFilterResults {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hate: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub self_harm: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sexual: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub violence: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profanity: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jailbreak: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indirect_attack: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_material_text: Option<ContentFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_material_code: Option<ContentFilterResult>,
+}
+
+impl ContentFilterResults {
+    /// Whether any category caused the content to be filtered.
+    pub fn is_filtered(&self) -> bool {
+        [
+            &self.hate,
+            &self.self_harm,
+            &self.sexual,
+            &self.violence,
+            &self.profanity,
+            &self.jailbreak,
+            &self.indirect_attack,
+            &self.protected_material_text,
+            &self.protected_material_code,
+        ]
+        .into_iter()
+        .flatten()
+        .any(|result| result.filtered)
+    }
+
+    /// Reads the results Azure attaches to a rejected prompt, found under
+    /// `innererror.content_filter_result` in the error body.
+    pub fn from_api_error(error: &ApiError) -> Option<Self> {
+        let results = error.inner_error.as_ref()?.get("content_filter_result")?;
+
+        serde_json::from_value(results.clone()).ok()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PromptFilterResult {
+    #[serde(default)]
+    pub prompt_index: usize,
+    pub content_filter_results: ContentFilterResults,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_reads_results_from_api_errors() {
+        let error = ApiError::new(400, "The response was filtered.").inner_error(json!({
+            "code": "ResponsibleAIPolicyViolation",
+            "content_filter_result": {
+                "hate": { "filtered": false, "severity": "safe" },
+                "violence": { "filtered": true, "severity": "high" },
+                "jailbreak": { "filtered": false, "detected": false }
+            }
+        }));
+
+        let results = ContentFilterResults::from_api_error(&error).unwrap();
+
+        assert!(results.is_filtered());
+        assert_eq!(
+            results.violence.unwrap().severity,
+            Some(ContentFilterSeverity::High)
+        );
+        assert_eq!(results.jailbreak.unwrap().detected, Some(false));
+        assert_eq!(
+            ContentFilterResults::from_api_error(&ApiError::new(400, "Bad request")),
+            None
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use crate::azure::content_filter::{ContentFilterResults, PromptFilterResult};
+use crate::openai::response::events::streaming::OpenAIStreamingEvent;
+use crate::openai::types::OpenAIResponse;
+use serde::{Deserialize, Serialize};
+
+/// An [`OpenAIResponse`] together with the content filter results Azure adds to it.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct AzureOpenAIResponse {
+    #[serde(flatten)]
+    pub response: OpenAIResponse,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_filter_results: Vec<PromptFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_filter_results: Option<ContentFilterResults>,
+}
+
+/// An [`OpenAIStreamingEvent`] together with the content filter results Azure adds
+/// to the events it has filtered.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AzureOpenAIStreamingEvent {
+    #[serde(flatten)]
+    pub event: OpenAIStreamingEvent,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prompt_filter_results: Vec<PromptFilterResult>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_filter_results: Option<ContentFilterResults>,
+}
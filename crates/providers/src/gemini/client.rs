@@ -0,0 +1,183 @@
+use crate::gemini::constants::GEMINI_API_URL;
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, Auth, HttpConfig, HttpTransport},
+    provider_strategy::ProviderStrategy,
+};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderName};
+use reqwest::{Method, RequestBuilder};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+use super::types::{GeminiRequest, GeminiResponse};
+
+/// How the API key is sent.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum GeminiAuthScheme {
+    /// The `x-goog-api-key` header.
+    #[default]
+    Header,
+    /// The `key` query parameter.
+    Query,
+}
+
+#[derive(Debug, Clone)]
+pub struct GeminiProvider {
+    api_key: String,
+    http: HttpTransport,
+}
+
+impl GeminiProvider {
+    pub fn new(api_key: String) -> Self {
+        GeminiProvider {
+            http: HttpTransport::new(
+                GEMINI_API_URL,
+                auth(&api_key, &GeminiAuthScheme::Header),
+                HeaderMap::new(),
+            ),
+            api_key,
+        }
+    }
+
+    pub fn builder(api_key: impl Into<String>) -> GeminiProviderBuilder {
+        GeminiProviderBuilder::new(api_key)
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, path)
+    }
+}
+
+fn auth(api_key: &str, scheme: &GeminiAuthScheme) -> Auth {
+    match scheme {
+        GeminiAuthScheme::Header => Auth::Header(
+            HeaderName::from_static("x-goog-api-key"),
+            api_key.to_string(),
+        ),
+        GeminiAuthScheme::Query => Auth::Query("key", api_key.to_string()),
+    }
+}
+
+/// Model ids are accepted with or without the `models/` prefix.
+fn model_path(model: &str, method: &str) -> String {
+    format!("/models/{}:{}", model.trim_start_matches("models/"), method)
+}
+
+/// Builder for a [`GeminiProvider`] with a custom endpoint, headers and HTTP client.
+#[derive(Debug)]
+pub struct GeminiProviderBuilder {
+    api_key: String,
+    auth_scheme: GeminiAuthScheme,
+    base_url: String,
+    http: HttpConfig,
+}
+
+impl GeminiProviderBuilder {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            auth_scheme: GeminiAuthScheme::default(),
+            base_url: GEMINI_API_URL.to_string(),
+            http: HttpConfig::default(),
+        }
+    }
+
+    pub fn base_url(mut self, value: impl Into<String>) -> Self {
+        self.base_url = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn auth_scheme(mut self, value: GeminiAuthScheme) -> Self {
+        self.auth_scheme = value;
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<GeminiProvider, ProviderError> {
+        let http = self.http.build(
+            self.base_url,
+            auth(&self.api_key, &self.auth_scheme),
+            HeaderMap::new(),
+        )?;
+
+        Ok(GeminiProvider {
+            api_key: self.api_key,
+            http,
+        })
+    }
+}
+
+#[async_trait]
+impl ProviderStrategy for GeminiProvider {
+    type GenerationRequest = GeminiRequest;
+    type StreamingRequest = GeminiRequest;
+    type GenerationResponse = GeminiResponse;
+    type StreamingResponse = GeminiResponse;
+
+    fn get_base_url(&self) -> String {
+        self.http.base_url().to_string()
+    }
+
+    fn get_api_key(&self) -> String {
+        self.api_key.clone()
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        http::send(
+            self.request(
+                Method::POST,
+                &model_path(request.model(), "generateContent"),
+            )
+            .json(request),
+        )
+        .await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        http::send_sse(
+            self.http
+                .stream_request(
+                    Method::POST,
+                    &model_path(request.model(), "streamGenerateContent"),
+                )
+                .query(&[("alt", "sse")])
+                .json(request),
+        )
+        .await
+    }
+}
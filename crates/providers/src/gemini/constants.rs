@@ -0,0 +1 @@
+pub const GEMINI_API_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
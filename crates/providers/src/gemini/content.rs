@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GeminiRole {
+    User,
+    Model,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Content {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role: Option<GeminiRole>,
+    #[serde(default)]
+    pub parts: Vec<Part>,
+}
+
+impl Content {
+    pub fn user(parts: Vec<Part>) -> Self {
+        Self {
+            role: Some(GeminiRole::User),
+            parts,
+        }
+    }
+
+    pub fn model(parts: Vec<Part>) -> Self {
+        Self {
+            role: Some(GeminiRole::Model),
+            parts,
+        }
+    }
+
+    /// Content without a role, as used for `systemInstruction`.
+    pub fn system(text: impl Into<String>) -> Self {
+        Self {
+            role: None,
+            parts: vec![Part::text(text)],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Part {
+    #[serde(flatten)]
+    pub data: PartData,
+    /// Set on parts that contain the model's thoughts rather than its answer.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thought: Option<bool>,
+    /// Opaque signature that must be sent back with the part in the next turn.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thought_signature: Option<String>,
+}
+
+impl Part {
+    pub fn new(data: PartData) -> Self {
+        Self {
+            data,
+            thought: None,
+            thought_signature: None,
+        }
+    }
+
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::new(PartData::Text(text.into()))
+    }
+
+    /// Inline bytes, already base64 encoded.
+    pub fn inline_data(mime_type: impl Into<String>, data: impl Into<String>) -> Self {
+        Self::new(PartData::InlineData(Blob {
+            mime_type: mime_type.into(),
+            data: data.into(),
+        }))
+    }
+
+    pub fn file_data(mime_type: impl Into<String>, file_uri: impl Into<String>) -> Self {
+        Self::new(PartData::FileData(FileData {
+            mime_type: Some(mime_type.into()),
+            file_uri: file_uri.into(),
+        }))
+    }
+
+    pub fn function_call(name: impl Into<String>, args: Value) -> Self {
+        Self::new(PartData::FunctionCall(FunctionCall {
+            id: None,
+            name: name.into(),
+            args,
+        }))
+    }
+
+    pub fn function_response(name: impl Into<String>, response: Value) -> Self {
+        Self::new(PartData::FunctionResponse(FunctionResponse {
+            id: None,
+            name: name.into(),
+            response,
+        }))
+    }
+
+    pub fn is_thought(&self) -> bool {
+        self.thought.unwrap_or(false)
+    }
+
+    pub fn as_text(&self) -> Option<&str> {
+        match &self.data {
+            PartData::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PartData {
+    Text(String),
+    InlineData(Blob),
+    FileData(FileData),
+    FunctionCall(FunctionCall),
+    FunctionResponse(FunctionResponse),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Blob {
+    pub mime_type: String,
+    pub data: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileData {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mime_type: Option<String>,
+    pub file_uri: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunctionCall {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default)]
+    pub args: Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunctionResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    pub response: Value,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_values() {
+        let content = Content::user(vec![
+            Part::text("What is in this image?"),
+            Part::inline_data("image/png", "aGVsbG8="),
+            Part::file_data("application/pdf", "https://example.com/file.pdf"),
+            Part::function_response("get_weather", json!({ "temperature": 18 })),
+        ]);
+
+        assert_eq!(
+            serde_json::to_value(&content).unwrap(),
+            json!({
+                "role": "user",
+                "parts": [
+                    { "text": "What is in this image?" },
+                    { "inlineData": { "mimeType": "image/png", "data": "aGVsbG8=" } },
+                    { "fileData": { "mimeType": "application/pdf", "fileUri": "https://example.com/file.pdf" } },
+                    { "functionResponse": { "name": "get_weather", "response": { "temperature": 18 } } }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn it_deserializes_thought_parts() {
+        let part: Part = serde_json::from_value(json!({
+            "text": "Thinking about it.",
+            "thought": true,
+            "thoughtSignature": "sig"
+        }))
+        .unwrap();
+
+        assert!(part.is_thought());
+        assert_eq!(part.as_text(), Some("Thinking about it."));
+        assert_eq!(part.thought_signature.as_deref(), Some("sig"));
+    }
+}
@@ -0,0 +1,509 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::gemini::content::{Content, FunctionCall, PartData};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunctionDeclaration {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// An OpenAPI 3.0 schema object describing the arguments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+}
+
+impl FunctionDeclaration {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            parameters: None,
+        }
+    }
+
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.description = Some(value.into());
+        self
+    }
+
+    pub fn parameters(mut self, value: Value) -> Self {
+        self.parameters = Some(value);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiTool {
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub function_declarations: Vec<FunctionDeclaration>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FunctionCallingMode {
+    Auto,
+    Any,
+    None,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FunctionCallingConfig {
+    pub mode: FunctionCallingMode,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_function_names: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToolConfig {
+    pub function_calling_config: FunctionCallingConfig,
+}
+
+impl ToolConfig {
+    pub fn mode(mode: FunctionCallingMode) -> Self {
+        Self {
+            function_calling_config: FunctionCallingConfig {
+                mode,
+                allowed_function_names: None,
+            },
+        }
+    }
+
+    /// Forces a call to one of the named functions.
+    pub fn allowed(names: Vec<String>) -> Self {
+        Self {
+            function_calling_config: FunctionCallingConfig {
+                mode: FunctionCallingMode::Any,
+                allowed_function_names: Some(names),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HarmCategory {
+    HarmCategoryHarassment,
+    HarmCategoryHateSpeech,
+    HarmCategorySexuallyExplicit,
+    HarmCategoryDangerousContent,
+    HarmCategoryCivicIntegrity,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HarmBlockThreshold {
+    BlockLowAndAbove,
+    BlockMediumAndAbove,
+    BlockOnlyHigh,
+    BlockNone,
+    Off,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum HarmProbability {
+    Negligible,
+    Low,
+    Medium,
+    High,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SafetySetting {
+    pub category: HarmCategory,
+    pub threshold: HarmBlockThreshold,
+}
+
+impl SafetySetting {
+    pub fn new(category: HarmCategory, threshold: HarmBlockThreshold) -> Self {
+        Self {
+            category,
+            threshold,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SafetyRating {
+    pub category: HarmCategory,
+    pub probability: HarmProbability,
+    #[serde(default)]
+    pub blocked: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ThinkingConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_budget: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_thoughts: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerationConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop_sequences: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_mime_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_schema: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub candidate_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_output_tokens: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking_config: Option<ThinkingConfig>,
+}
+
+impl GenerationConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_stop_sequence(mut self, value: impl Into<String>) -> Self {
+        match self.stop_sequences {
+            Some(ref mut stop_sequences) => stop_sequences.push(value.into()),
+            None => self.stop_sequences = Some(vec![value.into()]),
+        }
+        self
+    }
+
+    /// Constrains the output to JSON matching an OpenAPI 3.0 schema object.
+    pub fn response_schema(mut self, value: Value) -> Self {
+        self.response_mime_type = Some("application/json".to_string());
+        self.response_schema = Some(value);
+        self
+    }
+
+    pub fn response_mime_type(mut self, value: impl Into<String>) -> Self {
+        self.response_mime_type = Some(value.into());
+        self
+    }
+
+    pub fn candidate_count(mut self, value: usize) -> Self {
+        self.candidate_count = Some(value);
+        self
+    }
+
+    pub fn max_output_tokens(mut self, value: usize) -> Self {
+        self.max_output_tokens = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn top_k(mut self, value: usize) -> Self {
+        self.top_k = Some(value);
+        self
+    }
+
+    pub fn presence_penalty(mut self, value: f32) -> Self {
+        self.presence_penalty = Some(value);
+        self
+    }
+
+    pub fn frequency_penalty(mut self, value: f32) -> Self {
+        self.frequency_penalty = Some(value);
+        self
+    }
+
+    pub fn seed(mut self, value: i64) -> Self {
+        self.seed = Some(value);
+        self
+    }
+
+    pub fn thinking_config(mut self, value: ThinkingConfig) -> Self {
+        self.thinking_config = Some(value);
+        self
+    }
+}
+
+/// A `generateContent` request. The model is part of the URL rather than the body.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiRequest {
+    #[serde(skip)]
+    model: String,
+    contents: Vec<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system_instruction: Option<Content>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<GeminiTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_config: Option<ToolConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    safety_settings: Option<Vec<SafetySetting>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation_config: Option<GenerationConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cached_content: Option<String>,
+}
+
+impl GeminiRequest {
+    pub fn new(model: impl Into<String>, contents: Vec<Content>) -> Self {
+        Self {
+            model: model.into(),
+            contents,
+            ..Default::default()
+        }
+    }
+
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    pub fn add_content(mut self, value: Content) -> Self {
+        self.contents.push(value);
+        self
+    }
+
+    pub fn system_instruction(mut self, value: impl Into<String>) -> Self {
+        self.system_instruction = Some(Content::system(value));
+        self
+    }
+
+    pub fn add_function(mut self, value: FunctionDeclaration) -> Self {
+        let tools = self.tools.get_or_insert_with(Vec::new);
+
+        match tools.first_mut() {
+            Some(tool) => tool.function_declarations.push(value),
+            None => tools.push(GeminiTool {
+                function_declarations: vec![value],
+            }),
+        }
+        self
+    }
+
+    pub fn tool_config(mut self, value: ToolConfig) -> Self {
+        self.tool_config = Some(value);
+        self
+    }
+
+    pub fn add_safety_setting(mut self, value: SafetySetting) -> Self {
+        match self.safety_settings {
+            Some(ref mut safety_settings) => safety_settings.push(value),
+            None => self.safety_settings = Some(vec![value]),
+        }
+        self
+    }
+
+    pub fn generation_config(mut self, value: GenerationConfig) -> Self {
+        self.generation_config = Some(value);
+        self
+    }
+
+    /// Name of a cached content resource, e.g. `cachedContents/abc123`.
+    pub fn cached_content(mut self, value: impl Into<String>) -> Self {
+        self.cached_content = Some(value.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FinishReason {
+    Stop,
+    MaxTokens,
+    Safety,
+    Recitation,
+    Language,
+    Other,
+    Blocklist,
+    ProhibitedContent,
+    Spii,
+    MalformedFunctionCall,
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Candidate {
+    #[serde(default)]
+    pub content: Content,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub finish_reason: Option<FinishReason>,
+    #[serde(default)]
+    pub safety_ratings: Vec<SafetyRating>,
+    #[serde(default)]
+    pub index: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptFeedback {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_reason: Option<String>,
+    #[serde(default)]
+    pub safety_ratings: Vec<SafetyRating>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UsageMetadata {
+    #[serde(default)]
+    pub prompt_token_count: usize,
+    #[serde(default)]
+    pub candidates_token_count: usize,
+    #[serde(default)]
+    pub total_token_count: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cached_content_token_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thoughts_token_count: Option<usize>,
+}
+
+/// A `generateContent` response, also sent as each chunk of `streamGenerateContent`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GeminiResponse {
+    #[serde(default)]
+    pub candidates: Vec<Candidate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_feedback: Option<PromptFeedback>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage_metadata: Option<UsageMetadata>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_id: Option<String>,
+}
+
+impl GeminiResponse {
+    /// The answer text of the first candidate, leaving out thoughts.
+    pub fn text(&self) -> String {
+        self.candidates
+            .first()
+            .map(|candidate| {
+                candidate
+                    .content
+                    .parts
+                    .iter()
+                    .filter(|part| !part.is_thought())
+                    .filter_map(|part| part.as_text())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn function_calls(&self) -> Vec<&FunctionCall> {
+        self.candidates
+            .first()
+            .map(|candidate| {
+                candidate
+                    .content
+                    .parts
+                    .iter()
+                    .filter_map(|part| match &part.data {
+                        PartData::FunctionCall(call) => Some(call),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gemini::content::Part;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_values() {
+        let request = GeminiRequest::new(
+            "gemini-2.5-flash",
+            vec![Content::user(vec![Part::text("Weather in Paris?")])],
+        )
+        .system_instruction("You are terse.")
+        .add_function(
+            FunctionDeclaration::new("get_weather")
+                .description("Looks up the weather.")
+                .parameters(json!({ "type": "object" })),
+        )
+        .tool_config(ToolConfig::mode(FunctionCallingMode::Auto))
+        .add_safety_setting(SafetySetting::new(
+            HarmCategory::HarmCategoryHarassment,
+            HarmBlockThreshold::BlockOnlyHigh,
+        ))
+        .generation_config(
+            GenerationConfig::new()
+                .temperature(0.5)
+                .response_schema(json!({ "type": "object" })),
+        );
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "contents": [{ "role": "user", "parts": [{ "text": "Weather in Paris?" }] }],
+                "systemInstruction": { "parts": [{ "text": "You are terse." }] },
+                "tools": [{
+                    "functionDeclarations": [{
+                        "name": "get_weather",
+                        "description": "Looks up the weather.",
+                        "parameters": { "type": "object" }
+                    }]
+                }],
+                "toolConfig": { "functionCallingConfig": { "mode": "AUTO" } },
+                "safetySettings": [
+                    { "category": "HARM_CATEGORY_HARASSMENT", "threshold": "BLOCK_ONLY_HIGH" }
+                ],
+                "generationConfig": {
+                    "temperature": 0.5,
+                    "responseMimeType": "application/json",
+                    "responseSchema": { "type": "object" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn it_deserializes_blocked_prompts() {
+        let response: GeminiResponse = serde_json::from_value(json!({
+            "promptFeedback": {
+                "blockReason": "SAFETY",
+                "safetyRatings": [{ "category": "HARM_CATEGORY_DANGEROUS_CONTENT", "probability": "HIGH", "blocked": true }]
+            },
+            "usageMetadata": { "promptTokenCount": 8, "totalTokenCount": 8 }
+        }))
+        .unwrap();
+
+        assert!(response.candidates.is_empty());
+        assert_eq!(response.text(), "");
+        assert_eq!(
+            response.prompt_feedback.unwrap().block_reason.as_deref(),
+            Some("SAFETY")
+        );
+    }
+}
@@ -1,12 +1,64 @@
 pub use crate::utils::provider_strategy::ProviderStrategy;
-pub use openai::client::OpenAIProvider;
+pub use crate::utils::retry::{Retry, RetryPolicy};
+pub use anthropic::client::{AnthropicProvider, AnthropicProviderBuilder};
+pub use anthropic::types::{AnthropicRequest, AnthropicResponse};
+pub use azure::client::{AzureOpenAIProvider, AzureOpenAIProviderBuilder};
+pub use azure::types::{AzureOpenAIResponse, AzureOpenAIStreamingEvent};
+pub use gemini::client::{GeminiProvider, GeminiProviderBuilder};
+pub use gemini::types::{GeminiRequest, GeminiResponse};
+pub use ollama::client::{OllamaProvider, OllamaProviderBuilder};
+pub use ollama::types::{OllamaChatRequest, OllamaChatResponse};
+pub use openai::client::{OpenAIProvider, OpenAIProviderBuilder};
+pub use openai::tool_executor::{Continuation, ToolExecutor, ToolHandler};
 pub use openai::types::{OpenAIRequest, OpenAIResponse};
+pub use openai_compatible::client::{OpenAICompatibleProvider, OpenAICompatibleProviderBuilder};
+pub use openai_compatible::types::ChatCompletionRequest;
+pub use schemars::{self, JsonSchema};
+
+pub mod anthropic {
+    pub mod client;
+    pub mod constants;
+    pub mod content;
+    pub mod streaming;
+    pub mod types;
+}
+
+pub mod azure {
+    pub mod client;
+    pub mod constants;
+    pub mod content_filter;
+    pub mod types;
+}
+
+pub mod gemini {
+    pub mod client;
+    pub mod constants;
+    pub mod content;
+    pub mod types;
+}
+
+pub mod ollama {
+    pub mod client;
+    pub mod constants;
+    pub mod models;
+    pub mod types;
+}
 
 pub mod openai {
+    pub mod background;
+    pub mod capabilities;
     pub mod client;
     pub mod constants;
+    pub mod embeddings;
     pub mod errors;
+    pub mod images;
+    pub mod lifecycle;
+    pub mod moderation;
+    pub mod speech;
+    pub mod tool_executor;
+    pub mod transcription;
     pub mod types;
+    pub mod upload;
     pub mod common {
         pub mod computer_tool_call_item;
         pub mod file_search_tool_item;
@@ -33,6 +85,7 @@ pub mod openai {
         }
     }
     pub mod response {
+        pub mod accumulator;
         pub mod incomplete_details;
         pub mod response_error;
         pub mod response_output;
@@ -43,7 +96,19 @@ pub mod openai {
     }
 }
 
+pub mod openai_compatible {
+    pub mod client;
+    pub mod quirks;
+    pub mod response;
+    pub mod types;
+}
+
 pub mod utils {
     pub mod errors;
+    pub mod http;
+    pub mod ndjson;
     pub mod provider_strategy;
+    pub mod retry;
+    pub mod schema;
+    pub mod sse;
 }
@@ -0,0 +1,250 @@
+use crate::ollama::constants::OLLAMA_BASE_URL;
+use crate::ollama::models::{OllamaModelList, PullProgress, PullRequest};
+use crate::ollama::types::{
+    OllamaChatRequest, OllamaChatResponse, OllamaGenerateRequest, OllamaGenerateResponse,
+};
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, Auth, HttpConfig, HttpTransport},
+    ndjson::decode_ndjson_stream,
+    provider_strategy::ProviderStrategy,
+};
+use async_trait::async_trait;
+use futures::stream::StreamExt;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+type NdjsonStream<T> = Pin<Box<dyn Stream<Item = Result<T, ProviderError>> + Send>>;
+
+/// Provider for a local (or self-hosted) Ollama server.
+///
+/// Ollama needs no credentials by default; an API key is only sent, as a bearer
+/// token, when one is configured for a server behind an authenticating proxy.
+#[derive(Debug, Clone)]
+pub struct OllamaProvider {
+    api_key: Option<String>,
+    http: HttpTransport,
+}
+
+impl Default for OllamaProvider {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OllamaProvider {
+    pub fn new() -> Self {
+        OllamaProvider {
+            api_key: None,
+            http: HttpTransport::new(OLLAMA_BASE_URL, Auth::None, HeaderMap::new()),
+        }
+    }
+
+    pub fn builder() -> OllamaProviderBuilder {
+        OllamaProviderBuilder::new()
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, path)
+    }
+
+    async fn post<B, T>(&self, path: &str, body: &B) -> Result<T, ProviderError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned,
+    {
+        let response_bytes = http::send_raw(
+            self.request(Method::POST, path)
+                .json(&with_stream(body, false)?),
+        )
+        .await?;
+
+        parse_line(&response_bytes)
+    }
+
+    async fn post_stream<B, T>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<NdjsonStream<T>, ProviderError>
+    where
+        B: Serialize + ?Sized,
+        T: DeserializeOwned + Send + 'static,
+    {
+        let response = http::execute(
+            self.http
+                .stream_request(Method::POST, path)
+                .json(&with_stream(body, true)?),
+        )
+        .await?;
+
+        let parsed_stream = decode_ndjson_stream(response.bytes_stream())
+            .map(|line| line.and_then(|line| parse_line(line.as_bytes())));
+
+        Ok(Box::pin(parsed_stream))
+    }
+
+    /// Sends a single completion request to `/api/generate`.
+    pub async fn complete(
+        &self,
+        request: &OllamaGenerateRequest,
+    ) -> Result<OllamaGenerateResponse, ProviderError> {
+        self.post("/api/generate", request).await
+    }
+
+    /// Streams a completion from `/api/generate`, one chunk per line.
+    pub async fn stream_completion(
+        &self,
+        request: &OllamaGenerateRequest,
+    ) -> Result<NdjsonStream<OllamaGenerateResponse>, ProviderError> {
+        self.post_stream("/api/generate", request).await
+    }
+
+    /// Lists the models available on the server.
+    pub async fn list_models(&self) -> Result<OllamaModelList, ProviderError> {
+        http::send(self.request(Method::GET, "/api/tags")).await
+    }
+
+    /// Downloads a model from the registry, reporting progress as it goes.
+    ///
+    /// The request timeout is not applied, since pulls can take a long time.
+    pub async fn pull_model(
+        &self,
+        model: impl Into<String>,
+    ) -> Result<NdjsonStream<PullProgress>, ProviderError> {
+        let request = PullRequest {
+            model: model.into(),
+            insecure: None,
+            stream: true,
+        };
+
+        self.post_stream("/api/pull", &request).await
+    }
+}
+
+fn with_stream<B: Serialize + ?Sized>(body: &B, stream: bool) -> Result<Value, ProviderError> {
+    let mut value =
+        serde_json::to_value(body).map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+    if let Value::Object(ref mut map) = value {
+        map.insert("stream".to_string(), Value::Bool(stream));
+    }
+
+    Ok(value)
+}
+
+#[derive(Deserialize)]
+struct StreamError {
+    error: String,
+}
+
+/// Parses one JSON value, turning the `{"error": ...}` objects Ollama writes into
+/// the stream when a request fails part way into an error.
+fn parse_line<T: DeserializeOwned>(line: &[u8]) -> Result<T, ProviderError> {
+    if let Ok(StreamError { error }) = serde_json::from_slice(line) {
+        return Err(ProviderError::Other(error));
+    }
+
+    serde_json::from_slice(line).map_err(|e| ProviderError::DeserializationError(e.to_string()))
+}
+
+/// Builder for an [`OllamaProvider`] with a custom endpoint, headers and HTTP client.
+#[derive(Debug, Default)]
+pub struct OllamaProviderBuilder {
+    api_key: Option<String>,
+    base_url: Option<String>,
+    http: HttpConfig,
+}
+
+impl OllamaProviderBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn api_key(mut self, value: impl Into<String>) -> Self {
+        self.api_key = Some(value.into());
+        self
+    }
+
+    pub fn base_url(mut self, value: impl Into<String>) -> Self {
+        self.base_url = Some(value.into().trim_end_matches('/').to_string());
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<OllamaProvider, ProviderError> {
+        let base_url = self.base_url.unwrap_or_else(|| OLLAMA_BASE_URL.to_string());
+        let auth = match &self.api_key {
+            Some(api_key) => Auth::Bearer(api_key.clone()),
+            None => Auth::None,
+        };
+
+        Ok(OllamaProvider {
+            api_key: self.api_key,
+            http: self.http.build(base_url, auth, HeaderMap::new())?,
+        })
+    }
+}
+
+#[async_trait]
+impl ProviderStrategy for OllamaProvider {
+    type GenerationRequest = OllamaChatRequest;
+    type StreamingRequest = OllamaChatRequest;
+    type GenerationResponse = OllamaChatResponse;
+    type StreamingResponse = OllamaChatResponse;
+
+    fn get_base_url(&self) -> String {
+        self.http.base_url().to_string()
+    }
+
+    fn get_api_key(&self) -> String {
+        self.api_key.clone().unwrap_or_default()
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        self.post("/api/chat", request).await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        self.post_stream("/api/chat", request).await
+    }
+}
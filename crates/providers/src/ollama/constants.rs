@@ -0,0 +1 @@
+pub const OLLAMA_BASE_URL: &str = "http://localhost:11434";
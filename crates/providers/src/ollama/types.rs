@@ -0,0 +1,492 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::utils::schema::schema_for;
+use schemars::JsonSchema;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OllamaRole {
+    System,
+    User,
+    Assistant,
+    Tool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaFunctionCall {
+    pub name: String,
+    #[serde(default)]
+    pub arguments: Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaToolCall {
+    pub function: OllamaFunctionCall,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaMessage {
+    pub role: OllamaRole,
+    #[serde(default)]
+    pub content: String,
+    /// Base64 encoded images, for multimodal models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub images: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_calls: Option<Vec<OllamaToolCall>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<String>,
+    /// The function a `tool` message answers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tool_name: Option<String>,
+}
+
+impl OllamaMessage {
+    pub fn new(role: OllamaRole, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: content.into(),
+            images: None,
+            tool_calls: None,
+            thinking: None,
+            tool_name: None,
+        }
+    }
+
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::new(OllamaRole::System, content)
+    }
+
+    pub fn user(content: impl Into<String>) -> Self {
+        Self::new(OllamaRole::User, content)
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self::new(OllamaRole::Assistant, content)
+    }
+
+    pub fn tool(tool_name: impl Into<String>, content: impl Into<String>) -> Self {
+        Self {
+            tool_name: Some(tool_name.into()),
+            ..Self::new(OllamaRole::Tool, content)
+        }
+    }
+
+    pub fn add_image(mut self, base64: impl Into<String>) -> Self {
+        match self.images {
+            Some(ref mut images) => images.push(base64.into()),
+            None => self.images = Some(vec![base64.into()]),
+        }
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaFunction {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub parameters: Value,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaTool {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub function: OllamaFunction,
+}
+
+impl OllamaTool {
+    pub fn function(name: impl Into<String>, parameters: Value) -> Self {
+        Self {
+            type_field: "function".to_string(),
+            function: OllamaFunction {
+                name: name.into(),
+                description: None,
+                parameters,
+            },
+        }
+    }
+
+    /// Builds a function tool whose parameters are derived from `T`, keeping its doc
+    /// comment as the description.
+    pub fn from_type<T: JsonSchema>(name: impl Into<String>) -> Self {
+        let mut parameters = schema_for::<T>();
+        let description = parameters
+            .as_object_mut()
+            .and_then(|schema| {
+                schema.remove("$schema");
+                schema.remove("title");
+                schema.remove("description")
+            })
+            .and_then(|description| description.as_str().map(str::to_string));
+
+        let mut tool = Self::function(name, parameters);
+        tool.function.description = description;
+        tool
+    }
+
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.function.description = Some(value.into());
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum JsonMode {
+    Json,
+}
+
+/// Constrains the output to any JSON value or to a JSON schema.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OllamaFormat {
+    Json(JsonMode),
+    Schema(Value),
+}
+
+impl OllamaFormat {
+    pub fn json() -> Self {
+        Self::Json(JsonMode::Json)
+    }
+
+    pub fn schema(value: Value) -> Self {
+        Self::Schema(value)
+    }
+
+    pub fn from_type<T: JsonSchema>() -> Self {
+        Self::Schema(schema_for::<T>())
+    }
+}
+
+/// How long the model stays loaded after the request, as a duration string such as
+/// `"10m"` or a number of seconds. A negative value keeps it loaded indefinitely and
+/// zero unloads it immediately.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum KeepAlive {
+    Seconds(i64),
+    Duration(String),
+}
+
+impl KeepAlive {
+    pub fn forever() -> Self {
+        Self::Seconds(-1)
+    }
+}
+
+impl From<Duration> for KeepAlive {
+    fn from(value: Duration) -> Self {
+        Self::Seconds(value.as_secs() as i64)
+    }
+}
+
+impl From<&str> for KeepAlive {
+    fn from(value: &str) -> Self {
+        Self::Duration(value.to_string())
+    }
+}
+
+/// Model parameters; anything left unset uses the value from the Modelfile.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OllamaOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_ctx: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_predict: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub seed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repeat_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stop: Option<Vec<String>>,
+}
+
+impl OllamaOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn top_k(mut self, value: usize) -> Self {
+        self.top_k = Some(value);
+        self
+    }
+
+    /// Size of the context window in tokens.
+    pub fn num_ctx(mut self, value: usize) -> Self {
+        self.num_ctx = Some(value);
+        self
+    }
+
+    /// Maximum number of tokens to generate; `-1` means no limit.
+    pub fn num_predict(mut self, value: i64) -> Self {
+        self.num_predict = Some(value);
+        self
+    }
+
+    pub fn seed(mut self, value: i64) -> Self {
+        self.seed = Some(value);
+        self
+    }
+
+    pub fn repeat_penalty(mut self, value: f32) -> Self {
+        self.repeat_penalty = Some(value);
+        self
+    }
+
+    pub fn add_stop(mut self, value: impl Into<String>) -> Self {
+        match self.stop {
+            Some(ref mut stop) => stop.push(value.into()),
+            None => self.stop = Some(vec![value.into()]),
+        }
+        self
+    }
+}
+
+/// A `/api/chat` request. Whether the response is streamed is decided by the
+/// provider method used to send it.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OllamaChatRequest {
+    model: String,
+    messages: Vec<OllamaMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<OllamaTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<OllamaFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<KeepAlive>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    think: Option<bool>,
+}
+
+impl OllamaChatRequest {
+    pub fn new(model: impl Into<String>, messages: Vec<OllamaMessage>) -> Self {
+        Self {
+            model: model.into(),
+            messages,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_message(mut self, value: OllamaMessage) -> Self {
+        self.messages.push(value);
+        self
+    }
+
+    pub fn add_tool(mut self, value: OllamaTool) -> Self {
+        match self.tools {
+            Some(ref mut tools) => tools.push(value),
+            None => self.tools = Some(vec![value]),
+        }
+        self
+    }
+
+    pub fn format(mut self, value: OllamaFormat) -> Self {
+        self.format = Some(value);
+        self
+    }
+
+    pub fn options(mut self, value: OllamaOptions) -> Self {
+        self.options = Some(value);
+        self
+    }
+
+    pub fn keep_alive(mut self, value: impl Into<KeepAlive>) -> Self {
+        self.keep_alive = Some(value.into());
+        self
+    }
+
+    pub fn think(mut self, value: bool) -> Self {
+        self.think = Some(value);
+        self
+    }
+}
+
+/// Timings in nanoseconds and token counts, sent with the final chunk.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OllamaMetrics {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_eval_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prompt_eval_duration: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eval_count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eval_duration: Option<u64>,
+}
+
+/// A `/api/chat` response, also sent as each line of a streamed response.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaChatResponse {
+    pub model: String,
+    pub created_at: String,
+    pub message: OllamaMessage,
+    pub done: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_reason: Option<String>,
+    #[serde(flatten)]
+    pub metrics: OllamaMetrics,
+}
+
+/// A `/api/generate` request for a single completion without chat history.
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OllamaGenerateRequest {
+    model: String,
+    prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    system: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    images: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    format: Option<OllamaFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<OllamaOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    keep_alive: Option<KeepAlive>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    raw: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    think: Option<bool>,
+}
+
+impl OllamaGenerateRequest {
+    pub fn new(model: impl Into<String>, prompt: impl Into<String>) -> Self {
+        Self {
+            model: model.into(),
+            prompt: prompt.into(),
+            ..Default::default()
+        }
+    }
+
+    pub fn suffix(mut self, value: impl Into<String>) -> Self {
+        self.suffix = Some(value.into());
+        self
+    }
+
+    pub fn system(mut self, value: impl Into<String>) -> Self {
+        self.system = Some(value.into());
+        self
+    }
+
+    pub fn add_image(mut self, base64: impl Into<String>) -> Self {
+        match self.images {
+            Some(ref mut images) => images.push(base64.into()),
+            None => self.images = Some(vec![base64.into()]),
+        }
+        self
+    }
+
+    pub fn format(mut self, value: OllamaFormat) -> Self {
+        self.format = Some(value);
+        self
+    }
+
+    pub fn options(mut self, value: OllamaOptions) -> Self {
+        self.options = Some(value);
+        self
+    }
+
+    pub fn keep_alive(mut self, value: impl Into<KeepAlive>) -> Self {
+        self.keep_alive = Some(value.into());
+        self
+    }
+
+    /// Sends the prompt without applying the model's prompt template.
+    pub fn raw(mut self, value: bool) -> Self {
+        self.raw = Some(value);
+        self
+    }
+
+    pub fn think(mut self, value: bool) -> Self {
+        self.think = Some(value);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OllamaGenerateResponse {
+    pub model: String,
+    pub created_at: String,
+    #[serde(default)]
+    pub response: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thinking: Option<String>,
+    pub done: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub done_reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context: Option<Vec<i64>>,
+    #[serde(flatten)]
+    pub metrics: OllamaMetrics,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_values() {
+        let request = OllamaChatRequest::new(
+            "llama3.2",
+            vec![OllamaMessage::user("What is in this image?").add_image("aGVsbG8=")],
+        )
+        .add_tool(OllamaTool::function(
+            "get_weather",
+            json!({ "type": "object" }),
+        ))
+        .format(OllamaFormat::json())
+        .options(OllamaOptions::new().temperature(0.0).num_ctx(8192).seed(42))
+        .keep_alive("10m");
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "llama3.2",
+                "messages": [{ "role": "user", "content": "What is in this image?", "images": ["aGVsbG8="] }],
+                "tools": [{ "type": "function", "function": { "name": "get_weather", "parameters": { "type": "object" } } }],
+                "format": "json",
+                "options": { "temperature": 0.0, "num_ctx": 8192, "seed": 42 },
+                "keep_alive": "10m"
+            })
+        );
+    }
+
+    #[test]
+    fn it_serializes_schema_formats_and_keep_alive_durations() {
+        let request = OllamaGenerateRequest::new("llama3.2", "Hi")
+            .format(OllamaFormat::schema(json!({ "type": "object" })))
+            .keep_alive(Duration::from_secs(300));
+
+        let value = serde_json::to_value(&request).unwrap();
+        assert_eq!(value["format"], json!({ "type": "object" }));
+        assert_eq!(value["keep_alive"], json!(300));
+    }
+}
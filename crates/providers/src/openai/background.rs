@@ -0,0 +1,144 @@
+use crate::openai::response::events::streaming::OpenAIStreamingEvent;
+use crate::utils::errors::ProviderError;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::Instant;
+use tokio_stream::Stream;
+
+pub type SequencedEventStream =
+    Pin<Box<dyn Stream<Item = Result<SequencedEvent, ProviderError>> + Send>>;
+
+/// How a background response is polled until it finishes.
+///
+/// The delay between polls starts at `poll_interval` and grows by `multiplier` up
+/// to `max_poll_interval`. When a `deadline` is set and passes, the response is
+/// cancelled (unless `cancel_on_deadline` is off) and a
+/// [`ProviderError::Timeout`](crate::utils::errors::ProviderError::Timeout) is
+/// returned.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundOptions {
+    pub(crate) poll_interval: Duration,
+    pub(crate) max_poll_interval: Duration,
+    pub(crate) multiplier: f64,
+    pub(crate) deadline: Option<Duration>,
+    pub(crate) cancel_on_deadline: bool,
+}
+
+impl Default for BackgroundOptions {
+    fn default() -> Self {
+        Self {
+            poll_interval: Duration::from_secs(1),
+            max_poll_interval: Duration::from_secs(10),
+            multiplier: 1.5,
+            deadline: None,
+            cancel_on_deadline: true,
+        }
+    }
+}
+
+impl BackgroundOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn poll_interval(mut self, value: Duration) -> Self {
+        self.poll_interval = value;
+        self
+    }
+
+    pub fn max_poll_interval(mut self, value: Duration) -> Self {
+        self.max_poll_interval = value;
+        self
+    }
+
+    pub fn multiplier(mut self, value: f64) -> Self {
+        self.multiplier = value;
+        self
+    }
+
+    pub fn deadline(mut self, value: Duration) -> Self {
+        self.deadline = Some(value);
+        self
+    }
+
+    pub fn cancel_on_deadline(mut self, value: bool) -> Self {
+        self.cancel_on_deadline = value;
+        self
+    }
+
+    /// The instant the deadline passes, counted from now.
+    pub(crate) fn deadline_from_now(&self) -> Option<Instant> {
+        self.deadline.map(|deadline| Instant::now() + deadline)
+    }
+
+    pub(crate) fn next_interval(&self, interval: Duration) -> Duration {
+        interval
+            .mul_f64(self.multiplier.max(1.0))
+            .min(self.max_poll_interval)
+    }
+}
+
+/// Runs `future` to completion, or until `deadline` passes, in which case `None`
+/// is returned.
+pub(crate) async fn within<F: Future>(deadline: Option<Instant>, future: F) -> Option<F::Output> {
+    match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline, future).await.ok(),
+        None => Some(future.await),
+    }
+}
+
+/// A streaming event together with its position in the response's event stream.
+///
+/// Passing the `sequence_number` of the last event received as `starting_after`
+/// resumes a background stream after a disconnect without repeating events.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = ""))]
+pub struct SequencedEvent {
+    pub sequence_number: u64,
+    #[serde(flatten)]
+    pub event: OpenAIStreamingEvent,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_grows_the_poll_interval_up_to_the_maximum() {
+        let options = BackgroundOptions::new()
+            .poll_interval(Duration::from_secs(2))
+            .max_poll_interval(Duration::from_secs(5))
+            .multiplier(2.0);
+
+        assert_eq!(
+            options.next_interval(Duration::from_secs(2)),
+            Duration::from_secs(4)
+        );
+        assert_eq!(
+            options.next_interval(Duration::from_secs(4)),
+            Duration::from_secs(5)
+        );
+    }
+
+    #[test]
+    fn it_reads_sequence_numbers() {
+        let event: SequencedEvent = serde_json::from_value(json!({
+            "type": "response.output_text.delta",
+            "sequence_number": 4,
+            "item_id": "msg_123",
+            "output_index": 0,
+            "content_index": 0,
+            "delta": "Hi"
+        }))
+        .unwrap();
+
+        assert_eq!(event.sequence_number, 4);
+        assert!(matches!(
+            event.event,
+            OpenAIStreamingEvent::OutputTextDelta { ref delta, .. } if delta == "Hi"
+        ));
+    }
+}
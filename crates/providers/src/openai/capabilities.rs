@@ -0,0 +1,300 @@
+use crate::openai::constants::OpenAIModelId;
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Modality {
+    Text,
+    Image,
+    Audio,
+}
+
+/// What a model accepts and produces, used to check requests before they are sent.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ModelCapabilities {
+    pub context_window: usize,
+    pub max_output_tokens: usize,
+    pub reasoning: bool,
+    pub tools: bool,
+    pub structured_outputs: bool,
+    pub input_modalities: Vec<Modality>,
+    pub output_modalities: Vec<Modality>,
+}
+
+impl ModelCapabilities {
+    /// A text-in, text-out model without tools, reasoning or structured outputs.
+    pub fn new(context_window: usize, max_output_tokens: usize) -> Self {
+        Self {
+            context_window,
+            max_output_tokens,
+            reasoning: false,
+            tools: false,
+            structured_outputs: false,
+            input_modalities: vec![Modality::Text],
+            output_modalities: vec![Modality::Text],
+        }
+    }
+
+    pub fn max_output_tokens(mut self, value: usize) -> Self {
+        self.max_output_tokens = value;
+        self
+    }
+
+    pub fn reasoning(mut self, value: bool) -> Self {
+        self.reasoning = value;
+        self
+    }
+
+    pub fn tools(mut self, value: bool) -> Self {
+        self.tools = value;
+        self
+    }
+
+    pub fn structured_outputs(mut self, value: bool) -> Self {
+        self.structured_outputs = value;
+        self
+    }
+
+    /// Adds image inputs.
+    pub fn vision(self) -> Self {
+        self.add_input_modality(Modality::Image)
+    }
+
+    pub fn add_input_modality(mut self, value: Modality) -> Self {
+        if !self.input_modalities.contains(&value) {
+            self.input_modalities.push(value);
+        }
+        self
+    }
+
+    pub fn output_modalities(mut self, value: Vec<Modality>) -> Self {
+        self.output_modalities = value;
+        self
+    }
+
+    pub fn supports_vision(&self) -> bool {
+        self.input_modalities.contains(&Modality::Image)
+    }
+}
+
+/// Capabilities keyed by model id.
+///
+/// Lookups fall back from dated snapshots (`gpt-4o-2024-11-20`, `gpt-4-0613`) to
+/// their alias and from fine-tunes (`ft:gpt-4o-mini:org::abc`) to their base model,
+/// so only aliases and snapshots that differ from them need an entry.
+#[derive(Clone, Debug, Default)]
+pub struct ModelRegistry {
+    models: HashMap<String, ModelCapabilities>,
+}
+
+impl ModelRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The models this crate knows about.
+    pub fn builtin() -> &'static ModelRegistry {
+        static REGISTRY: OnceLock<ModelRegistry> = OnceLock::new();
+
+        REGISTRY.get_or_init(ModelRegistry::openai)
+    }
+
+    pub fn register(mut self, id: impl Into<String>, capabilities: ModelCapabilities) -> Self {
+        self.insert(id, capabilities);
+        self
+    }
+
+    pub fn insert(&mut self, id: impl Into<String>, capabilities: ModelCapabilities) {
+        self.models.insert(id.into(), capabilities);
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ModelCapabilities> {
+        if let Some(capabilities) = self.models.get(id) {
+            return Some(capabilities);
+        }
+
+        if let Some(base) = id
+            .strip_prefix("ft:")
+            .and_then(|rest| rest.split(':').next())
+        {
+            return self.get(base);
+        }
+
+        strip_snapshot_suffix(id).and_then(|alias| self.models.get(alias))
+    }
+
+    fn openai() -> Self {
+        let gpt_4o = ModelCapabilities::new(128_000, 16_384)
+            .tools(true)
+            .structured_outputs(true)
+            .vision();
+        let gpt_4_1 = ModelCapabilities::new(1_047_576, 32_768)
+            .tools(true)
+            .structured_outputs(true)
+            .vision();
+        let gpt_4_turbo_preview = ModelCapabilities::new(128_000, 4_096).tools(true);
+        let gpt_3_5_turbo = ModelCapabilities::new(16_385, 4_096).tools(true);
+        let o_series = ModelCapabilities::new(200_000, 100_000)
+            .reasoning(true)
+            .tools(true)
+            .structured_outputs(true)
+            .vision();
+        let audio = ModelCapabilities::new(128_000, 16_384)
+            .tools(true)
+            .add_input_modality(Modality::Audio)
+            .output_modalities(vec![Modality::Text, Modality::Audio]);
+        let search = ModelCapabilities::new(128_000, 16_384).structured_outputs(true);
+        let transcribe = ModelCapabilities::new(16_000, 2_000).add_input_modality(Modality::Audio);
+
+        let models = [
+            (
+                OpenAIModelId::Gpt4,
+                ModelCapabilities::new(8_192, 8_192).tools(true),
+            ),
+            (
+                OpenAIModelId::Gpt4Turbo,
+                gpt_4_turbo_preview.clone().vision(),
+            ),
+            (OpenAIModelId::Gpt4TurboPreview, gpt_4_turbo_preview.clone()),
+            (OpenAIModelId::Gpt4_0125Preview, gpt_4_turbo_preview.clone()),
+            (OpenAIModelId::Gpt4_1106Preview, gpt_4_turbo_preview),
+            (OpenAIModelId::Gpt4O, gpt_4o.clone()),
+            (
+                OpenAIModelId::Gpt4O2024_05_13,
+                ModelCapabilities::new(128_000, 4_096).tools(true).vision(),
+            ),
+            (OpenAIModelId::Gpt4OMini, gpt_4o.clone()),
+            (OpenAIModelId::Gpt4_5Preview, gpt_4o.clone()),
+            (
+                OpenAIModelId::ChatGpt4oLatest,
+                ModelCapabilities::new(128_000, 16_384).vision(),
+            ),
+            (OpenAIModelId::Gpt4OAudioPreview, audio.clone()),
+            (OpenAIModelId::Gpt4OMiniAudioPreview, audio.clone()),
+            (
+                OpenAIModelId::Gpt4ORealtimePreview,
+                audio.clone().max_output_tokens(4_096),
+            ),
+            (
+                OpenAIModelId::Gpt4OMiniRealtimePreview,
+                audio.max_output_tokens(4_096),
+            ),
+            (OpenAIModelId::Gpt4OSearchPreview, search.clone()),
+            (OpenAIModelId::Gpt4OMiniSearchPreview, search),
+            (OpenAIModelId::Gpt4OTranscribe, transcribe.clone()),
+            (OpenAIModelId::Gpt4OMiniTranscribe, transcribe),
+            (OpenAIModelId::Gpt4_1, gpt_4_1.clone()),
+            (OpenAIModelId::Gpt4_1Mini, gpt_4_1.clone()),
+            (OpenAIModelId::Gpt4_1Nano, gpt_4_1),
+            (OpenAIModelId::Gpt3_5Turbo, gpt_3_5_turbo.clone()),
+            (OpenAIModelId::Gpt3_5Turbo16k, gpt_3_5_turbo),
+            (
+                OpenAIModelId::Gpt3_5TurboInstruct,
+                ModelCapabilities::new(4_096, 4_096),
+            ),
+            (
+                OpenAIModelId::O1Preview,
+                ModelCapabilities::new(128_000, 32_768).reasoning(true),
+            ),
+            (
+                OpenAIModelId::O1Mini,
+                ModelCapabilities::new(128_000, 65_536).reasoning(true),
+            ),
+            (OpenAIModelId::O1Pro, o_series.clone()),
+            (
+                OpenAIModelId::O3Mini,
+                ModelCapabilities::new(200_000, 100_000)
+                    .reasoning(true)
+                    .tools(true)
+                    .structured_outputs(true),
+            ),
+            (OpenAIModelId::O4Mini, o_series.clone()),
+            (OpenAIModelId::CodexMiniLatest, o_series),
+        ];
+
+        let mut registry = Self::new();
+        for (model, capabilities) in models {
+            registry.insert(model.as_str(), capabilities);
+        }
+
+        registry
+    }
+}
+
+impl OpenAIModelId {
+    /// Looks the model up in [`ModelRegistry::builtin`].
+    pub fn capabilities(&self) -> Option<&'static ModelCapabilities> {
+        ModelRegistry::builtin().get(self.as_str())
+    }
+}
+
+/// Strips a `-YYYY-MM-DD` or `-MMDD` snapshot suffix.
+fn strip_snapshot_suffix(id: &str) -> Option<&str> {
+    let is_date = |suffix: &str, pattern: &str| {
+        suffix.len() == pattern.len()
+            && suffix.chars().zip(pattern.chars()).all(|(c, p)| match p {
+                'd' => c.is_ascii_digit(),
+                _ => c == p,
+            })
+    };
+
+    ["-dddd-dd-dd", "-dddd"].into_iter().find_map(|pattern| {
+        let split = id.len().checked_sub(pattern.len())?;
+        let (alias, suffix) = (id.get(..split)?, id.get(split..)?);
+
+        is_date(suffix, pattern).then_some(alias)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_resolves_snapshots_and_fine_tunes() {
+        let registry = ModelRegistry::builtin();
+        let gpt_4o_mini = registry.get("gpt-4o-mini").unwrap();
+
+        assert_eq!(registry.get("gpt-4o-mini-2024-07-18"), Some(gpt_4o_mini));
+        assert_eq!(registry.get("ft:gpt-4o-mini:org::abc"), Some(gpt_4o_mini));
+        assert_eq!(
+            registry.get("ft:gpt-4o-mini-2024-07-18:org:custom:abc"),
+            Some(gpt_4o_mini)
+        );
+        assert_eq!(
+            registry.get("gpt-3.5-turbo-0125").unwrap().context_window,
+            16_385
+        );
+        assert_eq!(
+            registry.get("gpt-4o-2024-05-13").unwrap().max_output_tokens,
+            4_096
+        );
+        assert_eq!(registry.get("unknown-model"), None);
+    }
+
+    #[test]
+    fn it_describes_known_models() {
+        let o4_mini = OpenAIModelId::O4Mini.capabilities().unwrap();
+        assert!(o4_mini.reasoning && o4_mini.tools && o4_mini.supports_vision());
+
+        let gpt_4 = OpenAIModelId::Gpt4_0613.capabilities().unwrap();
+        assert!(!gpt_4.reasoning && !gpt_4.supports_vision());
+        assert_eq!(gpt_4.context_window, 8_192);
+
+        let audio = OpenAIModelId::Gpt4OAudioPreview.capabilities().unwrap();
+        assert!(audio.output_modalities.contains(&Modality::Audio));
+
+        assert_eq!(OpenAIModelId::custom("my-model").capabilities(), None);
+    }
+
+    #[test]
+    fn it_accepts_custom_registrations() {
+        let registry = ModelRegistry::new().register(
+            "llama-3.1-8b",
+            ModelCapabilities::new(131_072, 8_192).tools(true),
+        );
+
+        assert!(registry.get("llama-3.1-8b").unwrap().tools);
+        assert_eq!(registry.get("gpt-4o"), None);
+    }
+}
@@ -1,20 +1,566 @@
+use crate::openai::background::{within, BackgroundOptions, SequencedEvent, SequencedEventStream};
 use crate::openai::constants::OPENAI_API_URL;
+use crate::openai::embeddings::{EmbeddingRequest, EmbeddingResponse};
+use crate::openai::errors::GuardError;
+use crate::openai::images::{
+    ImageEditRequest, ImageGenerationRequest, ImageVariationRequest, ImagesResponse,
+};
+use crate::openai::lifecycle::{
+    include_query, DeletedResponse, InputItemsPage, ListInputItemsParams,
+};
+use crate::openai::moderation::{ModerationInput, ModerationRequest, ModerationResponse};
+use crate::openai::request::include::Include;
 use crate::openai::response::events::streaming::OpenAIStreamingEvent;
-use crate::utils::{errors::ProviderError, provider_strategy::ProviderStrategy};
+use crate::openai::speech::{AudioStream, SpeechRequest};
+use crate::openai::transcription::{
+    TranscriptionEventStream, TranscriptionFormat, TranscriptionRequest, TranscriptionResponse,
+    TranslationRequest,
+};
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, header_value, network_error, Auth, HttpConfig, HttpTransport},
+    provider_strategy::ProviderStrategy,
+};
 use async_trait::async_trait;
+use bytes::Bytes;
 use futures::stream::StreamExt;
+use reqwest::header::HeaderMap;
+use reqwest::{Method, RequestBuilder};
+use serde_json::{json, Value};
+use std::future::Future;
 use std::pin::Pin;
+use std::time::Duration;
+use tokio::time::Instant;
 use tokio_stream::Stream;
 
 use super::types::{OpenAIRequest, OpenAIResponse};
 
+#[derive(Debug, Clone)]
 pub struct OpenAIProvider {
     api_key: String,
+    http: HttpTransport,
 }
 
 impl OpenAIProvider {
     pub fn new(api_key: String) -> Self {
-        OpenAIProvider { api_key }
+        OpenAIProvider {
+            http: HttpTransport::new(
+                OPENAI_API_URL,
+                Auth::Bearer(api_key.clone()),
+                HeaderMap::new(),
+            ),
+            api_key,
+        }
+    }
+
+    pub fn builder(api_key: impl Into<String>) -> OpenAIProviderBuilder {
+        OpenAIProviderBuilder::new(api_key)
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, path)
+    }
+
+    fn background_body(request: &OpenAIRequest, stream: bool) -> Result<Value, ProviderError> {
+        request.validate()?;
+
+        let mut body = serde_json::to_value(request)
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+        if let Value::Object(ref mut map) = body {
+            map.insert("background".to_string(), json!(true));
+
+            if stream {
+                map.insert("stream".to_string(), json!(true));
+            }
+        }
+
+        Ok(body)
+    }
+
+    /// Starts `request` in background mode and returns the queued response.
+    pub async fn submit_background(
+        &self,
+        request: &OpenAIRequest,
+    ) -> Result<OpenAIResponse, ProviderError> {
+        let body = Self::background_body(request, false)?;
+
+        http::send(self.request(Method::POST, "/responses").json(&body)).await
+    }
+
+    /// Starts `request` in background mode and polls it until it finishes. The
+    /// deadline covers submitting the request as well as polling it.
+    pub async fn generate_background(
+        &self,
+        request: &OpenAIRequest,
+        options: &BackgroundOptions,
+    ) -> Result<OpenAIResponse, ProviderError> {
+        let deadline = options.deadline_from_now();
+
+        let response = within(deadline, self.submit_background(request))
+            .await
+            .ok_or_else(|| {
+                ProviderError::Timeout(format!(
+                    "background request was not accepted within {:?}",
+                    options.deadline
+                ))
+            })??;
+
+        if response.status.is_terminal() {
+            return Ok(response);
+        }
+
+        self.poll_response(&response.id, options, deadline, std::future::pending())
+            .await
+    }
+
+    /// Polls a background response until its status is terminal.
+    pub async fn wait_for_response(
+        &self,
+        response_id: &str,
+        options: &BackgroundOptions,
+    ) -> Result<OpenAIResponse, ProviderError> {
+        self.wait_for_response_until(response_id, options, std::future::pending())
+            .await
+    }
+
+    /// Like [`OpenAIProvider::wait_for_response`], but cancels the response as soon
+    /// as `cancel` completes and returns the cancelled response.
+    pub async fn wait_for_response_until<F>(
+        &self,
+        response_id: &str,
+        options: &BackgroundOptions,
+        cancel: F,
+    ) -> Result<OpenAIResponse, ProviderError>
+    where
+        F: Future<Output = ()>,
+    {
+        self.poll_response(response_id, options, options.deadline_from_now(), cancel)
+            .await
+    }
+
+    async fn poll_response<F>(
+        &self,
+        response_id: &str,
+        options: &BackgroundOptions,
+        deadline: Option<Instant>,
+        cancel: F,
+    ) -> Result<OpenAIResponse, ProviderError>
+    where
+        F: Future<Output = ()>,
+    {
+        let mut interval = options.poll_interval;
+        tokio::pin!(cancel);
+
+        loop {
+            // a hanging poll must not outlive the deadline or delay cancellation
+            let poll = tokio::select! {
+                poll = within(deadline, self.retrieve_response(response_id, &[])) => poll,
+                _ = &mut cancel => return self.cancel_response(response_id).await,
+            };
+
+            let Some(response) = poll else {
+                if options.cancel_on_deadline {
+                    // the deadline error is more useful than a failed cancellation
+                    let _ = self.cancel_response(response_id).await;
+                }
+
+                return Err(ProviderError::Timeout(format!(
+                    "response {} did not finish within {:?}",
+                    response_id, options.deadline
+                )));
+            };
+
+            let response = response?;
+
+            if response.status.is_terminal() {
+                return Ok(response);
+            }
+
+            let wait = match deadline {
+                Some(deadline) => interval.min(deadline.saturating_duration_since(Instant::now())),
+                None => interval,
+            };
+
+            tokio::select! {
+                _ = tokio::time::sleep(wait) => {}
+                _ = &mut cancel => return self.cancel_response(response_id).await,
+            }
+
+            interval = options.next_interval(interval);
+        }
+    }
+
+    /// Starts `request` in background mode and streams its events. If the stream
+    /// drops, [`OpenAIProvider::resume_stream`] picks it up again.
+    pub async fn stream_background(
+        &self,
+        request: &OpenAIRequest,
+    ) -> Result<SequencedEventStream, ProviderError> {
+        let body = Self::background_body(request, true)?;
+
+        http::send_sse(
+            self.http
+                .stream_request(Method::POST, "/responses")
+                .json(&body),
+        )
+        .await
+    }
+
+    /// Streams the events of a background response, starting after the event with
+    /// sequence number `starting_after`, or from the beginning.
+    pub async fn resume_stream(
+        &self,
+        response_id: &str,
+        starting_after: Option<u64>,
+    ) -> Result<SequencedEventStream, ProviderError> {
+        let mut query = vec![("stream", "true".to_string())];
+
+        if let Some(starting_after) = starting_after {
+            query.push(("starting_after", starting_after.to_string()));
+        }
+
+        let request = self
+            .http
+            .stream_request(Method::GET, &format!("/responses/{}", response_id))
+            .query(&query);
+
+        http::send_sse::<SequencedEvent>(request).await
+    }
+
+    /// Creates embeddings, splitting inputs longer than the request's batch size
+    /// into several calls whose results are merged back in input order.
+    pub async fn embed(
+        &self,
+        request: &EmbeddingRequest,
+    ) -> Result<EmbeddingResponse, ProviderError> {
+        if request.input().is_empty() {
+            return Err(ProviderError::ValidationError(
+                "no input to embed".to_string(),
+            ));
+        }
+
+        let mut merged: Option<EmbeddingResponse> = None;
+
+        for batch in request.batches() {
+            let response: EmbeddingResponse =
+                http::send(self.request(Method::POST, "/embeddings").json(&batch)).await?;
+
+            match merged {
+                Some(ref mut merged) => merged.merge(response),
+                None => merged = Some(response),
+            }
+        }
+
+        merged.ok_or_else(|| ProviderError::InternalError("no batches were embedded".to_string()))
+    }
+
+    pub async fn generate_image(
+        &self,
+        request: &ImageGenerationRequest,
+    ) -> Result<ImagesResponse, ProviderError> {
+        request.validate()?;
+
+        http::send(
+            self.request(Method::POST, "/images/generations")
+                .json(request),
+        )
+        .await
+    }
+
+    /// Edits images, uploading them and the optional mask as `multipart/form-data`.
+    pub async fn edit_image(
+        &self,
+        request: &ImageEditRequest,
+    ) -> Result<ImagesResponse, ProviderError> {
+        request.validate()?;
+
+        http::send(
+            self.request(Method::POST, "/images/edits")
+                .multipart(request.to_form()?),
+        )
+        .await
+    }
+
+    pub async fn create_image_variation(
+        &self,
+        request: &ImageVariationRequest,
+    ) -> Result<ImagesResponse, ProviderError> {
+        request.validate()?;
+
+        http::send(
+            self.request(Method::POST, "/images/variations")
+                .multipart(request.to_form()?),
+        )
+        .await
+    }
+
+    /// Synthesizes speech and returns the whole audio file.
+    pub async fn speech(&self, request: &SpeechRequest) -> Result<Bytes, ProviderError> {
+        request.validate()?;
+
+        http::send_raw(self.request(Method::POST, "/audio/speech").json(request)).await
+    }
+
+    /// Synthesizes speech, yielding audio chunks as they are generated so playback
+    /// can start before the whole file is ready.
+    pub async fn speech_stream(
+        &self,
+        request: &SpeechRequest,
+    ) -> Result<AudioStream, ProviderError> {
+        request.validate()?;
+
+        let response = http::execute(
+            self.http
+                .stream_request(Method::POST, "/audio/speech")
+                .json(request),
+        )
+        .await?;
+
+        Ok(Box::pin(
+            response
+                .bytes_stream()
+                .map(|chunk| chunk.map_err(network_error)),
+        ))
+    }
+
+    async fn send_transcription(
+        &self,
+        request: RequestBuilder,
+        format: TranscriptionFormat,
+    ) -> Result<TranscriptionResponse, ProviderError> {
+        let response_bytes = http::send_raw(request).await?;
+
+        TranscriptionResponse::parse(format, &response_bytes)
+    }
+
+    pub async fn transcribe(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> Result<TranscriptionResponse, ProviderError> {
+        request.validate()?;
+
+        self.send_transcription(
+            self.request(Method::POST, "/audio/transcriptions")
+                .multipart(request.to_form(false)?),
+            request.format(),
+        )
+        .await
+    }
+
+    /// Streams the transcript as it is produced. Only the gpt-4o transcribe models
+    /// support this.
+    pub async fn transcribe_stream(
+        &self,
+        request: &TranscriptionRequest,
+    ) -> Result<TranscriptionEventStream, ProviderError> {
+        request.validate_stream()?;
+
+        http::send_sse(
+            self.http
+                .stream_request(Method::POST, "/audio/transcriptions")
+                .multipart(request.to_form(true)?),
+        )
+        .await
+    }
+
+    /// Translates audio into English.
+    pub async fn translate(
+        &self,
+        request: &TranslationRequest,
+    ) -> Result<TranscriptionResponse, ProviderError> {
+        request.validate()?;
+
+        self.send_transcription(
+            self.request(Method::POST, "/audio/translations")
+                .multipart(request.to_form()?),
+            request.format(),
+        )
+        .await
+    }
+
+    pub async fn moderate(
+        &self,
+        request: &ModerationRequest,
+    ) -> Result<ModerationResponse, ProviderError> {
+        http::send(self.request(Method::POST, "/moderations").json(request)).await
+    }
+
+    /// Runs the request's input through moderation and fails with
+    /// [`GuardError::Flagged`] if anything is flagged. Requests without
+    /// text or images to check pass without a moderation call.
+    pub async fn guard_input(
+        &self,
+        request: &OpenAIRequest,
+    ) -> Result<Option<ModerationResponse>, GuardError> {
+        let Some(input) = ModerationInput::from_input(request.get_input()) else {
+            return Ok(None);
+        };
+
+        let moderation = self.moderate(&ModerationRequest::new(input)).await?;
+
+        if moderation.flagged() {
+            return Err(GuardError::Flagged(Box::new(moderation)));
+        }
+
+        Ok(Some(moderation))
+    }
+
+    /// Like [`ProviderStrategy::generate`], after checking the input with
+    /// [`OpenAIProvider::guard_input`].
+    pub async fn generate_guarded(
+        &self,
+        request: &OpenAIRequest,
+    ) -> Result<OpenAIResponse, GuardError> {
+        request.validate()?;
+        self.guard_input(request).await?;
+
+        Ok(self.create_response(request).await?)
+    }
+
+    async fn create_response(
+        &self,
+        request: &OpenAIRequest,
+    ) -> Result<OpenAIResponse, ProviderError> {
+        http::send(self.request(Method::POST, "/responses").json(request)).await
+    }
+
+    /// Fetches a stored response.
+    pub async fn retrieve_response(
+        &self,
+        response_id: &str,
+        include: &[Include],
+    ) -> Result<OpenAIResponse, ProviderError> {
+        let request = self
+            .request(Method::GET, &format!("/responses/{}", response_id))
+            .query(&include_query(include));
+
+        http::send(request).await
+    }
+
+    pub async fn delete_response(
+        &self,
+        response_id: &str,
+    ) -> Result<DeletedResponse, ProviderError> {
+        let request = self.request(Method::DELETE, &format!("/responses/{}", response_id));
+
+        http::send(request).await
+    }
+
+    /// Cancels a response that is still running in the background.
+    pub async fn cancel_response(
+        &self,
+        response_id: &str,
+    ) -> Result<OpenAIResponse, ProviderError> {
+        let request = self.request(Method::POST, &format!("/responses/{}/cancel", response_id));
+
+        http::send(request).await
+    }
+
+    /// Lists one page of the items a stored response was given as input.
+    pub async fn list_input_items(
+        &self,
+        response_id: &str,
+        params: &ListInputItemsParams,
+    ) -> Result<InputItemsPage, ProviderError> {
+        let request = self
+            .request(
+                Method::GET,
+                &format!("/responses/{}/input_items", response_id),
+            )
+            .query(&params.to_query());
+
+        http::send(request).await
+    }
+}
+
+/// Builder for an [`OpenAIProvider`] with a custom endpoint, headers and HTTP client.
+///
+/// The request timeout applies to whole non-streaming requests. The connect timeout
+/// only applies when the builder creates the HTTP client itself, i.e. when no client
+/// was supplied through [`OpenAIProviderBuilder::http_client`].
+#[derive(Debug)]
+pub struct OpenAIProviderBuilder {
+    api_key: String,
+    base_url: String,
+    organization: Option<String>,
+    project: Option<String>,
+    http: HttpConfig,
+}
+
+impl OpenAIProviderBuilder {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            base_url: OPENAI_API_URL.to_string(),
+            organization: None,
+            project: None,
+            http: HttpConfig::default(),
+        }
+    }
+
+    pub fn base_url(mut self, value: impl Into<String>) -> Self {
+        self.base_url = value.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn organization(mut self, value: impl Into<String>) -> Self {
+        self.organization = Some(value.into());
+        self
+    }
+
+    pub fn project(mut self, value: impl Into<String>) -> Self {
+        self.project = Some(value.into());
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<OpenAIProvider, ProviderError> {
+        let mut headers = HeaderMap::new();
+
+        if let Some(organization) = self.organization {
+            headers.insert(
+                "OpenAI-Organization",
+                header_value("OpenAI-Organization", &organization)?,
+            );
+        }
+
+        if let Some(project) = self.project {
+            headers.insert("OpenAI-Project", header_value("OpenAI-Project", &project)?);
+        }
+
+        let http = self
+            .http
+            .build(self.base_url, Auth::Bearer(self.api_key.clone()), headers)?;
+
+        Ok(OpenAIProvider {
+            api_key: self.api_key,
+            http,
+        })
     }
 }
 
@@ -26,7 +572,7 @@ impl ProviderStrategy for OpenAIProvider {
     type StreamingResponse = OpenAIStreamingEvent;
 
     fn get_base_url(&self) -> String {
-        OPENAI_API_URL.to_string()
+        self.http.base_url().to_string()
     }
 
     fn get_api_key(&self) -> String {
@@ -37,38 +583,9 @@ impl ProviderStrategy for OpenAIProvider {
         &self,
         request: &Self::GenerationRequest,
     ) -> Result<Self::GenerationResponse, ProviderError> {
-        let client = reqwest::Client::new();
-        let url = format!("{}/responses", self.get_base_url());
-
-        let response = client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.get_api_key()))
-            .header("Content-Type", "application/json")
-            .json(request)
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let error_message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Failed to read error response".to_string());
-
-            return Err(ProviderError::ApiError {
-                status,
-                message: error_message,
-            });
-        }
-
-        let response_bytes = response
-            .bytes()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
+        request.validate()?;
 
-        serde_json::from_slice(&response_bytes)
-            .map_err(|e| ProviderError::DeserializationError(e.to_string()))
+        self.create_response(request).await
     }
 
     async fn stream(
@@ -78,42 +595,13 @@ impl ProviderStrategy for OpenAIProvider {
         Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
         ProviderError,
     > {
-        let client = reqwest::Client::new();
-        let url = format!("{}/responses", self.get_base_url());
-
-        let response = client
-            .post(&url)
-            .header("Authorization", format!("Bearer {}", self.get_api_key()))
-            .header("Content-Type", "application/json")
-            .header("Accept", "text/event-stream")
-            .json(&request.wrap_for_streaming())
-            .send()
-            .await
-            .map_err(|e| ProviderError::NetworkError(e.to_string()))?;
-
-        if !response.status().is_success() {
-            let status = response.status().as_u16();
-            let error_message = response
-                .text()
-                .await
-                .unwrap_or_else(|_| "Failed to read error response".to_string());
-
-            return Err(ProviderError::ApiError {
-                status,
-                message: error_message,
-            });
-        }
+        request.validate()?;
 
-        let stream = response.bytes_stream();
-        let parsed_stream = stream.map(|chunk_result| {
-            chunk_result
-                .map_err(|e| ProviderError::NetworkError(e.to_string()))
-                .and_then(|chunk| {
-                    serde_json::from_slice(&chunk)
-                        .map_err(|e| ProviderError::DeserializationError(e.to_string()))
-                })
-        });
-
-        Ok(Box::pin(parsed_stream))
+        http::send_sse(
+            self.http
+                .stream_request(Method::POST, "/responses")
+                .json(&request.wrap_for_streaming()),
+        )
+        .await
     }
 }
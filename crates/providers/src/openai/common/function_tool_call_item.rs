@@ -7,6 +7,9 @@ pub struct FunctionToolCallItem {
     pub arguments: String,
     pub call_id: String,
     pub name: String,
+    #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_field: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -23,11 +26,17 @@ impl FunctionToolCallItem {
             arguments: arguments.into(),
             call_id: call_id.into(),
             name: name.into(),
+            type_field: None,
             id: None,
             status: None,
         }
     }
 
+    pub fn insert_type(mut self) -> Self {
+        self.type_field = Some("function_call".to_string());
+        self
+    }
+
     pub fn id(mut self, value: impl Into<String>) -> Self {
         self.id = Some(value.into());
         self
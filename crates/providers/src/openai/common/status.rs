@@ -6,10 +6,19 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Status {
+    Queued,
     InProgress,
     Completed,
     Incomplete,
     Failed,
+    Cancelled,
+}
+
+impl Status {
+    /// Whether the response has stopped running, successfully or not.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, Status::Queued | Status::InProgress)
+    }
 }
 
 impl FromStr for Status {
@@ -17,10 +26,12 @@ impl FromStr for Status {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "queued" => Ok(Status::Queued),
             "in_progress" => Ok(Status::InProgress),
             "completed" => Ok(Status::Completed),
             "incomplete" => Ok(Status::Incomplete),
             "failed" => Ok(Status::Failed),
+            "cancelled" => Ok(Status::Cancelled),
             _ => Err(ConversionError::FromStr(s.to_string())),
         }
     }
@@ -1,6 +1,8 @@
 use serde::{Deserialize, Serialize};
 
 use crate::openai::errors::ConversionError;
+use crate::utils::schema::{schema_description, schema_name, strict_schema_for};
+use schemars::JsonSchema;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -53,6 +55,17 @@ impl JsonSchemaFormat {
         }
     }
 
+    /// Creates a strict format from the schema of `T`, named after the type and
+    /// described by its doc comment.
+    pub fn from_type<T: JsonSchema>() -> Self {
+        let schema = strict_schema_for::<T>();
+        let description = schema_description(&schema);
+
+        let mut format = Self::new(schema_name::<T>(), schema).strict();
+        format.description = description;
+        format
+    }
+
     pub fn description(mut self, value: impl Into<String>) -> Self {
         self.description = Some(value.into());
         self
@@ -216,7 +229,7 @@ mod tests {
             format: Some(ResponseFormat::JsonSchema(JsonSchemaFormat {
                 type_field: ResponseFormatType::JsonSchema,
                 name: "test".to_string(),
-                schema: schema,
+                schema,
                 description: Some("this is a description".to_string()),
                 strict: Some(false),
             })),
@@ -302,4 +315,23 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn it_builds_json_schema_format_from_a_type() {
+        /// A person.
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        struct Person {
+            name: String,
+            nickname: Option<String>,
+        }
+
+        let format = JsonSchemaFormat::from_type::<Person>();
+
+        assert_eq!(format.name, "Person");
+        assert_eq!(format.description.as_deref(), Some("A person."));
+        assert_eq!(format.strict, Some(true));
+        assert_eq!(format.schema["required"], json!(["name", "nickname"]));
+        assert_eq!(format.schema["additionalProperties"], json!(false));
+    }
 }
@@ -3,6 +3,9 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 use crate::openai::errors::ConversionError;
+use crate::utils::errors::ProviderError;
+use crate::utils::schema::{schema_description, strict_schema_for};
+use schemars::JsonSchema;
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
@@ -253,6 +256,17 @@ impl FunctionTool {
         }
     }
 
+    /// Creates a strict function tool whose parameters are the schema of `T`,
+    /// described by the doc comment of the type.
+    pub fn from_type<T: JsonSchema>(name: impl Into<String>) -> Self {
+        let parameters = strict_schema_for::<T>();
+        let description = schema_description(&parameters);
+
+        let mut tool = Self::new(name, parameters);
+        tool.description = description;
+        tool
+    }
+
     pub fn strict(mut self, value: bool) -> Self {
         self.strict = value;
         self
@@ -396,6 +410,29 @@ pub enum Tool {
     WebSearch(WebSearchTool),
 }
 
+impl Tool {
+    /// The name of a function tool.
+    pub fn function_name(&self) -> Option<&str> {
+        match self {
+            Tool::Function(tool) => Some(&tool.name),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> Result<(), ProviderError> {
+        match self {
+            Tool::FileSearch(FileSearchTool {
+                max_num_results: Some(value),
+                ..
+            }) if !(1..=50).contains(value) => Err(ProviderError::ValidationError(format!(
+                "file search max_num_results must be between 1 and 50, got {}",
+                value
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
 impl From<FileSearchTool> for Tool {
     fn from(tool: FileSearchTool) -> Self {
         Tool::FileSearch(tool)
@@ -745,4 +782,38 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn it_creates_function_tool_from_a_type() {
+        /// Looks up the weather in a city.
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        struct GetWeather {
+            city: String,
+            unit: Option<String>,
+        }
+
+        let tool = FunctionTool::from_type::<GetWeather>("get_weather");
+
+        assert_eq!(
+            serde_json::to_value(&tool).unwrap(),
+            json!({
+                "type": "function",
+                "name": "get_weather",
+                "strict": true,
+                "description": "Looks up the weather in a city.",
+                "parameters": {
+                    "title": "GetWeather",
+                    "description": "Looks up the weather in a city.",
+                    "type": "object",
+                    "properties": {
+                        "city": { "type": "string" },
+                        "unit": { "type": ["string", "null"] }
+                    },
+                    "required": ["city", "unit"],
+                    "additionalProperties": false
+                }
+            })
+        );
+    }
 }
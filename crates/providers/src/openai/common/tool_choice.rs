@@ -74,6 +74,10 @@ impl FunctionToolChoice {
             type_field: "function".to_string(),
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
 }
 
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
@@ -4,8 +4,11 @@ use std::str::FromStr;
 
 pub const OPENAI_API_URL: &str = "https://api.openai.com/v1";
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-#[serde(into = "String", try_from = "&str")]
+/// The most inputs the embeddings endpoint accepts in one request.
+pub const MAX_EMBEDDING_INPUTS: usize = 2048;
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "String")]
 pub enum OpenAIModelId {
     Gpt4,
     Gpt4Turbo,
@@ -44,6 +47,7 @@ pub enum OpenAIModelId {
     Gpt4_1Mini2025_04_14,
     Gpt4_1Nano,
     Gpt4_1Nano2025_04_14,
+    #[default]
     Gpt3_5Turbo,
     Gpt3_5Turbo0125,
     Gpt3_5Turbo1106,
@@ -51,6 +55,10 @@ pub enum OpenAIModelId {
     Gpt3_5TurboInstruct,
     Gpt3_5TurboInstruct0914,
     GptImage1,
+    DallE2,
+    DallE3,
+    Tts1,
+    Whisper1,
     Tts1Hd,
     Tts1Hd1106,
     TextEmbeddingAda002,
@@ -70,16 +78,17 @@ pub enum OpenAIModelId {
     OmniModerationLatest,
     OmniModeration2024_09_26,
     CodexMiniLatest,
+    /// Any model the crate does not list, such as a newer release or a fine-tune
+    /// like `ft:gpt-4o-mini:org::abc`.
+    Custom(String),
 }
 
-impl Default for OpenAIModelId {
-    fn default() -> Self {
-        Self::Gpt3_5Turbo
+impl OpenAIModelId {
+    pub fn custom(value: impl Into<String>) -> Self {
+        Self::Custom(value.into())
     }
-}
 
-impl OpenAIModelId {
-    pub fn as_str(&self) -> &'static str {
+    pub fn as_str(&self) -> &str {
         match self {
             Self::Gpt4 => "gpt-4",
             Self::Gpt4Turbo => "gpt-4-turbo",
@@ -125,6 +134,10 @@ impl OpenAIModelId {
             Self::Gpt3_5TurboInstruct => "gpt-3.5-turbo-instruct",
             Self::Gpt3_5TurboInstruct0914 => "gpt-3.5-turbo-instruct-0914",
             Self::GptImage1 => "gpt-image-1",
+            Self::DallE2 => "dall-e-2",
+            Self::DallE3 => "dall-e-3",
+            Self::Tts1 => "tts-1",
+            Self::Whisper1 => "whisper-1",
             Self::Tts1Hd => "tts-1-hd",
             Self::Tts1Hd1106 => "tts-1-hd-1106",
             Self::TextEmbeddingAda002 => "text-embedding-ada-002",
@@ -144,6 +157,7 @@ impl OpenAIModelId {
             Self::OmniModerationLatest => "omni-moderation-latest",
             Self::OmniModeration2024_09_26 => "omni-moderation-2024-09-26",
             Self::CodexMiniLatest => "codex-mini-latest",
+            Self::Custom(value) => value,
         }
     }
 }
@@ -156,9 +170,26 @@ impl TryFrom<&str> for OpenAIModelId {
     }
 }
 
+impl TryFrom<String> for OpenAIModelId {
+    type Error = InputError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        OpenAIModelId::from_str(&value)
+    }
+}
+
 impl From<OpenAIModelId> for String {
     fn from(value: OpenAIModelId) -> Self {
-        value.as_str().to_string()
+        match value {
+            OpenAIModelId::Custom(value) => value,
+            other => other.as_str().to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for OpenAIModelId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
     }
 }
 
@@ -213,6 +244,10 @@ impl FromStr for OpenAIModelId {
             "gpt-3.5-turbo-instruct" => Ok(Self::Gpt3_5TurboInstruct),
             "gpt-3.5-turbo-instruct-0914" => Ok(Self::Gpt3_5TurboInstruct0914),
             "gpt-image-1" => Ok(Self::GptImage1),
+            "dall-e-2" => Ok(Self::DallE2),
+            "dall-e-3" => Ok(Self::DallE3),
+            "tts-1" => Ok(Self::Tts1),
+            "whisper-1" => Ok(Self::Whisper1),
             "tts-1-hd" => Ok(Self::Tts1Hd),
             "tts-1-hd-1106" => Ok(Self::Tts1Hd1106),
             "text-embedding-ada-002" => Ok(Self::TextEmbeddingAda002),
@@ -232,7 +267,43 @@ impl FromStr for OpenAIModelId {
             "omni-moderation-latest" => Ok(Self::OmniModerationLatest),
             "omni-moderation-2024-09-26" => Ok(Self::OmniModeration2024_09_26),
             "codex-mini-latest" => Ok(Self::CodexMiniLatest),
-            _ => Err(InputError::InvalidModelId(s.to_string())),
+            "" => Err(InputError::InvalidModelId(s.to_string())),
+            _ => Ok(Self::Custom(s.to_string())),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_parses_known_and_custom_model_ids() {
+        assert_eq!(
+            OpenAIModelId::from_str("gpt-4o-mini"),
+            Ok(OpenAIModelId::Gpt4OMini)
+        );
+        assert_eq!(
+            OpenAIModelId::from_str("ft:gpt-4o-mini:org::abc"),
+            Ok(OpenAIModelId::custom("ft:gpt-4o-mini:org::abc"))
+        );
+        assert_eq!(
+            OpenAIModelId::from_str(""),
+            Err(InputError::InvalidModelId(String::new()))
+        );
+    }
+
+    #[test]
+    fn it_round_trips_custom_model_ids_through_json() {
+        let model: OpenAIModelId = serde_json::from_value(json!("gpt-5")).unwrap();
+
+        assert_eq!(model, OpenAIModelId::custom("gpt-5"));
+        assert_eq!(model.as_str(), "gpt-5");
+        assert_eq!(serde_json::to_value(&model).unwrap(), json!("gpt-5"));
+        assert_eq!(
+            serde_json::to_value(OpenAIModelId::O4Mini).unwrap(),
+            json!("o4-mini")
+        );
+    }
+}
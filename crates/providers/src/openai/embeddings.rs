@@ -0,0 +1,326 @@
+use crate::openai::constants::{OpenAIModelId, MAX_EMBEDDING_INPUTS};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum EmbeddingInput {
+    Text(String),
+    Texts(Vec<String>),
+    Tokens(Vec<u32>),
+    TokenBatches(Vec<Vec<u32>>),
+}
+
+impl EmbeddingInput {
+    /// The number of embeddings this input produces.
+    pub fn len(&self) -> usize {
+        match self {
+            EmbeddingInput::Text(_) | EmbeddingInput::Tokens(_) => 1,
+            EmbeddingInput::Texts(texts) => texts.len(),
+            EmbeddingInput::TokenBatches(batches) => batches.len(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn chunks(&self, size: usize) -> Vec<EmbeddingInput> {
+        match self {
+            EmbeddingInput::Texts(texts) => texts
+                .chunks(size)
+                .map(|chunk| EmbeddingInput::Texts(chunk.to_vec()))
+                .collect(),
+            EmbeddingInput::TokenBatches(batches) => batches
+                .chunks(size)
+                .map(|chunk| EmbeddingInput::TokenBatches(chunk.to_vec()))
+                .collect(),
+            single => vec![single.clone()],
+        }
+    }
+}
+
+impl From<&str> for EmbeddingInput {
+    fn from(value: &str) -> Self {
+        EmbeddingInput::Text(value.to_string())
+    }
+}
+
+impl From<String> for EmbeddingInput {
+    fn from(value: String) -> Self {
+        EmbeddingInput::Text(value)
+    }
+}
+
+impl From<Vec<String>> for EmbeddingInput {
+    fn from(value: Vec<String>) -> Self {
+        EmbeddingInput::Texts(value)
+    }
+}
+
+impl From<Vec<&str>> for EmbeddingInput {
+    fn from(value: Vec<&str>) -> Self {
+        EmbeddingInput::Texts(value.into_iter().map(str::to_string).collect())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EncodingFormat {
+    Float,
+    /// Little-endian `f32`s, base64 encoded. Smaller on the wire; decoded back into
+    /// floats when the response is parsed.
+    Base64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingRequest {
+    input: EmbeddingInput,
+    model: OpenAIModelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    dimensions: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    encoding_format: Option<EncodingFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+    #[serde(skip)]
+    batch_size: Option<usize>,
+}
+
+impl EmbeddingRequest {
+    pub fn new(model: OpenAIModelId, input: impl Into<EmbeddingInput>) -> Self {
+        Self {
+            input: input.into(),
+            model,
+            dimensions: None,
+            encoding_format: None,
+            user: None,
+            batch_size: None,
+        }
+    }
+
+    pub fn input(&self) -> &EmbeddingInput {
+        &self.input
+    }
+
+    /// Shortens the embeddings, supported by `text-embedding-3` and later models.
+    pub fn dimensions(mut self, value: usize) -> Self {
+        self.dimensions = Some(value);
+        self
+    }
+
+    pub fn encoding_format(mut self, value: EncodingFormat) -> Self {
+        self.encoding_format = Some(value);
+        self
+    }
+
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    /// How many inputs to send per request. Defaults to, and is capped at,
+    /// [`MAX_EMBEDDING_INPUTS`].
+    pub fn batch_size(mut self, value: usize) -> Self {
+        self.batch_size = Some(value);
+        self
+    }
+
+    /// Splits the request into requests of at most `batch_size` inputs each.
+    pub fn batches(&self) -> Vec<EmbeddingRequest> {
+        let size = self
+            .batch_size
+            .unwrap_or(MAX_EMBEDDING_INPUTS)
+            .clamp(1, MAX_EMBEDDING_INPUTS);
+
+        if self.input.len() <= size {
+            return vec![self.clone()];
+        }
+
+        self.input
+            .chunks(size)
+            .into_iter()
+            .map(|input| EmbeddingRequest {
+                input,
+                ..self.clone()
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Embedding {
+    pub index: usize,
+    #[serde(deserialize_with = "deserialize_embedding")]
+    pub embedding: Vec<f32>,
+    #[serde(default)]
+    pub object: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingUsage {
+    pub prompt_tokens: usize,
+    pub total_tokens: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingResponse {
+    #[serde(default)]
+    pub object: String,
+    pub data: Vec<Embedding>,
+    pub model: String,
+    #[serde(default)]
+    pub usage: EmbeddingUsage,
+}
+
+impl EmbeddingResponse {
+    /// The embeddings in input order.
+    pub fn embeddings(&self) -> Vec<&[f32]> {
+        let mut data: Vec<_> = self.data.iter().collect();
+        data.sort_by_key(|embedding| embedding.index);
+
+        data.into_iter()
+            .map(|embedding| embedding.embedding.as_slice())
+            .collect()
+    }
+
+    /// Appends the response to a later batch, shifting its indices past the
+    /// embeddings already collected.
+    pub(crate) fn merge(&mut self, other: EmbeddingResponse) {
+        let offset = self.data.len();
+
+        self.data
+            .extend(other.data.into_iter().map(|embedding| Embedding {
+                index: embedding.index + offset,
+                ..embedding
+            }));
+        self.usage.prompt_tokens += other.usage.prompt_tokens;
+        self.usage.total_tokens += other.usage.total_tokens;
+    }
+}
+
+fn deserialize_embedding<'de, D>(deserializer: D) -> Result<Vec<f32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Encoded {
+        Float(Vec<f32>),
+        Base64(String),
+    }
+
+    match Encoded::deserialize(deserializer)? {
+        Encoded::Float(values) => Ok(values),
+        Encoded::Base64(value) => {
+            let bytes = STANDARD.decode(value).map_err(de::Error::custom)?;
+
+            if bytes.len() % 4 != 0 {
+                return Err(de::Error::custom(
+                    "base64 embedding is not a whole number of f32 values",
+                ));
+            }
+
+            Ok(bytes
+                .chunks_exact(4)
+                .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_requests() {
+        let request =
+            EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, vec!["first", "second"])
+                .dimensions(256)
+                .encoding_format(EncodingFormat::Base64)
+                .batch_size(10);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "input": ["first", "second"],
+                "model": "text-embedding-3-small",
+                "dimensions": 256,
+                "encoding_format": "base64"
+            })
+        );
+    }
+
+    #[test]
+    fn it_decodes_base64_embeddings() {
+        let bytes: Vec<u8> = [0.5f32, -1.25, 3.0]
+            .iter()
+            .flat_map(|value| value.to_le_bytes())
+            .collect();
+
+        let embedding: Embedding = serde_json::from_value(json!({
+            "object": "embedding",
+            "index": 0,
+            "embedding": STANDARD.encode(bytes)
+        }))
+        .unwrap();
+
+        assert_eq!(embedding.embedding, vec![0.5, -1.25, 3.0]);
+
+        let error = serde_json::from_value::<Embedding>(json!({
+            "index": 0,
+            "embedding": STANDARD.encode([1u8, 2, 3])
+        }));
+        assert!(error.is_err());
+    }
+
+    #[test]
+    fn it_splits_large_inputs_into_batches() {
+        let texts: Vec<String> = (0..5).map(|i| i.to_string()).collect();
+        let request =
+            EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, texts).batch_size(2);
+
+        let batches: Vec<_> = request
+            .batches()
+            .iter()
+            .map(|batch| batch.input().len())
+            .collect();
+        assert_eq!(batches, vec![2, 2, 1]);
+
+        let single = EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, "hello");
+        assert_eq!(single.batches(), vec![single.clone()]);
+    }
+
+    #[test]
+    fn it_merges_batches_in_order() {
+        let response = |values: &[f32], tokens: usize| EmbeddingResponse {
+            object: "list".to_string(),
+            data: values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| Embedding {
+                    index,
+                    embedding: vec![*value],
+                    object: "embedding".to_string(),
+                })
+                .collect(),
+            model: "text-embedding-3-small".to_string(),
+            usage: EmbeddingUsage {
+                prompt_tokens: tokens,
+                total_tokens: tokens,
+            },
+        };
+
+        let mut merged = response(&[1.0, 2.0], 4);
+        merged.merge(response(&[3.0], 2));
+
+        assert_eq!(
+            merged.embeddings(),
+            vec![&[1.0][..], &[2.0][..], &[3.0][..]]
+        );
+        assert_eq!(merged.data[2].index, 2);
+        assert_eq!(merged.usage.total_tokens, 6);
+    }
+}
@@ -1,3 +1,5 @@
+use crate::openai::moderation::ModerationResponse;
+use crate::utils::errors::ProviderError;
 use std::{error::Error, fmt::Display};
 
 #[derive(Debug, PartialEq)]
@@ -55,3 +57,47 @@ impl From<ConversionError> for InputError {
         InputError::ConversionError(error)
     }
 }
+
+/// Why a moderated request was not generated.
+#[derive(Debug)]
+pub enum GuardError {
+    /// The input was flagged, with the moderation result that flagged it.
+    Flagged(Box<ModerationResponse>),
+    Provider(ProviderError),
+}
+
+impl Display for GuardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GuardError::Flagged(moderation) => {
+                let categories: Vec<&str> = moderation
+                    .flagged_categories()
+                    .iter()
+                    .map(|category| category.as_str())
+                    .collect();
+
+                write!(
+                    f,
+                    "Content flagged by moderation: {}",
+                    categories.join(", ")
+                )
+            }
+            GuardError::Provider(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl Error for GuardError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GuardError::Flagged(_) => None,
+            GuardError::Provider(err) => Some(err),
+        }
+    }
+}
+
+impl From<ProviderError> for GuardError {
+    fn from(error: ProviderError) -> Self {
+        GuardError::Provider(error)
+    }
+}
@@ -0,0 +1,590 @@
+use crate::openai::constants::OpenAIModelId;
+use crate::openai::upload::FileUpload;
+use crate::utils::errors::ProviderError;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ImageSize {
+    #[serde(rename = "auto")]
+    Auto,
+    #[serde(rename = "256x256")]
+    Size256x256,
+    #[serde(rename = "512x512")]
+    Size512x512,
+    #[serde(rename = "1024x1024")]
+    Size1024x1024,
+    #[serde(rename = "1536x1024")]
+    Size1536x1024,
+    #[serde(rename = "1024x1536")]
+    Size1024x1536,
+    #[serde(rename = "1792x1024")]
+    Size1792x1024,
+    #[serde(rename = "1024x1792")]
+    Size1024x1792,
+}
+
+impl ImageSize {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageSize::Auto => "auto",
+            ImageSize::Size256x256 => "256x256",
+            ImageSize::Size512x512 => "512x512",
+            ImageSize::Size1024x1024 => "1024x1024",
+            ImageSize::Size1536x1024 => "1536x1024",
+            ImageSize::Size1024x1536 => "1024x1536",
+            ImageSize::Size1792x1024 => "1792x1024",
+            ImageSize::Size1024x1792 => "1024x1792",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageQuality {
+    Auto,
+    High,
+    Medium,
+    Low,
+    /// `dall-e-3` only.
+    Standard,
+    /// `dall-e-3` only.
+    Hd,
+}
+
+impl ImageQuality {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageQuality::Auto => "auto",
+            ImageQuality::High => "high",
+            ImageQuality::Medium => "medium",
+            ImageQuality::Low => "low",
+            ImageQuality::Standard => "standard",
+            ImageQuality::Hd => "hd",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageBackground {
+    Auto,
+    Transparent,
+    Opaque,
+}
+
+impl ImageBackground {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageBackground::Auto => "auto",
+            ImageBackground::Transparent => "transparent",
+            ImageBackground::Opaque => "opaque",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageOutputFormat {
+    Png,
+    Jpeg,
+    Webp,
+}
+
+impl ImageOutputFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageOutputFormat::Png => "png",
+            ImageOutputFormat::Jpeg => "jpeg",
+            ImageOutputFormat::Webp => "webp",
+        }
+    }
+}
+
+/// How `dall-e` models return images. `gpt-image-1` always returns `b64_json`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImageResponseFormat {
+    Url,
+    B64Json,
+}
+
+impl ImageResponseFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImageResponseFormat::Url => "url",
+            ImageResponseFormat::B64Json => "b64_json",
+        }
+    }
+}
+
+fn validate_options(n: Option<u8>, output_compression: Option<u8>) -> Result<(), ProviderError> {
+    if let Some(n) = n {
+        if !(1..=10).contains(&n) {
+            return Err(ProviderError::ValidationError(format!(
+                "n must be between 1 and 10, got {}",
+                n
+            )));
+        }
+    }
+
+    if let Some(compression) = output_compression {
+        if compression > 100 {
+            return Err(ProviderError::ValidationError(format!(
+                "output_compression must be between 0 and 100, got {}",
+                compression
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageGenerationRequest {
+    prompt: String,
+    model: OpenAIModelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    size: Option<ImageSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quality: Option<ImageQuality>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<ImageBackground>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_format: Option<ImageOutputFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output_compression: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ImageResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+impl ImageGenerationRequest {
+    pub fn new(model: OpenAIModelId, prompt: impl Into<String>) -> Self {
+        Self {
+            prompt: prompt.into(),
+            model,
+            n: None,
+            size: None,
+            quality: None,
+            background: None,
+            output_format: None,
+            output_compression: None,
+            response_format: None,
+            user: None,
+        }
+    }
+
+    pub fn n(mut self, value: u8) -> Self {
+        self.n = Some(value);
+        self
+    }
+
+    pub fn size(mut self, value: ImageSize) -> Self {
+        self.size = Some(value);
+        self
+    }
+
+    pub fn quality(mut self, value: ImageQuality) -> Self {
+        self.quality = Some(value);
+        self
+    }
+
+    pub fn background(mut self, value: ImageBackground) -> Self {
+        self.background = Some(value);
+        self
+    }
+
+    pub fn output_format(mut self, value: ImageOutputFormat) -> Self {
+        self.output_format = Some(value);
+        self
+    }
+
+    /// Compression level from 0 to 100, for `jpeg` and `webp` output.
+    pub fn output_compression(mut self, value: u8) -> Self {
+        self.output_compression = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ImageResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        validate_options(self.n, self.output_compression)
+    }
+}
+
+/// Edits one or more images following a prompt. Sent as `multipart/form-data`.
+///
+/// `gpt-image-1` accepts several input images; `dall-e-2` takes exactly one. The
+/// optional mask is a PNG whose transparent areas mark what should be edited.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageEditRequest {
+    images: Vec<FileUpload>,
+    prompt: String,
+    model: OpenAIModelId,
+    mask: Option<FileUpload>,
+    n: Option<u8>,
+    size: Option<ImageSize>,
+    quality: Option<ImageQuality>,
+    background: Option<ImageBackground>,
+    output_format: Option<ImageOutputFormat>,
+    output_compression: Option<u8>,
+    response_format: Option<ImageResponseFormat>,
+    user: Option<String>,
+}
+
+impl ImageEditRequest {
+    pub fn new(model: OpenAIModelId, image: FileUpload, prompt: impl Into<String>) -> Self {
+        Self {
+            images: vec![image],
+            prompt: prompt.into(),
+            model,
+            mask: None,
+            n: None,
+            size: None,
+            quality: None,
+            background: None,
+            output_format: None,
+            output_compression: None,
+            response_format: None,
+            user: None,
+        }
+    }
+
+    pub fn add_image(mut self, value: FileUpload) -> Self {
+        self.images.push(value);
+        self
+    }
+
+    pub fn mask(mut self, value: FileUpload) -> Self {
+        self.mask = Some(value);
+        self
+    }
+
+    pub fn n(mut self, value: u8) -> Self {
+        self.n = Some(value);
+        self
+    }
+
+    pub fn size(mut self, value: ImageSize) -> Self {
+        self.size = Some(value);
+        self
+    }
+
+    pub fn quality(mut self, value: ImageQuality) -> Self {
+        self.quality = Some(value);
+        self
+    }
+
+    pub fn background(mut self, value: ImageBackground) -> Self {
+        self.background = Some(value);
+        self
+    }
+
+    pub fn output_format(mut self, value: ImageOutputFormat) -> Self {
+        self.output_format = Some(value);
+        self
+    }
+
+    pub fn output_compression(mut self, value: u8) -> Self {
+        self.output_compression = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ImageResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        if self.images.len() > 1 && self.model != OpenAIModelId::GptImage1 {
+            return Err(ProviderError::CapabilityError(format!(
+                "{} only accepts a single image to edit",
+                self.model
+            )));
+        }
+
+        validate_options(self.n, self.output_compression)
+    }
+
+    pub(crate) fn to_form(&self) -> Result<Form, ProviderError> {
+        let image_field = if self.images.len() > 1 {
+            "image[]"
+        } else {
+            "image"
+        };
+
+        let mut form = Form::new()
+            .text("prompt", self.prompt.clone())
+            .text("model", self.model.to_string());
+
+        for image in &self.images {
+            form = form.part(image_field, image.to_part()?);
+        }
+
+        if let Some(mask) = &self.mask {
+            form = form.part("mask", mask.to_part()?);
+        }
+
+        let fields = [
+            ("n", self.n.map(|n| n.to_string())),
+            ("size", self.size.map(|size| size.as_str().to_string())),
+            ("quality", self.quality.map(|q| q.as_str().to_string())),
+            (
+                "background",
+                self.background.map(|b| b.as_str().to_string()),
+            ),
+            (
+                "output_format",
+                self.output_format.map(|f| f.as_str().to_string()),
+            ),
+            (
+                "output_compression",
+                self.output_compression.map(|c| c.to_string()),
+            ),
+            (
+                "response_format",
+                self.response_format.map(|f| f.as_str().to_string()),
+            ),
+            ("user", self.user.clone()),
+        ];
+
+        Ok(fields
+            .into_iter()
+            .fold(form, |form, (name, value)| match value {
+                Some(value) => form.text(name, value),
+                None => form,
+            }))
+    }
+}
+
+/// Creates variations of an image. Only supported by `dall-e-2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ImageVariationRequest {
+    image: FileUpload,
+    model: OpenAIModelId,
+    n: Option<u8>,
+    size: Option<ImageSize>,
+    response_format: Option<ImageResponseFormat>,
+    user: Option<String>,
+}
+
+impl ImageVariationRequest {
+    pub fn new(model: OpenAIModelId, image: FileUpload) -> Self {
+        Self {
+            image,
+            model,
+            n: None,
+            size: None,
+            response_format: None,
+            user: None,
+        }
+    }
+
+    pub fn n(mut self, value: u8) -> Self {
+        self.n = Some(value);
+        self
+    }
+
+    pub fn size(mut self, value: ImageSize) -> Self {
+        self.size = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ImageResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        validate_options(self.n, None)
+    }
+
+    pub(crate) fn to_form(&self) -> Result<Form, ProviderError> {
+        let form = Form::new()
+            .part("image", self.image.to_part()?)
+            .text("model", self.model.to_string());
+
+        let fields = [
+            ("n", self.n.map(|n| n.to_string())),
+            ("size", self.size.map(|size| size.as_str().to_string())),
+            (
+                "response_format",
+                self.response_format.map(|f| f.as_str().to_string()),
+            ),
+            ("user", self.user.clone()),
+        ];
+
+        Ok(fields
+            .into_iter()
+            .fold(form, |form, (name, value)| match value {
+                Some(value) => form.text(name, value),
+                None => form,
+            }))
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageInputTokensDetails {
+    #[serde(default)]
+    pub text_tokens: usize,
+    #[serde(default)]
+    pub image_tokens: usize,
+}
+
+/// Token usage, reported for `gpt-image-1` only.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageUsage {
+    pub input_tokens: usize,
+    pub output_tokens: usize,
+    pub total_tokens: usize,
+    #[serde(default)]
+    pub input_tokens_details: ImageInputTokensDetails,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Image {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b64_json: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revised_prompt: Option<String>,
+}
+
+impl Image {
+    /// Decodes the base64 image data. Fails for images returned as URLs.
+    pub fn bytes(&self) -> Result<Vec<u8>, ProviderError> {
+        let data = self.b64_json.as_deref().ok_or_else(|| {
+            ProviderError::ValidationError("image was not returned as b64_json".to_string())
+        })?;
+
+        STANDARD
+            .decode(data)
+            .map_err(|e| ProviderError::DeserializationError(e.to_string()))
+    }
+
+    pub async fn save(&self, path: impl AsRef<Path>) -> Result<(), ProviderError> {
+        let path = path.as_ref();
+
+        tokio::fs::write(path, self.bytes()?)
+            .await
+            .map_err(|e| ProviderError::InternalError(format!("{}: {}", path.display(), e)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImagesResponse {
+    pub created: u64,
+    pub data: Vec<Image>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<ImageUsage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quality: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_generation_requests() {
+        let request = ImageGenerationRequest::new(OpenAIModelId::GptImage1, "A red fox")
+            .size(ImageSize::Size1536x1024)
+            .quality(ImageQuality::High)
+            .background(ImageBackground::Transparent)
+            .output_format(ImageOutputFormat::Webp)
+            .output_compression(80)
+            .n(2);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "prompt": "A red fox",
+                "model": "gpt-image-1",
+                "n": 2,
+                "size": "1536x1024",
+                "quality": "high",
+                "background": "transparent",
+                "output_format": "webp",
+                "output_compression": 80
+            })
+        );
+    }
+
+    #[test]
+    fn it_validates_image_options() {
+        let request = ImageGenerationRequest::new(OpenAIModelId::GptImage1, "A red fox");
+
+        assert!(request.clone().n(10).validate().is_ok());
+        assert!(matches!(
+            request.clone().n(11).validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            request.output_compression(101).validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+
+        let edit = ImageEditRequest::new(
+            OpenAIModelId::DallE2,
+            FileUpload::new("a.png", vec![]),
+            "Add a hat",
+        )
+        .add_image(FileUpload::new("b.png", vec![]));
+        assert!(matches!(
+            edit.validate(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+    }
+
+    #[test]
+    fn it_decodes_base64_images() {
+        let image = Image {
+            b64_json: Some(STANDARD.encode(b"\x89PNG")),
+            url: None,
+            revised_prompt: None,
+        };
+        assert_eq!(image.bytes().unwrap(), b"\x89PNG");
+
+        let url = Image {
+            b64_json: None,
+            url: Some("https://example.com/image.png".to_string()),
+            revised_prompt: None,
+        };
+        assert!(matches!(
+            url.bytes(),
+            Err(ProviderError::ValidationError(_))
+        ));
+    }
+}
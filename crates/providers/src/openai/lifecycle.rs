@@ -0,0 +1,156 @@
+use crate::openai::request::{include::Include, input_models::item::Item};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "asc",
+            SortOrder::Desc => "desc",
+        }
+    }
+}
+
+/// Query parameters for listing the input items of a stored response.
+///
+/// Pages are walked by passing the `last_id` of one page as `after` for the next,
+/// for as long as `has_more` is set.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ListInputItemsParams {
+    after: Option<String>,
+    before: Option<String>,
+    limit: Option<u8>,
+    order: Option<SortOrder>,
+    include: Vec<Include>,
+}
+
+impl ListInputItemsParams {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn after(mut self, value: impl Into<String>) -> Self {
+        self.after = Some(value.into());
+        self
+    }
+
+    pub fn before(mut self, value: impl Into<String>) -> Self {
+        self.before = Some(value.into());
+        self
+    }
+
+    /// Number of items per page, between 1 and 100.
+    pub fn limit(mut self, value: u8) -> Self {
+        self.limit = Some(value);
+        self
+    }
+
+    pub fn order(mut self, value: SortOrder) -> Self {
+        self.order = Some(value);
+        self
+    }
+
+    pub fn include(mut self, value: Include) -> Self {
+        self.include.push(value);
+        self
+    }
+
+    pub(crate) fn to_query(&self) -> Vec<(&'static str, String)> {
+        let mut query = Vec::new();
+
+        if let Some(after) = &self.after {
+            query.push(("after", after.clone()));
+        }
+
+        if let Some(before) = &self.before {
+            query.push(("before", before.clone()));
+        }
+
+        if let Some(limit) = self.limit {
+            query.push(("limit", limit.to_string()));
+        }
+
+        if let Some(order) = self.order {
+            query.push(("order", order.as_str().to_string()));
+        }
+
+        query.extend(include_query(&self.include));
+        query
+    }
+}
+
+pub(crate) fn include_query(include: &[Include]) -> Vec<(&'static str, String)> {
+    include
+        .iter()
+        .map(|value| ("include[]", value.as_str().to_string()))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct InputItemsPage {
+    #[serde(default)]
+    pub object: String,
+    pub data: Vec<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_id: Option<String>,
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+impl InputItemsPage {
+    /// The `params` this page was listed with, moved on to the page after it, if
+    /// there is one.
+    pub fn next_params(&self, params: &ListInputItemsParams) -> Option<ListInputItemsParams> {
+        match (&self.last_id, self.has_more) {
+            (Some(last_id), true) => Some(ListInputItemsParams {
+                after: Some(last_id.clone()),
+                before: None,
+                ..params.clone()
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DeletedResponse {
+    pub id: String,
+    #[serde(default)]
+    pub object: String,
+    pub deleted: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_builds_list_queries() {
+        let params = ListInputItemsParams::new()
+            .after("msg_1")
+            .limit(20)
+            .order(SortOrder::Asc)
+            .include(Include::FileSearchCallResults)
+            .include(Include::MessageInputImageUrl);
+
+        assert_eq!(
+            params.to_query(),
+            vec![
+                ("after", "msg_1".to_string()),
+                ("limit", "20".to_string()),
+                ("order", "asc".to_string()),
+                ("include[]", "file_search_call.results".to_string()),
+                ("include[]", "message.input_image.image_url".to_string()),
+            ]
+        );
+        assert!(ListInputItemsParams::new().to_query().is_empty());
+    }
+}
@@ -0,0 +1,376 @@
+use crate::openai::constants::OpenAIModelId;
+use crate::openai::request::input::{Input, InputItemList};
+use crate::openai::request::input_models::{
+    common::Content, input_message::InputMessage, item::Item,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModerationImageUrl {
+    pub url: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationContent {
+    Text { text: String },
+    ImageUrl { image_url: ModerationImageUrl },
+}
+
+impl ModerationContent {
+    pub fn text(value: impl Into<String>) -> Self {
+        ModerationContent::Text { text: value.into() }
+    }
+
+    /// A public URL or a base64 data URL.
+    pub fn image_url(value: impl Into<String>) -> Self {
+        ModerationContent::ImageUrl {
+            image_url: ModerationImageUrl { url: value.into() },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ModerationInput {
+    Text(String),
+    Texts(Vec<String>),
+    /// Mixed text and images. Only supported by the omni moderation models.
+    Content(Vec<ModerationContent>),
+}
+
+impl ModerationInput {
+    /// Collects the text and image URLs of every message in a Responses API input.
+    /// Returns `None` when there is nothing to moderate.
+    ///
+    /// The moderation endpoint only accepts images by URL, so images given by
+    /// `file_id` and file contents are not included and go unchecked.
+    pub fn from_input(input: &Input) -> Option<Self> {
+        let items = match input {
+            Input::Message(text) if text.is_empty() => return None,
+            Input::Message(text) => return Some(ModerationInput::Text(text.clone())),
+            Input::Messages(items) => items,
+        };
+
+        let mut content = vec![];
+
+        for item in items {
+            match item {
+                InputItemList::InputMessage(InputMessage::TextInput(message)) => {
+                    content.push(ModerationContent::text(message.content.clone()));
+                }
+                InputItemList::InputMessage(InputMessage::InputItemContentList(message)) => {
+                    content.extend(message.content.iter().filter_map(to_moderation_content));
+                }
+                InputItemList::Item(Item::InputMessage(message)) => {
+                    content.extend(message.content.iter().filter_map(to_moderation_content));
+                }
+                _ => {}
+            }
+        }
+
+        content.retain(
+            |content| !matches!(content, ModerationContent::Text { text } if text.is_empty()),
+        );
+
+        (!content.is_empty()).then_some(ModerationInput::Content(content))
+    }
+}
+
+fn to_moderation_content(content: &Content) -> Option<ModerationContent> {
+    match content {
+        Content::Text(text) => Some(ModerationContent::text(text.text.clone())),
+        Content::Image(image) => image.image_url.clone().map(ModerationContent::image_url),
+        Content::File(_) => None,
+    }
+}
+
+impl From<&str> for ModerationInput {
+    fn from(value: &str) -> Self {
+        ModerationInput::Text(value.to_string())
+    }
+}
+
+impl From<String> for ModerationInput {
+    fn from(value: String) -> Self {
+        ModerationInput::Text(value)
+    }
+}
+
+impl From<Vec<String>> for ModerationInput {
+    fn from(value: Vec<String>) -> Self {
+        ModerationInput::Texts(value)
+    }
+}
+
+impl From<Vec<ModerationContent>> for ModerationInput {
+    fn from(value: Vec<ModerationContent>) -> Self {
+        ModerationInput::Content(value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModerationRequest {
+    input: ModerationInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    model: Option<OpenAIModelId>,
+}
+
+impl ModerationRequest {
+    pub fn new(input: impl Into<ModerationInput>) -> Self {
+        Self {
+            input: input.into(),
+            model: None,
+        }
+    }
+
+    /// Defaults to `omni-moderation-latest` on the server.
+    pub fn model(mut self, value: OpenAIModelId) -> Self {
+        self.model = Some(value);
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ModerationCategory {
+    #[serde(rename = "sexual")]
+    Sexual,
+    #[serde(rename = "sexual/minors")]
+    SexualMinors,
+    #[serde(rename = "harassment")]
+    Harassment,
+    #[serde(rename = "harassment/threatening")]
+    HarassmentThreatening,
+    #[serde(rename = "hate")]
+    Hate,
+    #[serde(rename = "hate/threatening")]
+    HateThreatening,
+    #[serde(rename = "illicit")]
+    Illicit,
+    #[serde(rename = "illicit/violent")]
+    IllicitViolent,
+    #[serde(rename = "self-harm")]
+    SelfHarm,
+    #[serde(rename = "self-harm/intent")]
+    SelfHarmIntent,
+    #[serde(rename = "self-harm/instructions")]
+    SelfHarmInstructions,
+    #[serde(rename = "violence")]
+    Violence,
+    #[serde(rename = "violence/graphic")]
+    ViolenceGraphic,
+}
+
+impl ModerationCategory {
+    pub const ALL: [ModerationCategory; 13] = [
+        ModerationCategory::Sexual,
+        ModerationCategory::SexualMinors,
+        ModerationCategory::Harassment,
+        ModerationCategory::HarassmentThreatening,
+        ModerationCategory::Hate,
+        ModerationCategory::HateThreatening,
+        ModerationCategory::Illicit,
+        ModerationCategory::IllicitViolent,
+        ModerationCategory::SelfHarm,
+        ModerationCategory::SelfHarmIntent,
+        ModerationCategory::SelfHarmInstructions,
+        ModerationCategory::Violence,
+        ModerationCategory::ViolenceGraphic,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationCategory::Sexual => "sexual",
+            ModerationCategory::SexualMinors => "sexual/minors",
+            ModerationCategory::Harassment => "harassment",
+            ModerationCategory::HarassmentThreatening => "harassment/threatening",
+            ModerationCategory::Hate => "hate",
+            ModerationCategory::HateThreatening => "hate/threatening",
+            ModerationCategory::Illicit => "illicit",
+            ModerationCategory::IllicitViolent => "illicit/violent",
+            ModerationCategory::SelfHarm => "self-harm",
+            ModerationCategory::SelfHarmIntent => "self-harm/intent",
+            ModerationCategory::SelfHarmInstructions => "self-harm/instructions",
+            ModerationCategory::Violence => "violence",
+            ModerationCategory::ViolenceGraphic => "violence/graphic",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationInputType {
+    Text,
+    Image,
+}
+
+/// One value per moderation category: a flag, a score or the input types that
+/// contributed to it. Categories a model does not report keep their default.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ModerationCategories<T> {
+    pub sexual: T,
+    #[serde(rename = "sexual/minors")]
+    pub sexual_minors: T,
+    pub harassment: T,
+    #[serde(rename = "harassment/threatening")]
+    pub harassment_threatening: T,
+    pub hate: T,
+    #[serde(rename = "hate/threatening")]
+    pub hate_threatening: T,
+    pub illicit: T,
+    #[serde(rename = "illicit/violent")]
+    pub illicit_violent: T,
+    #[serde(rename = "self-harm")]
+    pub self_harm: T,
+    #[serde(rename = "self-harm/intent")]
+    pub self_harm_intent: T,
+    #[serde(rename = "self-harm/instructions")]
+    pub self_harm_instructions: T,
+    pub violence: T,
+    #[serde(rename = "violence/graphic")]
+    pub violence_graphic: T,
+}
+
+impl<T> ModerationCategories<T> {
+    pub fn get(&self, category: ModerationCategory) -> &T {
+        match category {
+            ModerationCategory::Sexual => &self.sexual,
+            ModerationCategory::SexualMinors => &self.sexual_minors,
+            ModerationCategory::Harassment => &self.harassment,
+            ModerationCategory::HarassmentThreatening => &self.harassment_threatening,
+            ModerationCategory::Hate => &self.hate,
+            ModerationCategory::HateThreatening => &self.hate_threatening,
+            ModerationCategory::Illicit => &self.illicit,
+            ModerationCategory::IllicitViolent => &self.illicit_violent,
+            ModerationCategory::SelfHarm => &self.self_harm,
+            ModerationCategory::SelfHarmIntent => &self.self_harm_intent,
+            ModerationCategory::SelfHarmInstructions => &self.self_harm_instructions,
+            ModerationCategory::Violence => &self.violence,
+            ModerationCategory::ViolenceGraphic => &self.violence_graphic,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (ModerationCategory, &T)> {
+        ModerationCategory::ALL
+            .into_iter()
+            .map(move |category| (category, self.get(category)))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResult {
+    pub flagged: bool,
+    pub categories: ModerationCategories<bool>,
+    pub category_scores: ModerationCategories<f64>,
+    /// Only reported by the omni moderation models.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category_applied_input_types: Option<ModerationCategories<Vec<ModerationInputType>>>,
+}
+
+impl ModerationResult {
+    pub fn flagged_categories(&self) -> Vec<ModerationCategory> {
+        self.categories
+            .iter()
+            .filter(|(_, flagged)| **flagged)
+            .map(|(category, _)| category)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ModerationResponse {
+    pub id: String,
+    pub model: String,
+    pub results: Vec<ModerationResult>,
+}
+
+impl ModerationResponse {
+    pub fn flagged(&self) -> bool {
+        self.results.iter().any(|result| result.flagged)
+    }
+
+    /// The categories flagged for any input, without duplicates.
+    pub fn flagged_categories(&self) -> Vec<ModerationCategory> {
+        ModerationCategory::ALL
+            .into_iter()
+            .filter(|category| {
+                self.results
+                    .iter()
+                    .any(|result| *result.categories.get(*category))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openai::request::input_models::{
+        common::{ImageContent, Role, TextContent},
+        input_message::{InputItemContentList, TextInput},
+    };
+    use serde_json::json;
+
+    #[test]
+    fn it_collects_text_and_images_from_request_input() {
+        let question = InputItemContentList {
+            role: Role::User,
+            content: vec![
+                TextContent::new().text("What is in this image?").into(),
+                ImageContent::new()
+                    .image_url("https://example.com/cat.png")
+                    .into(),
+            ],
+            type_field: None,
+        };
+        let input = Input::Messages(vec![
+            InputItemList::InputMessage(InputMessage::TextInput(TextInput::new("Be helpful."))),
+            InputItemList::InputMessage(InputMessage::InputItemContentList(question)),
+        ]);
+
+        assert_eq!(
+            serde_json::to_value(ModerationInput::from_input(&input)).unwrap(),
+            json!([
+                { "type": "text", "text": "Be helpful." },
+                { "type": "text", "text": "What is in this image?" },
+                { "type": "image_url", "image_url": { "url": "https://example.com/cat.png" } }
+            ])
+        );
+        assert_eq!(ModerationInput::from_input(&Input::default()), None);
+    }
+
+    #[test]
+    fn it_deserializes_moderation_results() {
+        let response: ModerationResponse = serde_json::from_value(json!({
+            "id": "modr-123",
+            "model": "omni-moderation-latest",
+            "results": [{
+                "flagged": true,
+                "categories": { "violence": true, "violence/graphic": false, "harassment": false },
+                "category_scores": { "violence": 0.86, "violence/graphic": 0.1, "harassment": 0.001 },
+                "category_applied_input_types": { "violence": ["image"], "harassment": ["text"] }
+            }]
+        }))
+        .unwrap();
+
+        let result = &response.results[0];
+        assert!(response.flagged());
+        assert_eq!(
+            response.flagged_categories(),
+            vec![ModerationCategory::Violence]
+        );
+        assert_eq!(
+            *result.category_scores.get(ModerationCategory::Violence),
+            0.86
+        );
+        assert_eq!(
+            result
+                .category_applied_input_types
+                .as_ref()
+                .unwrap()
+                .violence,
+            vec![ModerationInputType::Image]
+        );
+    }
+}
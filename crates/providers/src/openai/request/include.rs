@@ -4,7 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::openai::errors::ConversionError;
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Include {
     #[serde(rename = "file_search_call.results")]
     FileSearchCallResults,
@@ -14,6 +14,16 @@ pub enum Include {
     ComputerCallOutputImageUrl,
 }
 
+impl Include {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Include::FileSearchCallResults => "file_search_call.results",
+            Include::MessageInputImageUrl => "message.input_image.image_url",
+            Include::ComputerCallOutputImageUrl => "computer_call_output.output.image_url",
+        }
+    }
+}
+
 impl FromStr for Include {
     type Err = ConversionError;
 
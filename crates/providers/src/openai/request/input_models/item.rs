@@ -7,9 +7,11 @@ use crate::openai::common::{
     reasoning_item::ReasoningItem, status::Status,
     web_search_tool_call_item::WebSearchToolCallItem,
 };
+use crate::openai::response::response_output::ResponseOutput;
 use std::str::FromStr;
 
-use serde::{Deserialize, Serialize};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Default)]
 pub struct InputMessageItem {
@@ -198,8 +200,7 @@ impl Summary {
     }
 }
 
-#[derive(Debug, PartialEq, Serialize, Deserialize)]
-#[serde(untagged)]
+#[derive(Debug, PartialEq)]
 pub enum Item {
     InputMessage(InputMessageItem),
     OutputMessage(OutputMessageItem),
@@ -212,6 +213,114 @@ pub enum Item {
     Reasoning(ReasoningItem),
 }
 
+/// Matches items sent without a `type` by shape.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum UntaggedItem {
+    InputMessage(InputMessageItem),
+    OutputMessage(OutputMessageItem),
+    FileSearchToolCall(FileSearchToolCallItem),
+    ComputerToolCall(ComputerToolCallItem),
+    ComputerToolCallOutput(ComputerToolCallOutputItem),
+    WebSearchToolCall(WebSearchToolCallItem),
+    FunctionToolCall(FunctionToolCallItem),
+    FunctionToolCallOutput(FunctionToolCallOutputItem),
+    Reasoning(ReasoningItem),
+}
+
+impl From<UntaggedItem> for Item {
+    fn from(item: UntaggedItem) -> Self {
+        match item {
+            UntaggedItem::InputMessage(item) => item.into(),
+            UntaggedItem::OutputMessage(item) => item.into(),
+            UntaggedItem::FileSearchToolCall(item) => item.into(),
+            UntaggedItem::ComputerToolCall(item) => item.into(),
+            UntaggedItem::ComputerToolCallOutput(item) => item.into(),
+            UntaggedItem::WebSearchToolCall(item) => item.into(),
+            UntaggedItem::FunctionToolCall(item) => item.into(),
+            UntaggedItem::FunctionToolCallOutput(item) => item.into(),
+            UntaggedItem::Reasoning(item) => item.into(),
+        }
+    }
+}
+
+/// Items are told apart by their `type`, since several item shapes (web search
+/// calls in particular) would otherwise match almost any object. Items sent
+/// without a `type` are matched by shape.
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let item_type = value.get("type").and_then(Value::as_str);
+
+        let parsed = match item_type {
+            Some("message") if value.get("role").and_then(Value::as_str) == Some("assistant") => {
+                serde_json::from_value(value).map(Item::OutputMessage)
+            }
+            Some("message") => serde_json::from_value(value).map(Item::InputMessage),
+            Some("file_search_call") => serde_json::from_value(value).map(Item::FileSearchToolCall),
+            Some("computer_call") => serde_json::from_value(value).map(Item::ComputerToolCall),
+            Some("computer_call_output") => {
+                serde_json::from_value(value).map(Item::ComputerToolCallOutput)
+            }
+            Some("web_search_call") => serde_json::from_value(value).map(Item::WebSearchToolCall),
+            Some("function_call") => serde_json::from_value(value).map(Item::FunctionToolCall),
+            Some("function_call_output") => {
+                serde_json::from_value(value).map(Item::FunctionToolCallOutput)
+            }
+            Some("reasoning") => serde_json::from_value(value).map(Item::Reasoning),
+            _ => serde_json::from_value::<UntaggedItem>(value).map(Item::from),
+        };
+
+        parsed.map_err(de::Error::custom)
+    }
+}
+
+/// Output items don't carry their `type`, which is the tag of [`ResponseOutput`],
+/// so it is added when they are sent back as input.
+impl Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (value, item_type) = match self {
+            Item::InputMessage(item) => return item.serialize(serializer),
+            Item::ComputerToolCallOutput(item) => return item.serialize(serializer),
+            Item::FunctionToolCallOutput(item) => return item.serialize(serializer),
+            Item::OutputMessage(item) => (serde_json::to_value(item), "message"),
+            Item::FileSearchToolCall(item) => (serde_json::to_value(item), "file_search_call"),
+            Item::ComputerToolCall(item) => (serde_json::to_value(item), "computer_call"),
+            Item::WebSearchToolCall(item) => (serde_json::to_value(item), "web_search_call"),
+            Item::FunctionToolCall(item) => (serde_json::to_value(item), "function_call"),
+            Item::Reasoning(item) => (serde_json::to_value(item), "reasoning"),
+        };
+
+        let mut value = value.map_err(ser::Error::custom)?;
+
+        if let Value::Object(ref mut map) = value {
+            map.entry("type")
+                .or_insert_with(|| Value::String(item_type.to_string()));
+        }
+
+        value.serialize(serializer)
+    }
+}
+
+impl From<ResponseOutput> for Item {
+    fn from(output: ResponseOutput) -> Self {
+        match output {
+            ResponseOutput::OutputMessage(item) => Item::OutputMessage(item),
+            ResponseOutput::FileSearchToolCall(item) => Item::FileSearchToolCall(item),
+            ResponseOutput::ComputerToolCall(item) => Item::ComputerToolCall(item),
+            ResponseOutput::WebSearchToolCall(item) => Item::WebSearchToolCall(item),
+            ResponseOutput::FunctionToolCall(item) => Item::FunctionToolCall(item),
+            ResponseOutput::Reasoning(item) => Item::Reasoning(item),
+        }
+    }
+}
+
 impl From<InputMessageItem> for Item {
     fn from(item: InputMessageItem) -> Self {
         Item::InputMessage(item)
@@ -265,3 +374,49 @@ impl From<ReasoningItem> for Item {
         Item::Reasoning(item)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_deserializes_items_by_type() {
+        let item: Item = serde_json::from_value(json!({
+            "type": "function_call_output",
+            "id": "fco_1",
+            "call_id": "call_1",
+            "output": "{}",
+            "status": "completed"
+        }))
+        .unwrap();
+        assert_eq!(
+            item,
+            Item::FunctionToolCallOutput(
+                FunctionToolCallOutputItem::new("call_1", "{}")
+                    .id("fco_1")
+                    .status(Status::Completed)
+            )
+        );
+
+        let item: Item = serde_json::from_value(json!({
+            "type": "web_search_call",
+            "id": "ws_1",
+            "status": "completed"
+        }))
+        .unwrap();
+        assert!(matches!(item, Item::WebSearchToolCall(_)));
+    }
+
+    #[test]
+    fn it_matches_untyped_items_by_shape() {
+        let item: Item = serde_json::from_value(json!({
+            "arguments": "{}",
+            "call_id": "call_1",
+            "name": "get_weather"
+        }))
+        .unwrap();
+
+        assert!(matches!(item, Item::FunctionToolCall(_)));
+    }
+}
@@ -0,0 +1,327 @@
+use crate::openai::common::output_message_item::{OutputContent, OutputText, Refusal};
+use crate::openai::request::input_models::item::Summary;
+use crate::openai::response::events::streaming::{OpenAIStreamingEvent, StreamingResponse};
+use crate::openai::response::response_output::ResponseOutput;
+use crate::openai::types::OpenAIResponse;
+use crate::utils::errors::ProviderError;
+use futures::{Stream, StreamExt};
+
+/// Rebuilds a response from its stream of events.
+///
+/// The accumulator keeps a live snapshot of every output item while deltas arrive,
+/// and once `response.completed`, `response.failed` or `response.incomplete` has
+/// been received it yields the same [`OpenAIResponse`] a non-streaming request
+/// would have returned.
+#[derive(Debug, Default)]
+pub struct ResponseAccumulator {
+    response: Option<StreamingResponse>,
+    output: Vec<ResponseOutput>,
+    done: bool,
+}
+
+impl ResponseAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The latest response snapshot sent by the server, without output.
+    pub fn response(&self) -> Option<&StreamingResponse> {
+        self.response.as_ref()
+    }
+
+    /// The output items as received so far.
+    pub fn output(&self) -> &[ResponseOutput] {
+        &self.output
+    }
+
+    /// Whether a terminal event has been received.
+    pub fn is_done(&self) -> bool {
+        self.done
+    }
+
+    pub fn push(&mut self, event: OpenAIStreamingEvent) {
+        match event {
+            OpenAIStreamingEvent::Created { response }
+            | OpenAIStreamingEvent::InProgress { response } => self.set_response(response),
+            OpenAIStreamingEvent::Completed { response }
+            | OpenAIStreamingEvent::Failed { response }
+            | OpenAIStreamingEvent::Incomplete { response } => {
+                self.set_response(response);
+                self.done = true;
+            }
+            OpenAIStreamingEvent::OutputItemAdded { output_index, item }
+            | OpenAIStreamingEvent::OutputItemDone { output_index, item } => {
+                if output_index < self.output.len() {
+                    self.output[output_index] = item;
+                } else {
+                    self.output.push(item);
+                }
+            }
+            OpenAIStreamingEvent::ContentPartAdded {
+                output_index,
+                content_index,
+                part,
+                ..
+            }
+            | OpenAIStreamingEvent::ContentPartDone {
+                output_index,
+                content_index,
+                part,
+                ..
+            } => {
+                if let Some(content) =
+                    self.content_mut(output_index, content_index, || part.clone())
+                {
+                    *content = part;
+                }
+            }
+            OpenAIStreamingEvent::OutputTextDelta {
+                output_index,
+                content_index,
+                delta,
+                ..
+            } => {
+                if let Some(OutputContent::OutputText(output)) =
+                    self.content_mut(output_index, content_index, output_text)
+                {
+                    output.text.push_str(&delta);
+                }
+            }
+            OpenAIStreamingEvent::OutputTextDone {
+                output_index,
+                content_index,
+                text,
+                ..
+            } => {
+                if let Some(OutputContent::OutputText(output)) =
+                    self.content_mut(output_index, content_index, output_text)
+                {
+                    output.text = text;
+                }
+            }
+            OpenAIStreamingEvent::OutputTextAnnotationAdded {
+                output_index,
+                content_index,
+                annotation_index,
+                annotation,
+                ..
+            } => {
+                if let Some(OutputContent::OutputText(output)) =
+                    self.content_mut(output_index, content_index, output_text)
+                {
+                    if annotation_index < output.annotations.len() {
+                        output.annotations[annotation_index] = annotation;
+                    } else {
+                        output.annotations.push(annotation);
+                    }
+                }
+            }
+            OpenAIStreamingEvent::RefusalDelta {
+                output_index,
+                content_index,
+                delta,
+                ..
+            } => {
+                if let Some(OutputContent::Refusal(refusal)) =
+                    self.content_mut(output_index, content_index, refusal)
+                {
+                    refusal.refusal.push_str(&delta);
+                }
+            }
+            OpenAIStreamingEvent::RefusalDone {
+                output_index,
+                content_index,
+                refusal: text,
+                ..
+            } => {
+                if let Some(OutputContent::Refusal(refusal)) =
+                    self.content_mut(output_index, content_index, refusal)
+                {
+                    refusal.refusal = text;
+                }
+            }
+            OpenAIStreamingEvent::FunctionCallArgumentsDelta {
+                output_index,
+                delta,
+                ..
+            } => {
+                if let Some(ResponseOutput::FunctionToolCall(call)) =
+                    self.output.get_mut(output_index)
+                {
+                    call.arguments.push_str(&delta);
+                }
+            }
+            OpenAIStreamingEvent::FunctionCallArgumentsDone {
+                output_index,
+                arguments,
+                ..
+            } => {
+                if let Some(ResponseOutput::FunctionToolCall(call)) =
+                    self.output.get_mut(output_index)
+                {
+                    call.arguments = arguments;
+                }
+            }
+            OpenAIStreamingEvent::ReasoningSummaryPartAdded {
+                output_index,
+                summary_index,
+                part,
+                ..
+            }
+            | OpenAIStreamingEvent::ReasoningSummaryPartDone {
+                output_index,
+                summary_index,
+                part,
+                ..
+            } => {
+                if let Some(summary) = self.summary_mut(output_index, summary_index) {
+                    summary.text = part.text;
+                }
+            }
+            OpenAIStreamingEvent::ReasoningSummaryTextDelta {
+                output_index,
+                summary_index,
+                delta,
+                ..
+            } => {
+                if let Some(summary) = self.summary_mut(output_index, summary_index) {
+                    summary.text.push_str(&delta);
+                }
+            }
+            OpenAIStreamingEvent::ReasoningSummaryTextDone {
+                output_index,
+                summary_index,
+                text,
+                ..
+            } => {
+                if let Some(summary) = self.summary_mut(output_index, summary_index) {
+                    summary.text = text;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns the final response once a terminal event has been received.
+    ///
+    /// The output sent with the terminal event is authoritative; the accumulated
+    /// items are only used when the server left it out.
+    pub fn finish(self) -> Option<OpenAIResponse> {
+        if !self.done {
+            return None;
+        }
+
+        let mut response = OpenAIResponse::from(self.response?);
+        if response.output.is_empty() {
+            response.output = self.output;
+        }
+
+        Some(response)
+    }
+
+    fn set_response(&mut self, mut response: StreamingResponse) {
+        if !response.output.is_empty() {
+            self.output = std::mem::take(&mut response.output);
+        }
+        self.response = Some(response);
+    }
+
+    fn content_mut(
+        &mut self,
+        output_index: usize,
+        content_index: usize,
+        create: impl FnOnce() -> OutputContent,
+    ) -> Option<&mut OutputContent> {
+        let message = match self.output.get_mut(output_index) {
+            Some(ResponseOutput::OutputMessage(message)) => message,
+            _ => return None,
+        };
+
+        if content_index >= message.content.len() {
+            message.content.push(create());
+        }
+
+        message.content.get_mut(content_index)
+    }
+
+    fn summary_mut(&mut self, output_index: usize, summary_index: usize) -> Option<&mut Summary> {
+        let reasoning = match self.output.get_mut(output_index) {
+            Some(ResponseOutput::Reasoning(reasoning)) => reasoning,
+            _ => return None,
+        };
+
+        if summary_index >= reasoning.summary.len() {
+            reasoning.summary.push(Summary::new(""));
+        }
+
+        reasoning.summary.get_mut(summary_index)
+    }
+}
+
+fn output_text() -> OutputContent {
+    OutputContent::OutputText(OutputText::new(""))
+}
+
+fn refusal() -> OutputContent {
+    OutputContent::Refusal(Refusal::new(""))
+}
+
+/// Consumes an event stream and returns the response it describes.
+pub async fn collect_response<S>(events: S) -> Result<OpenAIResponse, ProviderError>
+where
+    S: Stream<Item = Result<OpenAIStreamingEvent, ProviderError>>,
+{
+    let mut events = Box::pin(events);
+    let mut accumulator = ResponseAccumulator::new();
+
+    while let Some(event) = events.next().await {
+        match event? {
+            OpenAIStreamingEvent::Error { code, message, .. } => {
+                return Err(ProviderError::Other(match code {
+                    Some(code) => format!("{}: {}", code, message),
+                    None => message,
+                }))
+            }
+            event => accumulator.push(event),
+        }
+
+        if accumulator.is_done() {
+            break;
+        }
+    }
+
+    accumulator.finish().ok_or_else(|| {
+        ProviderError::InternalError("the stream ended before the response finished".to_string())
+    })
+}
+
+impl From<StreamingResponse> for OpenAIResponse {
+    fn from(response: StreamingResponse) -> Self {
+        Self {
+            background: response.background,
+            created_at: response.created_at,
+            error: response.error,
+            id: response.id,
+            incomplete_details: response.incomplete_details,
+            instructions: response.instructions,
+            max_output_tokens: response.max_output_tokens.map(|value| value as usize),
+            metadata: Some(response.metadata),
+            model: response.model,
+            object: response.object,
+            output: response.output,
+            parallel_tool_calls: response.parallel_tool_calls,
+            previous_response_id: response.previous_response_id,
+            reasoning: response.reasoning,
+            service_tier: response.service_tier,
+            status: response.status,
+            temperature: response.temperature,
+            text: response.text,
+            tool_choice: response.tool_choice,
+            tools: response.tools,
+            top_p: response.top_p,
+            truncation: response.truncation,
+            usage: response.usage.unwrap_or_default(),
+            user: response.user,
+        }
+    }
+}
@@ -184,6 +184,8 @@ pub enum ReasoningPartType {
 #[serde(bound(deserialize = ""))]
 #[derive(Deserialize)]
 pub struct StreamingResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub background: Option<bool>,
     pub created_at: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<ResponseError>,
@@ -215,6 +217,8 @@ pub struct StreamingResponse {
     pub text: Text,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub truncation: Option<Truncation>,
-    pub usage: Usage,
-    pub user: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<Usage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
 }
@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct IncompleteDetails {
-    reason: String,
+    pub reason: String,
 }
 
 impl IncompleteDetails {
@@ -2,8 +2,8 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct ResponseError {
-    code: String,
-    message: String,
+    pub code: String,
+    pub message: String,
 }
 
 impl ResponseError {
@@ -0,0 +1,226 @@
+use crate::openai::constants::OpenAIModelId;
+use crate::utils::errors::ProviderError;
+use bytes::Bytes;
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+use tokio_stream::Stream;
+
+pub type AudioStream = Pin<Box<dyn Stream<Item = Result<Bytes, ProviderError>> + Send>>;
+
+/// The longest input `/audio/speech` accepts, in characters.
+pub const MAX_SPEECH_INPUT_LENGTH: usize = 4096;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Voice {
+    Alloy,
+    Ash,
+    Ballad,
+    Coral,
+    Echo,
+    Fable,
+    Onyx,
+    Nova,
+    Sage,
+    Shimmer,
+    Verse,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpeechFormat {
+    #[default]
+    Mp3,
+    Opus,
+    Aac,
+    Flac,
+    Wav,
+    /// Raw 24kHz, 16-bit signed little-endian samples without a header.
+    Pcm,
+}
+
+impl SpeechFormat {
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            SpeechFormat::Mp3 => "audio/mpeg",
+            SpeechFormat::Opus => "audio/opus",
+            SpeechFormat::Aac => "audio/aac",
+            SpeechFormat::Flac => "audio/flac",
+            SpeechFormat::Wav => "audio/wav",
+            SpeechFormat::Pcm => "audio/pcm",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SpeechRequest {
+    model: OpenAIModelId,
+    input: String,
+    voice: Voice,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instructions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<SpeechFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    speed: Option<f32>,
+}
+
+impl SpeechRequest {
+    pub fn new(model: OpenAIModelId, input: impl Into<String>, voice: Voice) -> Self {
+        Self {
+            model,
+            input: input.into(),
+            voice,
+            instructions: None,
+            response_format: None,
+            speed: None,
+        }
+    }
+
+    /// Steers tone, accent or pacing. Not supported by `tts-1` and `tts-1-hd`.
+    pub fn instructions(mut self, value: impl Into<String>) -> Self {
+        self.instructions = Some(value.into());
+        self
+    }
+
+    pub fn response_format(mut self, value: SpeechFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    /// Playback speed from 0.25 to 4.0.
+    pub fn speed(mut self, value: f32) -> Self {
+        self.speed = Some(value);
+        self
+    }
+
+    pub fn format(&self) -> SpeechFormat {
+        self.response_format.unwrap_or_default()
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        if self.input.is_empty() {
+            return Err(ProviderError::ValidationError(
+                "input must not be empty".to_string(),
+            ));
+        }
+
+        let length = self.input.chars().count();
+        if length > MAX_SPEECH_INPUT_LENGTH {
+            return Err(ProviderError::ValidationError(format!(
+                "input must be at most {} characters, got {}",
+                MAX_SPEECH_INPUT_LENGTH, length
+            )));
+        }
+
+        if let Some(speed) = self.speed {
+            if !(0.25..=4.0).contains(&speed) {
+                return Err(ProviderError::ValidationError(format!(
+                    "speed must be between 0.25 and 4.0, got {}",
+                    speed
+                )));
+            }
+        }
+
+        let legacy = matches!(
+            self.model,
+            OpenAIModelId::Tts1 | OpenAIModelId::Tts1Hd | OpenAIModelId::Tts1Hd1106
+        );
+        if legacy && self.instructions.is_some() {
+            return Err(ProviderError::CapabilityError(format!(
+                "{} does not support instructions",
+                self.model
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies an audio stream into `writer` as it arrives and returns the number of
+/// bytes written.
+pub async fn write_audio<W>(mut stream: AudioStream, writer: &mut W) -> Result<u64, ProviderError>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut written = 0;
+
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+
+        writer
+            .write_all(&chunk)
+            .await
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+        written += chunk.len() as u64;
+    }
+
+    writer
+        .flush()
+        .await
+        .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn it_serializes_speech_requests() {
+        let request = SpeechRequest::new(OpenAIModelId::Gpt4OMiniTts, "Hello", Voice::Coral)
+            .instructions("Speak cheerfully.")
+            .response_format(SpeechFormat::Opus)
+            .speed(1.25);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "gpt-4o-mini-tts",
+                "input": "Hello",
+                "voice": "coral",
+                "instructions": "Speak cheerfully.",
+                "response_format": "opus",
+                "speed": 1.25
+            })
+        );
+        assert_eq!(request.format().mime_type(), "audio/opus");
+    }
+
+    #[test]
+    fn it_validates_speech_requests() {
+        let request = SpeechRequest::new(OpenAIModelId::Tts1Hd, "Hello", Voice::Alloy);
+        assert!(request.validate().is_ok());
+
+        assert!(matches!(
+            request.clone().speed(4.5).validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            SpeechRequest::new(OpenAIModelId::Tts1, "a".repeat(4097), Voice::Alloy).validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            request.instructions("Whisper.").validate(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn it_writes_audio_streams() {
+        let stream: AudioStream = Box::pin(futures::stream::iter(vec![
+            Ok(Bytes::from_static(b"ID3")),
+            Ok(Bytes::from_static(b"data")),
+        ]));
+        let mut output = vec![];
+
+        let written = write_audio(stream, &mut output).await.unwrap();
+
+        assert_eq!(written, 7);
+        assert_eq!(output, b"ID3data");
+    }
+}
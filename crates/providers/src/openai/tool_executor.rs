@@ -0,0 +1,257 @@
+use crate::openai::common::function_tool_call_item::FunctionToolCallItem;
+use crate::openai::request::input::{Input, InputItemList};
+use crate::openai::request::input_models::item::{FunctionToolCallOutputItem, Item};
+use crate::openai::response::response_output::ResponseOutput;
+use crate::openai::types::{OpenAIRequest, OpenAIResponse};
+use crate::utils::errors::ProviderError;
+use crate::utils::provider_strategy::ProviderStrategy;
+use async_trait::async_trait;
+use futures::future::join_all;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::Arc;
+
+/// Runs a single function tool.
+///
+/// Any `Fn(Value) -> impl Future<Output = Result<Value, ProviderError>>` closure is a
+/// handler, so most tools never need to implement this trait by hand.
+#[async_trait]
+pub trait ToolHandler: Send + Sync {
+    async fn call(&self, arguments: Value) -> Result<Value, ProviderError>;
+}
+
+#[async_trait]
+impl<F, Fut> ToolHandler for F
+where
+    F: Fn(Value) -> Fut + Send + Sync,
+    Fut: Future<Output = Result<Value, ProviderError>> + Send,
+{
+    async fn call(&self, arguments: Value) -> Result<Value, ProviderError> {
+        self(arguments).await
+    }
+}
+
+/// How tool outputs are sent back to the model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Continuation {
+    /// Resend the whole conversation with the response output, including reasoning
+    /// items and messages, and the call outputs appended.
+    #[default]
+    AppendInput,
+    /// Send only the outputs and chain onto the previous response with
+    /// `previous_response_id`. Requires the responses to be stored.
+    PreviousResponseId,
+}
+
+/// One round trip of a tool loop: the response and the tool calls it asked for,
+/// along with the outputs produced for them.
+#[derive(Debug)]
+pub struct ToolStep {
+    pub response: OpenAIResponse,
+    pub calls: Vec<FunctionToolCallItem>,
+    pub outputs: Vec<FunctionToolCallOutputItem>,
+}
+
+#[derive(Debug)]
+pub struct ToolRun {
+    pub steps: Vec<ToolStep>,
+}
+
+impl ToolRun {
+    pub fn final_response(&self) -> Option<&OpenAIResponse> {
+        self.steps.last().map(|step| &step.response)
+    }
+
+    /// Whether the loop stopped because of `max_steps` while calls were still pending.
+    pub fn is_truncated(&self) -> bool {
+        self.steps.last().is_some_and(|step| !step.calls.is_empty())
+    }
+}
+
+/// Drives the call → execute → resubmit cycle for function tools.
+///
+/// Handlers are looked up by function name. A failing handler or an unknown tool
+/// does not abort the loop; the error is reported to the model as the call output
+/// so it can recover.
+#[derive(Clone)]
+pub struct ToolExecutor {
+    handlers: HashMap<String, Arc<dyn ToolHandler>>,
+    max_steps: usize,
+    continuation: Continuation,
+}
+
+impl Default for ToolExecutor {
+    fn default() -> Self {
+        Self {
+            handlers: HashMap::new(),
+            max_steps: 10,
+            continuation: Continuation::default(),
+        }
+    }
+}
+
+impl fmt::Debug for ToolExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut tools: Vec<&String> = self.handlers.keys().collect();
+        tools.sort();
+
+        f.debug_struct("ToolExecutor")
+            .field("tools", &tools)
+            .field("max_steps", &self.max_steps)
+            .field("continuation", &self.continuation)
+            .finish()
+    }
+}
+
+impl ToolExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(
+        mut self,
+        name: impl Into<String>,
+        handler: impl ToolHandler + 'static,
+    ) -> Self {
+        self.handlers.insert(name.into(), Arc::new(handler));
+        self
+    }
+
+    pub fn max_steps(mut self, value: usize) -> Self {
+        self.max_steps = value.max(1);
+        self
+    }
+
+    pub fn continuation(mut self, value: Continuation) -> Self {
+        self.continuation = value;
+        self
+    }
+
+    /// Sends `request` and keeps executing the requested tools until the model stops
+    /// calling them or `max_steps` responses have been received.
+    pub async fn run<P>(
+        &self,
+        provider: &P,
+        request: OpenAIRequest,
+    ) -> Result<ToolRun, ProviderError>
+    where
+        P: ProviderStrategy<GenerationRequest = OpenAIRequest, GenerationResponse = OpenAIResponse>
+            + Sync,
+    {
+        let mut request = request;
+        let mut steps = vec![];
+
+        while steps.len() < self.max_steps {
+            let response = provider.generate(&request).await?;
+
+            let calls: Vec<FunctionToolCallItem> = response
+                .output
+                .iter()
+                .filter_map(|item| match item {
+                    ResponseOutput::FunctionToolCall(call) => Some(call.clone()),
+                    _ => None,
+                })
+                .collect();
+
+            if calls.is_empty() {
+                steps.push(ToolStep {
+                    response,
+                    calls,
+                    outputs: vec![],
+                });
+                break;
+            }
+
+            let outputs = if response.parallel_tool_calls {
+                join_all(calls.iter().map(|call| self.execute(call))).await
+            } else {
+                let mut outputs = vec![];
+                for call in &calls {
+                    outputs.push(self.execute(call).await);
+                }
+                outputs
+            };
+
+            let output_items = outputs
+                .iter()
+                .map(|output| InputItemList::Item(Item::FunctionToolCallOutput(output.clone())));
+
+            request = match self.continuation {
+                // reasoning items and messages have to be sent back along with the
+                // calls, or reasoning models reject the follow-up request
+                Continuation::AppendInput => request.append_input(
+                    response
+                        .output
+                        .iter()
+                        .map(|item| InputItemList::Item(Item::from(item.clone())))
+                        .chain(output_items)
+                        .collect(),
+                ),
+                Continuation::PreviousResponseId => request
+                    .input(Input::Messages(output_items.collect()))
+                    .previous_response_id(response.id.clone()),
+            };
+
+            steps.push(ToolStep {
+                response,
+                calls,
+                outputs,
+            });
+        }
+
+        Ok(ToolRun { steps })
+    }
+
+    async fn execute(&self, call: &FunctionToolCallItem) -> FunctionToolCallOutputItem {
+        let result = match self.handlers.get(&call.name) {
+            Some(handler) => match serde_json::from_str(&call.arguments) {
+                Ok(arguments) => handler.call(arguments).await,
+                Err(e) => Err(ProviderError::DeserializationError(e.to_string())),
+            },
+            None => Err(ProviderError::NotSupported(format!(
+                "no handler registered for tool `{}`",
+                call.name
+            ))),
+        };
+
+        let output = match result {
+            Ok(Value::String(output)) => output,
+            Ok(output) => output.to_string(),
+            Err(e) => json!({ "error": e.to_string() }).to_string(),
+        };
+
+        FunctionToolCallOutputItem::new(call.call_id.clone(), output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn it_reports_handler_failures_as_outputs() {
+        let executor = ToolExecutor::new()
+            .register("echo", |arguments: Value| async move { Ok(arguments) })
+            .register("fail", |_: Value| async {
+                Err(ProviderError::Other("boom".to_string()))
+            });
+
+        let output = executor
+            .execute(&FunctionToolCallItem::new(r#"{"a":1}"#, "call_1", "echo"))
+            .await;
+        assert_eq!(output.call_id, "call_1");
+        assert_eq!(output.output, r#"{"a":1}"#);
+
+        let output = executor
+            .execute(&FunctionToolCallItem::new("{}", "call_2", "fail"))
+            .await;
+        assert!(output.output.contains("boom"));
+
+        let output = executor
+            .execute(&FunctionToolCallItem::new("{}", "call_3", "missing"))
+            .await;
+        assert!(output.output.contains("missing"));
+    }
+}
@@ -0,0 +1,538 @@
+use crate::openai::constants::OpenAIModelId;
+use crate::openai::upload::FileUpload;
+use crate::utils::errors::ProviderError;
+use reqwest::multipart::Form;
+use serde::{Deserialize, Serialize};
+use std::pin::Pin;
+use tokio_stream::Stream;
+
+pub type TranscriptionEventStream =
+    Pin<Box<dyn Stream<Item = Result<TranscriptionStreamEvent, ProviderError>> + Send>>;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TranscriptionFormat {
+    #[default]
+    Json,
+    Text,
+    Srt,
+    VerboseJson,
+    Vtt,
+}
+
+impl TranscriptionFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TranscriptionFormat::Json => "json",
+            TranscriptionFormat::Text => "text",
+            TranscriptionFormat::Srt => "srt",
+            TranscriptionFormat::VerboseJson => "verbose_json",
+            TranscriptionFormat::Vtt => "vtt",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimestampGranularity {
+    Word,
+    Segment,
+}
+
+impl TimestampGranularity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimestampGranularity::Word => "word",
+            TimestampGranularity::Segment => "segment",
+        }
+    }
+}
+
+fn is_gpt4o_transcribe(model: &OpenAIModelId) -> bool {
+    matches!(
+        model,
+        OpenAIModelId::Gpt4OTranscribe | OpenAIModelId::Gpt4OMiniTranscribe
+    )
+}
+
+fn validate_common(
+    model: &OpenAIModelId,
+    format: TranscriptionFormat,
+    temperature: Option<f32>,
+) -> Result<(), ProviderError> {
+    if let Some(temperature) = temperature {
+        if !(0.0..=1.0).contains(&temperature) {
+            return Err(ProviderError::ValidationError(format!(
+                "temperature must be between 0 and 1, got {}",
+                temperature
+            )));
+        }
+    }
+
+    let json_or_text = matches!(
+        format,
+        TranscriptionFormat::Json | TranscriptionFormat::Text
+    );
+    if is_gpt4o_transcribe(model) && !json_or_text {
+        return Err(ProviderError::CapabilityError(format!(
+            "{} only supports the json and text response formats",
+            model
+        )));
+    }
+
+    Ok(())
+}
+
+fn add_fields(form: Form, fields: Vec<(&'static str, Option<String>)>) -> Form {
+    fields
+        .into_iter()
+        .fold(form, |form, (name, value)| match value {
+            Some(value) => form.text(name, value),
+            None => form,
+        })
+}
+
+/// Transcribes audio into text in its original language. Sent as
+/// `multipart/form-data`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TranscriptionRequest {
+    file: FileUpload,
+    model: OpenAIModelId,
+    language: Option<String>,
+    prompt: Option<String>,
+    response_format: Option<TranscriptionFormat>,
+    temperature: Option<f32>,
+    timestamp_granularities: Vec<TimestampGranularity>,
+    logprobs: bool,
+}
+
+impl TranscriptionRequest {
+    pub fn new(model: OpenAIModelId, file: FileUpload) -> Self {
+        Self {
+            file,
+            model,
+            language: None,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+            timestamp_granularities: vec![],
+            logprobs: false,
+        }
+    }
+
+    /// ISO-639-1 code of the spoken language, e.g. `en`.
+    pub fn language(mut self, value: impl Into<String>) -> Self {
+        self.language = Some(value.into());
+        self
+    }
+
+    pub fn prompt(mut self, value: impl Into<String>) -> Self {
+        self.prompt = Some(value.into());
+        self
+    }
+
+    pub fn response_format(mut self, value: TranscriptionFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    /// Requires [`TranscriptionFormat::VerboseJson`].
+    pub fn add_timestamp_granularity(mut self, value: TimestampGranularity) -> Self {
+        if !self.timestamp_granularities.contains(&value) {
+            self.timestamp_granularities.push(value);
+        }
+        self
+    }
+
+    /// Asks for token log probabilities. Only the gpt-4o transcribe models with the
+    /// `json` format return them.
+    pub fn logprobs(mut self, value: bool) -> Self {
+        self.logprobs = value;
+        self
+    }
+
+    pub fn format(&self) -> TranscriptionFormat {
+        self.response_format.unwrap_or_default()
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        validate_common(&self.model, self.format(), self.temperature)?;
+
+        if !self.timestamp_granularities.is_empty()
+            && self.format() != TranscriptionFormat::VerboseJson
+        {
+            return Err(ProviderError::ValidationError(
+                "timestamp_granularities requires the verbose_json response format".to_string(),
+            ));
+        }
+
+        if self.logprobs
+            && (!is_gpt4o_transcribe(&self.model) || self.format() != TranscriptionFormat::Json)
+        {
+            return Err(ProviderError::CapabilityError(format!(
+                "{} does not return logprobs with the {} format",
+                self.model,
+                self.format().as_str()
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn validate_stream(&self) -> Result<(), ProviderError> {
+        self.validate()?;
+
+        if !is_gpt4o_transcribe(&self.model) {
+            return Err(ProviderError::CapabilityError(format!(
+                "{} does not support streamed transcriptions",
+                self.model
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn to_form(&self, stream: bool) -> Result<Form, ProviderError> {
+        let mut form = Form::new()
+            .part("file", self.file.to_part()?)
+            .text("model", self.model.to_string());
+
+        for granularity in &self.timestamp_granularities {
+            form = form.text("timestamp_granularities[]", granularity.as_str());
+        }
+
+        if self.logprobs {
+            form = form.text("include[]", "logprobs");
+        }
+
+        Ok(add_fields(
+            form,
+            vec![
+                ("language", self.language.clone()),
+                ("prompt", self.prompt.clone()),
+                (
+                    "response_format",
+                    self.response_format.map(|f| f.as_str().to_string()),
+                ),
+                ("temperature", self.temperature.map(|t| t.to_string())),
+                ("stream", stream.then(|| "true".to_string())),
+            ],
+        ))
+    }
+}
+
+/// Translates audio into English text. Sent as `multipart/form-data`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TranslationRequest {
+    file: FileUpload,
+    model: OpenAIModelId,
+    prompt: Option<String>,
+    response_format: Option<TranscriptionFormat>,
+    temperature: Option<f32>,
+}
+
+impl TranslationRequest {
+    pub fn new(model: OpenAIModelId, file: FileUpload) -> Self {
+        Self {
+            file,
+            model,
+            prompt: None,
+            response_format: None,
+            temperature: None,
+        }
+    }
+
+    pub fn prompt(mut self, value: impl Into<String>) -> Self {
+        self.prompt = Some(value.into());
+        self
+    }
+
+    pub fn response_format(mut self, value: TranscriptionFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn format(&self) -> TranscriptionFormat {
+        self.response_format.unwrap_or_default()
+    }
+
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        validate_common(&self.model, self.format(), self.temperature)
+    }
+
+    pub(crate) fn to_form(&self) -> Result<Form, ProviderError> {
+        let form = Form::new()
+            .part("file", self.file.to_part()?)
+            .text("model", self.model.to_string());
+
+        Ok(add_fields(
+            form,
+            vec![
+                ("prompt", self.prompt.clone()),
+                (
+                    "response_format",
+                    self.response_format.map(|f| f.as_str().to_string()),
+                ),
+                ("temperature", self.temperature.map(|t| t.to_string())),
+            ],
+        ))
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionLogprob {
+    pub token: String,
+    pub logprob: f64,
+    #[serde(default)]
+    pub bytes: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionInputTokenDetails {
+    #[serde(default)]
+    pub text_tokens: usize,
+    #[serde(default)]
+    pub audio_tokens: usize,
+}
+
+/// Billing usage. Token-based for the gpt-4o transcribe models, duration-based for
+/// `whisper-1`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum TranscriptionUsage {
+    Tokens {
+        input_tokens: usize,
+        output_tokens: usize,
+        total_tokens: usize,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        input_token_details: Option<TranscriptionInputTokenDetails>,
+    },
+    Duration {
+        seconds: f64,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Transcription {
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub logprobs: Option<Vec<TranscriptionLogprob>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TranscriptionUsage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionWord {
+    pub word: String,
+    pub start: f64,
+    pub end: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TranscriptionSegment {
+    pub id: usize,
+    pub seek: usize,
+    pub start: f64,
+    pub end: f64,
+    pub text: String,
+    #[serde(default)]
+    pub tokens: Vec<u32>,
+    pub temperature: f64,
+    pub avg_logprob: f64,
+    pub compression_ratio: f64,
+    pub no_speech_prob: f64,
+}
+
+/// The `verbose_json` format, with timestamps for the requested granularities.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct VerboseTranscription {
+    #[serde(default)]
+    pub task: String,
+    pub language: String,
+    pub duration: f64,
+    pub text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<TranscriptionWord>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub segments: Option<Vec<TranscriptionSegment>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<TranscriptionUsage>,
+}
+
+/// A transcription or translation, shaped by the requested response format.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TranscriptionResponse {
+    Json(Transcription),
+    Verbose(VerboseTranscription),
+    /// The raw body of the `text`, `srt` and `vtt` formats.
+    Text(String),
+}
+
+impl TranscriptionResponse {
+    pub(crate) fn parse(format: TranscriptionFormat, body: &[u8]) -> Result<Self, ProviderError> {
+        let parse_error = |e: serde_json::Error| ProviderError::DeserializationError(e.to_string());
+
+        match format {
+            TranscriptionFormat::Json => serde_json::from_slice(body)
+                .map(TranscriptionResponse::Json)
+                .map_err(parse_error),
+            TranscriptionFormat::VerboseJson => serde_json::from_slice(body)
+                .map(TranscriptionResponse::Verbose)
+                .map_err(parse_error),
+            TranscriptionFormat::Text | TranscriptionFormat::Srt | TranscriptionFormat::Vtt => Ok(
+                TranscriptionResponse::Text(String::from_utf8_lossy(body).into_owned()),
+            ),
+        }
+    }
+
+    /// The transcribed text, or the subtitle file for `srt` and `vtt`.
+    pub fn text(&self) -> &str {
+        match self {
+            TranscriptionResponse::Json(transcription) => &transcription.text,
+            TranscriptionResponse::Verbose(transcription) => &transcription.text,
+            TranscriptionResponse::Text(text) => text,
+        }
+    }
+
+    pub fn usage(&self) -> Option<&TranscriptionUsage> {
+        match self {
+            TranscriptionResponse::Json(transcription) => transcription.usage.as_ref(),
+            TranscriptionResponse::Verbose(transcription) => transcription.usage.as_ref(),
+            TranscriptionResponse::Text(_) => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum TranscriptionStreamEvent {
+    #[serde(rename = "transcript.text.delta")]
+    Delta {
+        delta: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        logprobs: Option<Vec<TranscriptionLogprob>>,
+    },
+    #[serde(rename = "transcript.text.done")]
+    Done {
+        text: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        logprobs: Option<Vec<TranscriptionLogprob>>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        usage: Option<TranscriptionUsage>,
+    },
+    /// An event type added to the API after this crate was released.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn audio() -> FileUpload {
+        FileUpload::new("speech.mp3", b"ID3".to_vec())
+    }
+
+    #[test]
+    fn it_validates_transcription_requests() {
+        let whisper = TranscriptionRequest::new(OpenAIModelId::Whisper1, audio());
+        assert!(whisper
+            .clone()
+            .response_format(TranscriptionFormat::VerboseJson)
+            .add_timestamp_granularity(TimestampGranularity::Word)
+            .validate()
+            .is_ok());
+        assert!(matches!(
+            whisper
+                .clone()
+                .add_timestamp_granularity(TimestampGranularity::Word)
+                .validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            whisper.clone().temperature(1.5).validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+        assert!(matches!(
+            whisper.logprobs(true).validate(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+
+        let gpt4o = TranscriptionRequest::new(OpenAIModelId::Gpt4OMiniTranscribe, audio());
+        assert!(gpt4o.clone().logprobs(true).validate_stream().is_ok());
+        assert!(matches!(
+            gpt4o.response_format(TranscriptionFormat::Srt).validate(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+        assert!(matches!(
+            TranscriptionRequest::new(OpenAIModelId::Whisper1, audio()).validate_stream(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+    }
+
+    #[test]
+    fn it_parses_responses_by_format() {
+        let json = TranscriptionResponse::parse(
+            TranscriptionFormat::Json,
+            br#"{"text":"Hello","usage":{"type":"tokens","input_tokens":14,"output_tokens":45,"total_tokens":59,"input_token_details":{"text_tokens":0,"audio_tokens":14}}}"#,
+        )
+        .unwrap();
+        assert_eq!(json.text(), "Hello");
+        assert!(matches!(
+            json.usage(),
+            Some(TranscriptionUsage::Tokens {
+                total_tokens: 59,
+                ..
+            })
+        ));
+
+        let srt = TranscriptionResponse::parse(
+            TranscriptionFormat::Srt,
+            b"1\n00:00:00,000 --> 00:00:01,000\nHello\n",
+        )
+        .unwrap();
+        assert!(srt.text().contains("--> 00:00:01,000"));
+        assert_eq!(srt.usage(), None);
+    }
+
+    #[test]
+    fn it_deserializes_stream_events() {
+        let event: TranscriptionStreamEvent = serde_json::from_value(json!({
+            "type": "transcript.text.done",
+            "text": "Hello there",
+            "usage": { "type": "duration", "seconds": 3.0 }
+        }))
+        .unwrap();
+
+        assert_eq!(
+            event,
+            TranscriptionStreamEvent::Done {
+                text: "Hello there".to_string(),
+                logprobs: None,
+                usage: Some(TranscriptionUsage::Duration { seconds: 3.0 }),
+            }
+        );
+    }
+
+    #[test]
+    fn it_tolerates_unknown_stream_events() {
+        let event: TranscriptionStreamEvent = serde_json::from_value(json!({
+            "type": "transcript.text.segment",
+            "text": "Hello"
+        }))
+        .unwrap();
+
+        assert_eq!(event, TranscriptionStreamEvent::Unknown);
+    }
+}
@@ -1,17 +1,27 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+use crate::openai::capabilities::ModelCapabilities;
 use crate::openai::common::{
     reasoning::Reasoning, service_tier::ServiceTier, status::Status, text::Text, tool::Tool,
     tool_choice::ToolChoice, truncation::Truncation,
 };
 use crate::openai::constants::OpenAIModelId;
-use crate::openai::request::{include::Include, input::Input};
+use crate::openai::request::{
+    include::Include,
+    input::{Input, InputItemList},
+    input_models::{
+        common::Content,
+        input_message::{InputMessage, TextInput},
+        item::Item,
+    },
+};
 use crate::openai::response::{
     incomplete_details::IncompleteDetails, response_error::ResponseError,
     response_output::ResponseOutput, usage::Usage,
 };
 
+use crate::utils::errors::ProviderError;
 use serde_json::{json, Value};
 
 #[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
@@ -19,6 +29,8 @@ pub struct OpenAIRequest {
     input: Input,
     model: OpenAIModelId,
     #[serde(skip_serializing_if = "Option::is_none")]
+    background: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     include: Option<Vec<Include>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     instructions: Option<String>,
@@ -61,6 +73,32 @@ impl OpenAIRequest {
         }
     }
 
+    pub fn input(mut self, value: Input) -> Self {
+        self.input = value;
+        self
+    }
+
+    /// Appends items to the input, turning a plain text input into a user message first.
+    pub fn append_input(mut self, items: Vec<InputItemList>) -> Self {
+        let mut input = match std::mem::take(&mut self.input) {
+            Input::Messages(input) => input,
+            Input::Message(text) => vec![InputItemList::InputMessage(
+                TextInput::new(text).insert_type().into(),
+            )],
+        };
+        input.extend(items);
+
+        self.input = Input::Messages(input);
+        self
+    }
+
+    /// Runs the response asynchronously. The request returns right away with a
+    /// queued response, which can be polled or streamed until it finishes.
+    pub fn background(mut self, value: bool) -> Self {
+        self.background = Some(value);
+        self
+    }
+
     pub fn include(mut self, value: Include) -> Self {
         match self.include {
             Some(ref mut include) => include.push(value),
@@ -160,6 +198,134 @@ impl OpenAIRequest {
         self
     }
 
+    /// Checks the request locally against the capabilities of its model, as listed in
+    /// the built-in model registry. Models missing from the registry only get the
+    /// checks that do not depend on the model.
+    pub fn validate(&self) -> Result<(), ProviderError> {
+        self.validate_with(self.model.capabilities())
+    }
+
+    /// Like [`OpenAIRequest::validate`], with the model capabilities supplied by the
+    /// caller, e.g. from a custom registry. `None` skips the capability checks.
+    pub fn validate_with(
+        &self,
+        capabilities: Option<&ModelCapabilities>,
+    ) -> Result<(), ProviderError> {
+        if let Some(temperature) = self.temperature {
+            if !(0.0..=2.0).contains(&temperature) {
+                return Err(ProviderError::ValidationError(format!(
+                    "temperature must be between 0 and 2, got {}",
+                    temperature
+                )));
+            }
+        }
+
+        if let Some(top_p) = self.top_p {
+            if !(0.0..=1.0).contains(&top_p) {
+                return Err(ProviderError::ValidationError(format!(
+                    "top_p must be between 0 and 1, got {}",
+                    top_p
+                )));
+            }
+        }
+
+        if self.background == Some(true) && self.store == Some(false) {
+            return Err(ProviderError::ValidationError(
+                "background responses must be stored".to_string(),
+            ));
+        }
+
+        let tools = self.tools.as_deref().unwrap_or_default();
+
+        for tool in tools {
+            tool.validate()?;
+        }
+
+        let uses_computer = tools
+            .iter()
+            .any(|tool| matches!(tool, Tool::ComputerUse(_)));
+        if uses_computer && self.truncation != Some(Truncation::Auto) {
+            return Err(ProviderError::ValidationError(
+                "the computer use tool requires truncation to be auto".to_string(),
+            ));
+        }
+
+        if let Some(ToolChoice::FunctionTool(choice)) = &self.tool_choice {
+            if !tools
+                .iter()
+                .any(|tool| tool.function_name() == Some(choice.name()))
+            {
+                return Err(ProviderError::ValidationError(format!(
+                    "tool_choice names the function {}, which is not in tools",
+                    choice.name()
+                )));
+            }
+        }
+
+        let Some(capabilities) = capabilities else {
+            return Ok(());
+        };
+        let unsupported = |feature: &str| {
+            Err(ProviderError::CapabilityError(format!(
+                "{} does not support {}",
+                self.model, feature
+            )))
+        };
+
+        if self.reasoning.is_some() && !capabilities.reasoning {
+            return unsupported("reasoning");
+        }
+
+        if capabilities.reasoning && self.temperature.is_some() {
+            return unsupported("temperature");
+        }
+
+        if capabilities.reasoning && self.top_p.is_some() {
+            return unsupported("top_p");
+        }
+
+        if !tools.is_empty() && !capabilities.tools {
+            return unsupported("tools");
+        }
+
+        if self.has_image_input() && !capabilities.supports_vision() {
+            return unsupported("image inputs");
+        }
+
+        if let Some(max_output_tokens) = self.max_output_tokens {
+            if max_output_tokens > capabilities.max_output_tokens {
+                return Err(ProviderError::CapabilityError(format!(
+                    "{} allows at most {} output tokens, got {}",
+                    self.model, capabilities.max_output_tokens, max_output_tokens
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn get_input(&self) -> &Input {
+        &self.input
+    }
+
+    fn has_image_input(&self) -> bool {
+        let Input::Messages(items) = &self.input else {
+            return false;
+        };
+
+        items
+            .iter()
+            .filter_map(|item| match item {
+                InputItemList::InputMessage(InputMessage::InputItemContentList(message)) => {
+                    Some(&message.content)
+                }
+                InputItemList::Item(Item::InputMessage(message)) => Some(&message.content),
+                _ => None,
+            })
+            .flatten()
+            .any(|content| matches!(content, Content::Image(_)))
+    }
+
     pub fn wrap_for_streaming(&self) -> impl Serialize + '_ {
         struct Wrapper<'a> {
             inner: &'a OpenAIRequest,
@@ -191,39 +357,151 @@ impl OpenAIRequest {
 #[serde(bound(deserialize = ""))]
 #[derive(Deserialize)]
 pub struct OpenAIResponse {
-    created_at: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
-    error: Option<ResponseError>,
-    id: String,
+    pub background: Option<bool>,
+    pub created_at: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
-    incomplete_details: Option<IncompleteDetails>,
+    pub error: Option<ResponseError>,
+    pub id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    instructions: Option<String>,
+    pub incomplete_details: Option<IncompleteDetails>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    max_output_tokens: Option<usize>,
+    pub instructions: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    metadata: Option<HashMap<String, String>>,
-    model: String,
-    object: String,
-    output: Vec<ResponseOutput>,
-    parallel_tool_calls: bool,
+    pub max_output_tokens: Option<usize>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    previous_response_id: Option<String>,
+    pub metadata: Option<HashMap<String, String>>,
+    pub model: String,
+    pub object: String,
+    pub output: Vec<ResponseOutput>,
+    pub parallel_tool_calls: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
-    reasoning: Option<Reasoning>,
+    pub previous_response_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    service_tier: Option<ServiceTier>,
-    status: Status,
+    pub reasoning: Option<Reasoning>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    temperature: Option<f32>,
-    text: Text,
-    tool_choice: ToolChoice,
-    tools: Vec<Tool>,
+    pub service_tier: Option<ServiceTier>,
+    pub status: Status,
     #[serde(skip_serializing_if = "Option::is_none")]
-    top_p: Option<f32>,
+    pub temperature: Option<f32>,
+    pub text: Text,
+    pub tool_choice: ToolChoice,
+    pub tools: Vec<Tool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    truncation: Option<Truncation>,
-    usage: Usage,
+    pub top_p: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    user: Option<String>,
+    pub truncation: Option<Truncation>,
+    pub usage: Usage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::openai::common::{
+        reasoning::Reasoning,
+        tool::{ComputerUseTool, FileSearchTool, FunctionTool},
+        tool_choice::FunctionToolChoice,
+    };
+    use crate::openai::request::input_models::common::{ImageContent, TextContent};
+    use crate::openai::request::input_models::input_message::InputItemContentList;
+
+    fn request(model: OpenAIModelId) -> OpenAIRequest {
+        OpenAIRequest::new(model, Input::from_text("Hello"))
+    }
+
+    fn assert_validation_error(request: OpenAIRequest) {
+        assert!(matches!(
+            request.validate(),
+            Err(ProviderError::ValidationError(_))
+        ));
+    }
+
+    fn assert_capability_error(request: OpenAIRequest) {
+        assert!(matches!(
+            request.validate(),
+            Err(ProviderError::CapabilityError(_))
+        ));
+    }
+
+    #[test]
+    fn it_accepts_valid_requests() {
+        let valid = request(OpenAIModelId::Gpt4OMini)
+            .temperature(0.7)
+            .top_p(1.0)
+            .add_tool(FunctionTool::new("get_weather", json!({})).into())
+            .tool_choice(FunctionToolChoice::new("get_weather").into());
+
+        assert!(valid.validate().is_ok());
+        assert!(request(OpenAIModelId::custom("gpt-5"))
+            .reasoning(Reasoning::new().effort("high"))
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn it_rejects_out_of_range_parameters() {
+        assert_validation_error(request(OpenAIModelId::Gpt4OMini).temperature(2.5));
+        assert_validation_error(request(OpenAIModelId::Gpt4OMini).top_p(-0.1));
+        assert_validation_error(
+            request(OpenAIModelId::Gpt4OMini)
+                .background(true)
+                .store(false),
+        );
+        assert_validation_error(
+            request(OpenAIModelId::Gpt4OMini)
+                .add_tool(FileSearchTool::new(vec!["vs_1"]).max_num_results(51).into()),
+        );
+        assert_validation_error(
+            request(OpenAIModelId::Gpt4OMini)
+                .add_tool(FileSearchTool::new(vec!["vs_1"]).max_num_results(0).into()),
+        );
+    }
+
+    #[test]
+    fn it_rejects_inconsistent_tool_settings() {
+        assert_validation_error(
+            request(OpenAIModelId::Gpt4OMini)
+                .add_tool(FunctionTool::new("get_weather", json!({})).into())
+                .tool_choice(FunctionToolChoice::new("get_time").into()),
+        );
+
+        let computer = || ComputerUseTool::new(768.0, 1024.0, "browser").into();
+        assert_validation_error(request(OpenAIModelId::Gpt4OMini).add_tool(computer()));
+        assert!(request(OpenAIModelId::Gpt4OMini)
+            .add_tool(computer())
+            .truncation(Truncation::Auto)
+            .validate()
+            .is_ok());
+    }
+
+    #[test]
+    fn it_checks_requests_against_model_capabilities() {
+        assert_capability_error(
+            request(OpenAIModelId::Gpt4OMini).reasoning(Reasoning::new().effort("low")),
+        );
+        assert_capability_error(request(OpenAIModelId::O4Mini).temperature(0.5));
+        assert_capability_error(request(OpenAIModelId::O3Mini2025_01_31).top_p(0.5));
+        assert_capability_error(request(OpenAIModelId::Gpt4OMini).max_output_tokens(100_000));
+
+        let image = InputItemContentList {
+            content: vec![
+                TextContent::new().text("What is this?").into(),
+                ImageContent::new()
+                    .image_url("https://example.com/cat.png")
+                    .into(),
+            ],
+            ..Default::default()
+        };
+        let input = || Input::Messages(vec![InputItemList::InputMessage(image.clone().into())]);
+
+        assert_capability_error(OpenAIRequest::new(OpenAIModelId::Gpt4_0613, input()));
+        assert!(OpenAIRequest::new(OpenAIModelId::Gpt4O, input())
+            .validate()
+            .is_ok());
+        assert!(OpenAIRequest::new(OpenAIModelId::Gpt4_0613, input())
+            .validate_with(None)
+            .is_ok());
+    }
 }
@@ -0,0 +1,114 @@
+use crate::utils::errors::ProviderError;
+use reqwest::multipart::Part;
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// A file sent as part of a multipart request, such as an image to edit or audio
+/// to transcribe.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileUpload {
+    pub filename: String,
+    pub bytes: Vec<u8>,
+    pub mime_type: String,
+}
+
+impl FileUpload {
+    /// The MIME type is guessed from the file extension and can be overridden with
+    /// [`FileUpload::mime_type`].
+    pub fn new(filename: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        let filename = filename.into();
+        let mime_type = guess_mime_type(&filename).to_string();
+
+        Self {
+            filename,
+            bytes: bytes.into(),
+            mime_type,
+        }
+    }
+
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self, ProviderError> {
+        let path = path.as_ref();
+        let bytes = tokio::fs::read(path)
+            .await
+            .map_err(|e| ProviderError::InternalError(format!("{}: {}", path.display(), e)))?;
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Ok(Self::new(filename, bytes))
+    }
+
+    /// Reads `reader` to the end. The whole file is buffered, since the multipart
+    /// body needs to know its length.
+    pub async fn from_reader<R>(
+        filename: impl Into<String>,
+        mut reader: R,
+    ) -> Result<Self, ProviderError>
+    where
+        R: AsyncRead + Unpin,
+    {
+        let mut bytes = vec![];
+        reader
+            .read_to_end(&mut bytes)
+            .await
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+        Ok(Self::new(filename, bytes))
+    }
+
+    pub fn mime_type(mut self, value: impl Into<String>) -> Self {
+        self.mime_type = value.into();
+        self
+    }
+
+    pub(crate) fn to_part(&self) -> Result<Part, ProviderError> {
+        Part::bytes(self.bytes.clone())
+            .file_name(self.filename.clone())
+            .mime_str(&self.mime_type)
+            .map_err(|e| ProviderError::ValidationError(e.to_string()))
+    }
+}
+
+fn guess_mime_type(filename: &str) -> &'static str {
+    let extension = filename
+        .rsplit_once('.')
+        .map(|(_, extension)| extension.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "webp" => "image/webp",
+        "gif" => "image/gif",
+        "mp3" | "mpga" | "mpeg" => "audio/mpeg",
+        "mp4" | "m4a" => "audio/mp4",
+        "wav" => "audio/wav",
+        "webm" => "audio/webm",
+        "ogg" => "audio/ogg",
+        "flac" => "audio/flac",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_guesses_mime_types_from_the_extension() {
+        assert_eq!(FileUpload::new("cat.PNG", vec![]).mime_type, "image/png");
+        assert_eq!(FileUpload::new("cat.jpg", vec![]).mime_type, "image/jpeg");
+        assert_eq!(FileUpload::new("meow.m4a", vec![]).mime_type, "audio/mp4");
+        assert_eq!(
+            FileUpload::new("cat", vec![]).mime_type,
+            "application/octet-stream"
+        );
+        assert_eq!(
+            FileUpload::new("cat", vec![])
+                .mime_type("image/webp")
+                .mime_type,
+            "image/webp"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+use crate::openai_compatible::quirks::Quirks;
+use crate::openai_compatible::response::{ChatCompletion, ChatCompletionChunk};
+use crate::openai_compatible::types::ChatCompletionRequest;
+use crate::utils::{
+    errors::ProviderError,
+    http::{self, header_name, Auth, HttpConfig, HttpTransport},
+    provider_strategy::ProviderStrategy,
+};
+use async_trait::async_trait;
+use reqwest::header::{HeaderMap, HeaderValue};
+use reqwest::{Method, RequestBuilder};
+use serde_json::Value;
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// How the API key is sent.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthScheme {
+    /// `Authorization: Bearer <key>`, which most servers expect.
+    Bearer,
+    /// The raw key in a custom header, e.g. `api-key` or `x-api-key`.
+    Header(String),
+}
+
+/// Provider for any server that implements `/chat/completions`.
+#[derive(Debug, Clone)]
+pub struct OpenAICompatibleProvider {
+    api_key: Option<String>,
+    quirks: Quirks,
+    http: HttpTransport,
+}
+
+impl OpenAICompatibleProvider {
+    pub fn builder(base_url: impl Into<String>) -> OpenAICompatibleProviderBuilder {
+        OpenAICompatibleProviderBuilder::new(base_url)
+    }
+
+    pub fn quirks(&self) -> &Quirks {
+        &self.quirks
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        self.http.request(method, path)
+    }
+
+    fn body(&self, request: &ChatCompletionRequest, stream: bool) -> Result<Value, ProviderError> {
+        let mut body = serde_json::to_value(request)
+            .map_err(|e| ProviderError::InternalError(e.to_string()))?;
+
+        if let Value::Object(ref mut map) = body {
+            self.quirks.apply(map, stream);
+        }
+
+        Ok(body)
+    }
+}
+
+/// Builder for an [`OpenAICompatibleProvider`].
+///
+/// The base URL is the prefix `/chat/completions` is appended to, usually ending
+/// in `/v1`. An API key is optional, since local servers often run without one.
+#[derive(Debug)]
+pub struct OpenAICompatibleProviderBuilder {
+    api_key: Option<String>,
+    auth_scheme: AuthScheme,
+    base_url: String,
+    quirks: Quirks,
+    http: HttpConfig,
+}
+
+impl OpenAICompatibleProviderBuilder {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            api_key: None,
+            auth_scheme: AuthScheme::Bearer,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            quirks: Quirks::default(),
+            http: HttpConfig::default(),
+        }
+    }
+
+    pub fn api_key(mut self, value: impl Into<String>) -> Self {
+        self.api_key = Some(value.into());
+        self
+    }
+
+    pub fn auth_scheme(mut self, value: AuthScheme) -> Self {
+        self.auth_scheme = value;
+        self
+    }
+
+    pub fn header(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.http = self.http.header(key.into(), value.into());
+        self
+    }
+
+    pub fn quirks(mut self, value: Quirks) -> Self {
+        self.quirks = value;
+        self
+    }
+
+    pub fn timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.timeout(value);
+        self
+    }
+
+    pub fn connect_timeout(mut self, value: Duration) -> Self {
+        self.http = self.http.connect_timeout(value);
+        self
+    }
+
+    pub fn user_agent(mut self, value: impl Into<String>) -> Self {
+        self.http = self.http.user_agent(value.into());
+        self
+    }
+
+    pub fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http = self.http.http_client(value);
+        self
+    }
+
+    pub fn build(self) -> Result<OpenAICompatibleProvider, ProviderError> {
+        if let Some(api_key) = &self.api_key {
+            HeaderValue::from_str(api_key).map_err(|_| {
+                ProviderError::ValidationError("API key is not a valid header value".to_string())
+            })?;
+        }
+
+        let auth = match (&self.auth_scheme, &self.api_key) {
+            (AuthScheme::Header(name), Some(api_key)) => {
+                Auth::Header(header_name(name)?, api_key.clone())
+            }
+            (AuthScheme::Bearer, Some(api_key)) => Auth::Bearer(api_key.clone()),
+            (_, None) => Auth::None,
+        };
+
+        Ok(OpenAICompatibleProvider {
+            api_key: self.api_key,
+            quirks: self.quirks,
+            http: self.http.build(self.base_url, auth, HeaderMap::new())?,
+        })
+    }
+}
+
+#[async_trait]
+impl ProviderStrategy for OpenAICompatibleProvider {
+    type GenerationRequest = ChatCompletionRequest;
+    type StreamingRequest = ChatCompletionRequest;
+    type GenerationResponse = ChatCompletion;
+    type StreamingResponse = ChatCompletionChunk;
+
+    fn get_base_url(&self) -> String {
+        self.http.base_url().to_string()
+    }
+
+    fn get_api_key(&self) -> String {
+        self.api_key.clone().unwrap_or_default()
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        http::send(
+            self.request(Method::POST, "/chat/completions")
+                .json(&self.body(request, false)?),
+        )
+        .await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        http::send_sse(
+            self.http
+                .stream_request(Method::POST, "/chat/completions")
+                .json(&self.body(request, true)?),
+        )
+        .await
+    }
+}
@@ -0,0 +1,187 @@
+use serde_json::{json, Map, Value};
+
+/// Differences between servers that otherwise speak the Chat Completions API.
+///
+/// The defaults describe a server that accepts everything OpenAI does. Each flag
+/// that is turned off rewrites the request body right before it is sent, so
+/// requests can be built the same way for every backend.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Quirks {
+    /// Whether `stream_options.include_usage` is accepted. When it is, streaming
+    /// requests ask for usage unless the request sets its own stream options.
+    pub stream_options: bool,
+    /// Whether `response_format: json_schema` is supported. When it is not, it is
+    /// downgraded to `json_object`.
+    pub json_schema: bool,
+    /// Whether `strict` is accepted on tools and JSON schemas.
+    pub strict: bool,
+    /// Whether `parallel_tool_calls` is accepted.
+    pub parallel_tool_calls: bool,
+    /// Whether the token limit is named `max_completion_tokens` rather than the
+    /// older `max_tokens`.
+    pub max_completion_tokens: bool,
+}
+
+impl Default for Quirks {
+    fn default() -> Self {
+        Self {
+            stream_options: true,
+            json_schema: true,
+            strict: true,
+            parallel_tool_calls: true,
+            max_completion_tokens: true,
+        }
+    }
+}
+
+impl Quirks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn stream_options(mut self, value: bool) -> Self {
+        self.stream_options = value;
+        self
+    }
+
+    pub fn json_schema(mut self, value: bool) -> Self {
+        self.json_schema = value;
+        self
+    }
+
+    pub fn strict(mut self, value: bool) -> Self {
+        self.strict = value;
+        self
+    }
+
+    pub fn parallel_tool_calls(mut self, value: bool) -> Self {
+        self.parallel_tool_calls = value;
+        self
+    }
+
+    pub fn max_completion_tokens(mut self, value: bool) -> Self {
+        self.max_completion_tokens = value;
+        self
+    }
+
+    /// Settings for servers that only implement the core of the API, such as older
+    /// llama.cpp and LM Studio builds.
+    pub fn minimal() -> Self {
+        Self {
+            stream_options: false,
+            json_schema: false,
+            strict: false,
+            parallel_tool_calls: false,
+            max_completion_tokens: false,
+        }
+    }
+
+    /// Rewrites a serialized request body for this server.
+    pub fn apply(&self, body: &mut Map<String, Value>, stream: bool) {
+        if stream {
+            body.insert("stream".to_string(), json!(true));
+
+            if self.stream_options {
+                body.entry("stream_options")
+                    .or_insert_with(|| json!({ "include_usage": true }));
+            }
+        }
+
+        if !stream || !self.stream_options {
+            body.remove("stream_options");
+        }
+
+        if !self.max_completion_tokens {
+            if let Some(value) = body.remove("max_completion_tokens") {
+                body.insert("max_tokens".to_string(), value);
+            }
+        }
+
+        if !self.parallel_tool_calls {
+            body.remove("parallel_tool_calls");
+        }
+
+        if !self.json_schema {
+            let is_json_schema = body
+                .get("response_format")
+                .and_then(|format| format.get("type"))
+                .is_some_and(|kind| kind == "json_schema");
+
+            if is_json_schema {
+                body.insert(
+                    "response_format".to_string(),
+                    json!({ "type": "json_object" }),
+                );
+            }
+        }
+
+        if !self.strict {
+            if let Some(Value::Array(tools)) = body.get_mut("tools") {
+                for tool in tools {
+                    if let Some(Value::Object(function)) = tool.get_mut("function") {
+                        function.remove("strict");
+                    }
+                }
+            }
+
+            if let Some(Value::Object(schema)) = body
+                .get_mut("response_format")
+                .and_then(|format| format.get_mut("json_schema"))
+            {
+                schema.remove("strict");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(value: Value) -> Map<String, Value> {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn it_leaves_requests_alone_by_default() {
+        let mut request = body(json!({
+            "max_completion_tokens": 10,
+            "parallel_tool_calls": false,
+            "response_format": { "type": "json_schema", "json_schema": { "name": "a", "schema": {}, "strict": true } }
+        }));
+        let expected = request.clone();
+
+        Quirks::default().apply(&mut request, false);
+        assert_eq!(request, expected);
+
+        Quirks::default().apply(&mut request, true);
+        assert_eq!(request["stream"], json!(true));
+        assert_eq!(request["stream_options"], json!({ "include_usage": true }));
+    }
+
+    #[test]
+    fn it_rewrites_requests_for_minimal_servers() {
+        let mut request = body(json!({
+            "max_completion_tokens": 10,
+            "parallel_tool_calls": false,
+            "stream_options": { "include_usage": true },
+            "tools": [{ "type": "function", "function": { "name": "f", "strict": true } }],
+            "response_format": { "type": "json_schema", "json_schema": { "name": "a", "schema": {} } }
+        }));
+
+        Quirks::minimal().apply(&mut request, true);
+
+        assert_eq!(
+            Value::Object(request),
+            json!({
+                "max_tokens": 10,
+                "stream": true,
+                "tools": [{ "type": "function", "function": { "name": "f" } }],
+                "response_format": { "type": "json_object" }
+            })
+        );
+    }
+}
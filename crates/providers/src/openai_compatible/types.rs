@@ -0,0 +1,513 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::utils::schema::{schema_name, strict_schema_for};
+use schemars::JsonSchema;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ImageUrl {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ContentPart {
+    Text { text: String },
+    ImageUrl { image_url: ImageUrl },
+}
+
+impl ContentPart {
+    pub fn text(text: impl Into<String>) -> Self {
+        Self::Text { text: text.into() }
+    }
+
+    /// An image by URL, which may also be a `data:` URL with base64 content.
+    pub fn image_url(url: impl Into<String>) -> Self {
+        Self::ImageUrl {
+            image_url: ImageUrl {
+                url: url.into(),
+                detail: None,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChatContent {
+    Text(String),
+    Parts(Vec<ContentPart>),
+}
+
+impl From<String> for ChatContent {
+    fn from(value: String) -> Self {
+        ChatContent::Text(value)
+    }
+}
+
+impl From<&str> for ChatContent {
+    fn from(value: &str) -> Self {
+        ChatContent::Text(value.to_string())
+    }
+}
+
+impl From<Vec<ContentPart>> for ChatContent {
+    fn from(value: Vec<ContentPart>) -> Self {
+        ChatContent::Parts(value)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunctionCall {
+    pub name: String,
+    /// The arguments as a JSON encoded string, which may not be valid JSON.
+    pub arguments: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub id: String,
+    #[serde(rename = "type", default = "function_type")]
+    pub type_field: String,
+    pub function: FunctionCall,
+}
+
+fn function_type() -> String {
+    "function".to_string()
+}
+
+impl ToolCall {
+    pub fn new(
+        id: impl Into<String>,
+        name: impl Into<String>,
+        arguments: impl Into<String>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            type_field: function_type(),
+            function: FunctionCall {
+                name: name.into(),
+                arguments: arguments.into(),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "role", rename_all = "snake_case")]
+pub enum ChatMessage {
+    System {
+        content: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    User {
+        content: ChatContent,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    Assistant {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        content: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        tool_calls: Option<Vec<ToolCall>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+    Tool {
+        tool_call_id: String,
+        content: String,
+    },
+}
+
+impl ChatMessage {
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::System {
+            content: content.into(),
+            name: None,
+        }
+    }
+
+    pub fn user(content: impl Into<ChatContent>) -> Self {
+        Self::User {
+            content: content.into(),
+            name: None,
+        }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self::Assistant {
+            content: Some(content.into()),
+            tool_calls: None,
+            name: None,
+        }
+    }
+
+    pub fn assistant_tool_calls(tool_calls: Vec<ToolCall>) -> Self {
+        Self::Assistant {
+            content: None,
+            tool_calls: Some(tool_calls),
+            name: None,
+        }
+    }
+
+    pub fn tool(tool_call_id: impl Into<String>, content: impl Into<String>) -> Self {
+        Self::Tool {
+            tool_call_id: tool_call_id.into(),
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct FunctionDefinition {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameters: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ChatTool {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub function: FunctionDefinition,
+}
+
+impl ChatTool {
+    pub fn function(name: impl Into<String>, parameters: Value) -> Self {
+        Self {
+            type_field: function_type(),
+            function: FunctionDefinition {
+                name: name.into(),
+                description: None,
+                parameters: Some(parameters),
+                strict: None,
+            },
+        }
+    }
+
+    /// Builds a strict function tool whose parameters are derived from `T`.
+    pub fn from_type<T: JsonSchema>(name: impl Into<String>) -> Self {
+        let mut parameters = strict_schema_for::<T>();
+        let description = parameters
+            .as_object_mut()
+            .and_then(|schema| {
+                schema.remove("title");
+                schema.remove("description")
+            })
+            .and_then(|description| description.as_str().map(str::to_string));
+
+        Self {
+            type_field: function_type(),
+            function: FunctionDefinition {
+                name: name.into(),
+                description,
+                parameters: Some(parameters),
+                strict: Some(true),
+            },
+        }
+    }
+
+    pub fn description(mut self, value: impl Into<String>) -> Self {
+        self.function.description = Some(value.into());
+        self
+    }
+
+    pub fn strict(mut self, value: bool) -> Self {
+        self.function.strict = Some(value);
+        self
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ToolChoiceMode {
+    None,
+    Auto,
+    Required,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamedFunction {
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NamedToolChoice {
+    #[serde(rename = "type")]
+    pub type_field: String,
+    pub function: NamedFunction,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ChatToolChoice {
+    Mode(ToolChoiceMode),
+    Named(NamedToolChoice),
+}
+
+impl ChatToolChoice {
+    pub fn function(name: impl Into<String>) -> Self {
+        Self::Named(NamedToolChoice {
+            type_field: function_type(),
+            function: NamedFunction { name: name.into() },
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct JsonSchemaSpec {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub schema: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ChatResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema { json_schema: JsonSchemaSpec },
+}
+
+impl ChatResponseFormat {
+    pub fn json_schema(name: impl Into<String>, schema: Value) -> Self {
+        Self::JsonSchema {
+            json_schema: JsonSchemaSpec {
+                name: name.into(),
+                description: None,
+                schema,
+                strict: None,
+            },
+        }
+    }
+
+    pub fn from_type<T: JsonSchema>() -> Self {
+        Self::JsonSchema {
+            json_schema: JsonSchemaSpec {
+                name: schema_name::<T>(),
+                description: None,
+                schema: strict_schema_for::<T>(),
+                strict: Some(true),
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StreamOptions {
+    pub include_usage: bool,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionRequest {
+    model: String,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    logprobs: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_completion_tokens: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    n: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parallel_tool_calls: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_format: Option<ChatResponseFormat>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<StreamOptions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tool_choice: Option<ChatToolChoice>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tools: Option<Vec<ChatTool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_logprobs: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<String>,
+}
+
+impl ChatCompletionRequest {
+    pub fn new(model: impl Into<String>, messages: Vec<ChatMessage>) -> Self {
+        Self {
+            model: model.into(),
+            messages,
+            ..Default::default()
+        }
+    }
+
+    pub fn add_message(mut self, value: ChatMessage) -> Self {
+        self.messages.push(value);
+        self
+    }
+
+    pub fn frequency_penalty(mut self, value: f32) -> Self {
+        self.frequency_penalty = Some(value);
+        self
+    }
+
+    /// Requests log probabilities of the output tokens, plus the `top_logprobs` most
+    /// likely alternatives at each position when that is set.
+    pub fn logprobs(mut self, top_logprobs: Option<usize>) -> Self {
+        self.logprobs = Some(true);
+        self.top_logprobs = top_logprobs;
+        self
+    }
+
+    pub fn max_completion_tokens(mut self, value: usize) -> Self {
+        self.max_completion_tokens = Some(value);
+        self
+    }
+
+    /// Number of choices to generate.
+    pub fn n(mut self, value: usize) -> Self {
+        self.n = Some(value);
+        self
+    }
+
+    pub fn parallel_tool_calls(mut self, value: bool) -> Self {
+        self.parallel_tool_calls = Some(value);
+        self
+    }
+
+    pub fn presence_penalty(mut self, value: f32) -> Self {
+        self.presence_penalty = Some(value);
+        self
+    }
+
+    pub fn response_format(mut self, value: ChatResponseFormat) -> Self {
+        self.response_format = Some(value);
+        self
+    }
+
+    pub fn seed(mut self, value: i64) -> Self {
+        self.seed = Some(value);
+        self
+    }
+
+    pub fn add_stop(mut self, value: impl Into<String>) -> Self {
+        match self.stop {
+            Some(ref mut stop) => stop.push(value.into()),
+            None => self.stop = Some(vec![value.into()]),
+        }
+        self
+    }
+
+    /// Stream options, sent only with streaming requests.
+    pub fn stream_options(mut self, value: StreamOptions) -> Self {
+        self.stream_options = Some(value);
+        self
+    }
+
+    pub fn temperature(mut self, value: f32) -> Self {
+        self.temperature = Some(value);
+        self
+    }
+
+    pub fn tool_choice(mut self, value: ChatToolChoice) -> Self {
+        self.tool_choice = Some(value);
+        self
+    }
+
+    pub fn add_tool(mut self, value: ChatTool) -> Self {
+        match self.tools {
+            Some(ref mut tools) => tools.push(value),
+            None => self.tools = Some(vec![value]),
+        }
+        self
+    }
+
+    pub fn top_p(mut self, value: f32) -> Self {
+        self.top_p = Some(value);
+        self
+    }
+
+    pub fn user(mut self, value: impl Into<String>) -> Self {
+        self.user = Some(value.into());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_json_values() {
+        let request = ChatCompletionRequest::new(
+            "llama-3.1-8b",
+            vec![
+                ChatMessage::system("Be terse."),
+                ChatMessage::user(vec![
+                    ContentPart::text("What is this?"),
+                    ContentPart::image_url("data:image/png;base64,aGVsbG8="),
+                ]),
+                ChatMessage::assistant_tool_calls(vec![ToolCall::new(
+                    "call_1",
+                    "lookup",
+                    "{\"q\":\"cat\"}",
+                )]),
+                ChatMessage::tool("call_1", "A cat."),
+            ],
+        )
+        .add_tool(ChatTool::function("lookup", json!({ "type": "object" })))
+        .tool_choice(ChatToolChoice::Mode(ToolChoiceMode::Auto))
+        .response_format(ChatResponseFormat::JsonObject)
+        .logprobs(Some(2))
+        .n(2)
+        .seed(7);
+
+        assert_eq!(
+            serde_json::to_value(&request).unwrap(),
+            json!({
+                "model": "llama-3.1-8b",
+                "messages": [
+                    { "role": "system", "content": "Be terse." },
+                    { "role": "user", "content": [
+                        { "type": "text", "text": "What is this?" },
+                        { "type": "image_url", "image_url": { "url": "data:image/png;base64,aGVsbG8=" } }
+                    ] },
+                    { "role": "assistant", "tool_calls": [
+                        { "id": "call_1", "type": "function", "function": { "name": "lookup", "arguments": "{\"q\":\"cat\"}" } }
+                    ] },
+                    { "role": "tool", "tool_call_id": "call_1", "content": "A cat." }
+                ],
+                "tools": [{ "type": "function", "function": { "name": "lookup", "parameters": { "type": "object" } } }],
+                "tool_choice": "auto",
+                "response_format": { "type": "json_object" },
+                "logprobs": true,
+                "top_logprobs": 2,
+                "n": 2,
+                "seed": 7
+            })
+        );
+    }
+
+    #[test]
+    fn it_serializes_named_tool_choices() {
+        assert_eq!(
+            serde_json::to_value(ChatToolChoice::function("lookup")).unwrap(),
+            json!({ "type": "function", "function": { "name": "lookup" } })
+        );
+    }
+}
@@ -1,23 +1,210 @@
+use serde_json::Value;
 use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiErrorKind {
+    Authentication,
+    PermissionDenied,
+    NotFound,
+    RateLimit,
+    QuotaExceeded,
+    ContextLengthExceeded,
+    ContentFilter,
+    InvalidRequest,
+    ServerError,
+    Other,
+}
+
+impl ApiErrorKind {
+    pub fn from_status(status: u16) -> Self {
+        match status {
+            400 | 422 => Self::InvalidRequest,
+            401 => Self::Authentication,
+            403 => Self::PermissionDenied,
+            404 => Self::NotFound,
+            429 => Self::RateLimit,
+            500..=599 => Self::ServerError,
+            _ => Self::Other,
+        }
+    }
+
+    /// Classifies an error from its status and the `code`/`type` fields of the body,
+    /// which are more specific than the status for quota, context length and content
+    /// filter failures.
+    pub fn classify(status: u16, code: Option<&str>, error_type: Option<&str>) -> Self {
+        let matches = |values: &[&str]| {
+            [code, error_type]
+                .into_iter()
+                .flatten()
+                .any(|value| values.contains(&value))
+        };
+
+        if matches(&["insufficient_quota", "billing_hard_limit_reached"]) {
+            Self::QuotaExceeded
+        } else if matches(&["context_length_exceeded", "string_above_max_length"]) {
+            Self::ContextLengthExceeded
+        } else if matches(&["content_filter", "content_policy_violation"]) {
+            Self::ContentFilter
+        } else {
+            Self::from_status(status)
+        }
+    }
+}
+
+impl fmt::Display for ApiErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let value = match self {
+            ApiErrorKind::Authentication => "authentication",
+            ApiErrorKind::PermissionDenied => "permission denied",
+            ApiErrorKind::NotFound => "not found",
+            ApiErrorKind::RateLimit => "rate limit",
+            ApiErrorKind::QuotaExceeded => "quota exceeded",
+            ApiErrorKind::ContextLengthExceeded => "context length exceeded",
+            ApiErrorKind::ContentFilter => "content filter",
+            ApiErrorKind::InvalidRequest => "invalid request",
+            ApiErrorKind::ServerError => "server error",
+            ApiErrorKind::Other => "other",
+        };
+
+        write!(f, "{}", value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    pub status: u16,
+    pub kind: ApiErrorKind,
+    pub message: String,
+    pub error_type: Option<String>,
+    pub param: Option<String>,
+    pub code: Option<String>,
+    pub request_id: Option<String>,
+    pub retry_after: Option<Duration>,
+    /// Provider-specific details, such as the `innererror` object Azure OpenAI
+    /// attaches to content filter rejections.
+    pub inner_error: Option<Value>,
+}
+
+impl ApiError {
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            kind: ApiErrorKind::from_status(status),
+            message: message.into(),
+            error_type: None,
+            param: None,
+            code: None,
+            request_id: None,
+            retry_after: None,
+            inner_error: None,
+        }
+    }
+
+    pub fn kind(mut self, value: ApiErrorKind) -> Self {
+        self.kind = value;
+        self
+    }
+
+    pub fn error_type(mut self, value: impl Into<String>) -> Self {
+        self.error_type = Some(value.into());
+        self
+    }
+
+    pub fn param(mut self, value: impl Into<String>) -> Self {
+        self.param = Some(value.into());
+        self
+    }
+
+    pub fn code(mut self, value: impl Into<String>) -> Self {
+        self.code = Some(value.into());
+        self
+    }
+
+    pub fn request_id(mut self, value: impl Into<String>) -> Self {
+        self.request_id = Some(value.into());
+        self
+    }
+
+    pub fn retry_after(mut self, value: Duration) -> Self {
+        self.retry_after = Some(value);
+        self
+    }
+
+    pub fn inner_error(mut self, value: Value) -> Self {
+        self.inner_error = Some(value);
+        self
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "API error (status {}): {}", self.status, self.message)?;
+
+        if let Some(request_id) = &self.request_id {
+            write!(f, " (request id: {})", request_id)?;
+        }
+
+        Ok(())
+    }
+}
 
 #[derive(Debug)]
 pub enum ProviderError {
+    /// The connection could not be made, e.g. it was refused or timed out, or it was
+    /// reset or closed before the response arrived.
+    ConnectionError(String),
     NetworkError(String),
-    ApiError { status: u16, message: String },
+    ApiError(Box<ApiError>),
+    RetryError {
+        attempts: u32,
+        last_error: Box<ProviderError>,
+    },
     DeserializationError(String),
     ValidationError(String),
     CapabilityError(String),
     NotSupported(String),
+    Timeout(String),
     InternalError(String),
     Other(String),
 }
 
+impl ProviderError {
+    /// Returns the API error behind this error, looking through retry wrappers.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            ProviderError::ApiError(error) => Some(error),
+            ProviderError::RetryError { last_error, .. } => last_error.api_error(),
+            _ => None,
+        }
+    }
+
+    pub fn api_error_kind(&self) -> Option<ApiErrorKind> {
+        self.api_error().map(|error| error.kind)
+    }
+}
+
+impl From<ApiError> for ProviderError {
+    fn from(error: ApiError) -> Self {
+        ProviderError::ApiError(Box::new(error))
+    }
+}
+
 impl fmt::Display for ProviderError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            ProviderError::ConnectionError(msg) => write!(f, "Connection error: {}", msg),
             ProviderError::NetworkError(msg) => write!(f, "Network error: {}", msg),
-            ProviderError::ApiError { status, message } => {
-                write!(f, "API error (status {}): {}", status, message)
+            ProviderError::ApiError(error) => write!(f, "{}", error),
+            ProviderError::RetryError {
+                attempts,
+                last_error,
+            } => {
+                write!(
+                    f,
+                    "Request failed after {} attempts: {}",
+                    attempts, last_error
+                )
             }
             ProviderError::DeserializationError(msg) => {
                 write!(f, "Deserialization error: {}", msg)
@@ -25,6 +212,7 @@ impl fmt::Display for ProviderError {
             ProviderError::ValidationError(msg) => write!(f, "Validation error: {}", msg),
             ProviderError::CapabilityError(msg) => write!(f, "Capability error: {}", msg),
             ProviderError::NotSupported(msg) => write!(f, "Operation not supported: {}", msg),
+            ProviderError::Timeout(msg) => write!(f, "Timed out: {}", msg),
             ProviderError::InternalError(msg) => write!(f, "Internal provider error: {}", msg),
             ProviderError::Other(msg) => write!(f, "An unexpected error occurred: {}", msg),
         }
@@ -34,14 +222,73 @@ impl fmt::Display for ProviderError {
 impl std::error::Error for ProviderError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
+            ProviderError::ConnectionError(_) => None,
             ProviderError::NetworkError(_) => None,
-            ProviderError::ApiError { .. } => None,
+            ProviderError::ApiError(_) => None,
+            ProviderError::RetryError { last_error, .. } => Some(last_error.as_ref()),
             ProviderError::DeserializationError(_) => None,
             ProviderError::ValidationError(_) => None,
             ProviderError::CapabilityError(_) => None,
             ProviderError::NotSupported(_) => None,
+            ProviderError::Timeout(_) => None,
             ProviderError::InternalError(_) => None,
             ProviderError::Other(_) => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_classifies_errors_by_status() {
+        let cases = [
+            (400, ApiErrorKind::InvalidRequest),
+            (401, ApiErrorKind::Authentication),
+            (403, ApiErrorKind::PermissionDenied),
+            (404, ApiErrorKind::NotFound),
+            (429, ApiErrorKind::RateLimit),
+            (503, ApiErrorKind::ServerError),
+            (418, ApiErrorKind::Other),
+        ];
+
+        for (status, expected) in cases {
+            assert_eq!(ApiErrorKind::classify(status, None, None), expected);
+        }
+    }
+
+    #[test]
+    fn it_classifies_errors_by_code_and_type() {
+        assert_eq!(
+            ApiErrorKind::classify(429, Some("insufficient_quota"), None),
+            ApiErrorKind::QuotaExceeded
+        );
+        assert_eq!(
+            ApiErrorKind::classify(400, Some("context_length_exceeded"), None),
+            ApiErrorKind::ContextLengthExceeded
+        );
+        assert_eq!(
+            ApiErrorKind::classify(400, None, Some("content_filter")),
+            ApiErrorKind::ContentFilter
+        );
+        assert_eq!(
+            ApiErrorKind::classify(429, Some("rate_limit_exceeded"), None),
+            ApiErrorKind::RateLimit
+        );
+    }
+
+    #[test]
+    fn it_finds_the_api_error_behind_retries() {
+        let error = ProviderError::RetryError {
+            attempts: 3,
+            last_error: Box::new(ApiError::new(429, "slow down").into()),
+        };
+
+        assert_eq!(error.api_error_kind(), Some(ApiErrorKind::RateLimit));
+        assert_eq!(
+            ProviderError::NetworkError("reset".to_string()).api_error_kind(),
+            None
+        );
+    }
+}
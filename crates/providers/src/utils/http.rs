@@ -0,0 +1,590 @@
+use crate::utils::errors::{ApiError, ApiErrorKind, ProviderError};
+use crate::utils::sse::decode_sse_stream;
+use bytes::Bytes;
+use futures::stream::StreamExt;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, RETRY_AFTER, USER_AGENT};
+use reqwest::{Method, RequestBuilder};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::Value;
+use std::error::Error;
+use std::io;
+use std::pin::Pin;
+use std::time::{Duration, SystemTime};
+use tokio_stream::Stream;
+
+pub(crate) type EventStream<T> = Pin<Box<dyn Stream<Item = Result<T, ProviderError>> + Send>>;
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    error: ErrorDetails,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ErrorDetails {
+    Object {
+        message: Option<String>,
+        #[serde(rename = "type")]
+        error_type: Option<String>,
+        param: Option<Value>,
+        code: Option<Value>,
+        status: Option<Value>,
+        innererror: Option<Value>,
+    },
+    Message(String),
+}
+
+pub(crate) fn header_name(name: &str) -> Result<HeaderName, ProviderError> {
+    HeaderName::from_bytes(name.as_bytes())
+        .map_err(|_| ProviderError::ValidationError(format!("invalid header name: {}", name)))
+}
+
+/// Parses the value of the header `name`. The value is left out of the error, since
+/// headers often carry credentials.
+pub(crate) fn header_value(name: &str, value: &str) -> Result<HeaderValue, ProviderError> {
+    HeaderValue::from_str(value)
+        .map_err(|_| ProviderError::ValidationError(format!("invalid value for header {}", name)))
+}
+
+/// How a provider authenticates. Credentials are added to each request rather
+/// than to the default headers, so headers set on the builder can still override
+/// them.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Auth {
+    None,
+    Bearer(String),
+    Header(HeaderName, String),
+    /// Sent as a query parameter, for APIs that accept keys in the URL.
+    Query(&'static str, String),
+}
+
+/// The connection settings every provider builder accepts.
+#[derive(Debug, Default)]
+pub(crate) struct HttpConfig {
+    headers: Vec<(String, String)>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    http_client: Option<reqwest::Client>,
+}
+
+impl HttpConfig {
+    pub(crate) fn header(mut self, key: String, value: String) -> Self {
+        self.headers.push((key, value));
+        self
+    }
+
+    pub(crate) fn timeout(mut self, value: Duration) -> Self {
+        self.timeout = Some(value);
+        self
+    }
+
+    pub(crate) fn connect_timeout(mut self, value: Duration) -> Self {
+        self.connect_timeout = Some(value);
+        self
+    }
+
+    pub(crate) fn user_agent(mut self, value: String) -> Self {
+        self.user_agent = Some(value);
+        self
+    }
+
+    pub(crate) fn http_client(mut self, value: reqwest::Client) -> Self {
+        self.http_client = Some(value);
+        self
+    }
+
+    /// Builds the transport. `headers` are the provider's own defaults; the user
+    /// agent and the headers added with [`HttpConfig::header`] are applied on top.
+    pub(crate) fn build(
+        self,
+        base_url: String,
+        auth: Auth,
+        mut headers: HeaderMap,
+    ) -> Result<HttpTransport, ProviderError> {
+        if let Some(user_agent) = self.user_agent {
+            headers.insert(USER_AGENT, header_value(USER_AGENT.as_str(), &user_agent)?);
+        }
+
+        for (key, value) in self.headers {
+            headers.insert(header_name(&key)?, header_value(&key, &value)?);
+        }
+
+        let client = match self.http_client {
+            Some(client) => client,
+            None => {
+                let mut builder = reqwest::Client::builder();
+
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+
+                builder
+                    .build()
+                    .map_err(|e| ProviderError::InternalError(e.to_string()))?
+            }
+        };
+
+        Ok(HttpTransport {
+            base_url,
+            auth,
+            headers,
+            timeout: self.timeout,
+            client,
+        })
+    }
+}
+
+/// The HTTP side of a provider: where requests go, how they are authenticated and
+/// which headers and timeout they carry.
+#[derive(Debug, Clone)]
+pub(crate) struct HttpTransport {
+    base_url: String,
+    auth: Auth,
+    headers: HeaderMap,
+    timeout: Option<Duration>,
+    client: reqwest::Client,
+}
+
+impl HttpTransport {
+    pub(crate) fn new(base_url: impl Into<String>, auth: Auth, headers: HeaderMap) -> Self {
+        Self {
+            base_url: base_url.into(),
+            auth,
+            headers,
+            timeout: None,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    pub(crate) fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// A request without the timeout, for streams that stay open for as long as
+    /// the model keeps generating.
+    pub(crate) fn stream_request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self
+            .client
+            .request(method, format!("{}{}", self.base_url, path));
+
+        let request = match &self.auth {
+            Auth::None => request,
+            Auth::Bearer(token) => request.bearer_auth(token),
+            Auth::Header(name, value) => request.header(name, value),
+            Auth::Query(name, value) => request.query(&[(name, value)]),
+        };
+
+        request.headers(self.headers.clone())
+    }
+
+    pub(crate) fn request(&self, method: Method, path: &str) -> RequestBuilder {
+        let request = self.stream_request(method, path);
+
+        match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        }
+    }
+}
+
+/// Connection failures are told apart from other transport errors so they can be
+/// retried. The URL is dropped from the message, since it may carry an API key.
+pub(crate) fn network_error(error: reqwest::Error) -> ProviderError {
+    let error = error.without_url();
+
+    if is_connection_error(&error) {
+        ProviderError::ConnectionError(error.to_string())
+    } else {
+        ProviderError::NetworkError(error.to_string())
+    }
+}
+
+/// Whether the connection could not be made or was dropped before the response
+/// completed, as happens when a pooled keep-alive connection was closed by the
+/// server.
+fn is_connection_error(error: &reqwest::Error) -> bool {
+    if error.is_connect() {
+        return true;
+    }
+
+    let mut source = error.source();
+    while let Some(error) = source {
+        if let Some(error) = error.downcast_ref::<hyper::Error>() {
+            if error.is_closed() || error.is_incomplete_message() {
+                return true;
+            }
+        }
+
+        if let Some(error) = error.downcast_ref::<io::Error>() {
+            if matches!(
+                error.kind(),
+                io::ErrorKind::ConnectionReset
+                    | io::ErrorKind::ConnectionAborted
+                    | io::ErrorKind::BrokenPipe
+                    | io::ErrorKind::UnexpectedEof
+            ) {
+                return true;
+            }
+        }
+
+        source = error.source();
+    }
+
+    false
+}
+
+/// Sends `request`, turning non-success responses into [`ProviderError::ApiError`].
+pub(crate) async fn execute(request: RequestBuilder) -> Result<reqwest::Response, ProviderError> {
+    let response = request.send().await.map_err(network_error)?;
+
+    if !response.status().is_success() {
+        return Err(api_error(response).await);
+    }
+
+    Ok(response)
+}
+
+/// Sends `request` and returns the whole response body.
+pub(crate) async fn send_raw(request: RequestBuilder) -> Result<Bytes, ProviderError> {
+    execute(request).await?.bytes().await.map_err(network_error)
+}
+
+/// Sends `request` and deserializes the JSON response body.
+pub(crate) async fn send<T: DeserializeOwned>(request: RequestBuilder) -> Result<T, ProviderError> {
+    let response_bytes = send_raw(request).await?;
+
+    serde_json::from_slice(&response_bytes)
+        .map_err(|e| ProviderError::DeserializationError(e.to_string()))
+}
+
+/// Sends `request` and deserializes each Server-Sent Event of the response as
+/// JSON. A `[DONE]` sentinel event is skipped.
+pub(crate) async fn send_sse<T>(request: RequestBuilder) -> Result<EventStream<T>, ProviderError>
+where
+    T: DeserializeOwned + Send + 'static,
+{
+    let response = execute(request.header("Accept", "text/event-stream")).await?;
+
+    let parsed_stream = decode_sse_stream(response.bytes_stream()).filter_map(|event| async move {
+        match event {
+            Ok(event) if event.data == "[DONE]" => None,
+            Ok(event) => Some(
+                serde_json::from_str(&event.data)
+                    .map_err(|e| ProviderError::DeserializationError(e.to_string())),
+            ),
+            Err(e) => Some(Err(e)),
+        }
+    });
+
+    Ok(Box::pin(parsed_stream))
+}
+
+/// Turns a non-success HTTP response into a [`ProviderError::ApiError`].
+///
+/// The body is parsed as the `{"error": {"message", "type", "param", "code"}}`
+/// envelope used by OpenAI (and, with small variations, by most other providers);
+/// bodies in any other shape are kept verbatim as the message.
+pub(crate) async fn api_error(response: reqwest::Response) -> ProviderError {
+    let status = response.status().as_u16();
+    let headers = response.headers().clone();
+    let body = response
+        .text()
+        .await
+        .unwrap_or_else(|_| "Failed to read error response".to_string());
+
+    parse_api_error(status, &headers, &body).into()
+}
+
+pub(crate) fn parse_api_error(status: u16, headers: &HeaderMap, body: &str) -> ApiError {
+    let mut error = ApiError::new(status, body);
+
+    match serde_json::from_str::<ErrorBody>(body).map(|body| body.error) {
+        Ok(ErrorDetails::Object {
+            message,
+            error_type,
+            param,
+            code,
+            status: status_text,
+            innererror,
+        }) => {
+            error.message = message.unwrap_or_else(|| body.to_string());
+            error.error_type = error_type.or(match status_text {
+                Some(Value::String(status_text)) => Some(status_text),
+                _ => None,
+            });
+            error.param = param.as_ref().and_then(value_to_string);
+            error.code = code.as_ref().and_then(value_to_string);
+            error.inner_error = innererror;
+        }
+        Ok(ErrorDetails::Message(message)) => error.message = message,
+        Err(_) => {}
+    }
+
+    error.kind = ApiErrorKind::classify(status, error.code.as_deref(), error.error_type.as_deref());
+    error.request_id = ["x-request-id", "request-id"]
+        .into_iter()
+        .find_map(|name| headers.get(name).and_then(|value| value.to_str().ok()))
+        .map(|value| value.to_string());
+    error.retry_after = retry_after(headers);
+
+    error
+}
+
+fn value_to_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(value) => Some(value.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+/// Reads the retry delay requested by the server.
+///
+/// `retry-after-ms` and `retry-after` (either delay-seconds or an HTTP date) take
+/// precedence; otherwise the longest of the `x-ratelimit-reset-*` durations is used.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let header = |name: &str| headers.get(name).and_then(|value| value.to_str().ok());
+
+    if let Some(millis) =
+        header("retry-after-ms").and_then(|value| value.trim().parse::<f64>().ok())
+    {
+        if millis.is_finite() && millis >= 0.0 {
+            return Some(Duration::from_secs_f64(millis / 1000.0));
+        }
+    }
+
+    if let Some(value) = header(RETRY_AFTER.as_str()).map(str::trim) {
+        if let Ok(seconds) = value.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        if let Ok(date) = httpdate::parse_http_date(value) {
+            return Some(
+                date.duration_since(SystemTime::now())
+                    .unwrap_or(Duration::ZERO),
+            );
+        }
+    }
+
+    ["x-ratelimit-reset-requests", "x-ratelimit-reset-tokens"]
+        .into_iter()
+        .filter_map(|name| header(name).and_then(parse_reset_duration))
+        .max()
+}
+
+/// Parses rate limit reset durations such as `1s`, `6m0s`, `20ms` or `1h2m3.5s`.
+fn parse_reset_duration(value: &str) -> Option<Duration> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    let mut total = 0.0;
+    let mut rest = value;
+
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_end].parse().ok()?;
+        rest = &rest[number_end..];
+
+        let unit_end = rest
+            .find(|c: char| c.is_ascii_digit() || c == '.')
+            .unwrap_or(rest.len());
+        let seconds_per_unit = match &rest[..unit_end] {
+            "h" => 3600.0,
+            "m" => 60.0,
+            "s" => 1.0,
+            "ms" => 0.001,
+            _ => return None,
+        };
+        rest = &rest[unit_end..];
+
+        total += number * seconds_per_unit;
+    }
+
+    Some(Duration::from_secs_f64(total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::retry::RetryPolicy;
+    use reqwest::header::HeaderValue;
+
+    fn headers(values: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in values {
+            headers.insert(*name, HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn it_keeps_header_values_out_of_errors() {
+        let error = HttpConfig::default()
+            .header("Authorization".to_string(), "Bearer secret\n".to_string())
+            .build(String::new(), Auth::None, HeaderMap::new())
+            .unwrap_err();
+
+        match error {
+            ProviderError::ValidationError(message) => {
+                assert_eq!(message, "invalid value for header Authorization")
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_treats_dropped_connections_as_connection_errors() {
+        use tokio::io::AsyncReadExt;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 1024];
+            let _ = socket.read(&mut buffer).await;
+            // close without answering, mid-request from the client's side
+        });
+
+        let transport =
+            HttpTransport::new(format!("http://{}", address), Auth::None, HeaderMap::new());
+        let error = execute(transport.request(Method::POST, "/responses").body("{}"))
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, ProviderError::ConnectionError(_)));
+        assert!(RetryPolicy::default().is_retryable(&error));
+    }
+
+    #[test]
+    fn it_parses_retry_after_seconds() {
+        let result = retry_after(&headers(&[("retry-after", "7")]));
+        assert_eq!(result, Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn it_prefers_retry_after_ms() {
+        let result = retry_after(&headers(&[("retry-after", "7"), ("retry-after-ms", "250")]));
+        assert_eq!(result, Some(Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn it_parses_retry_after_http_dates() {
+        let date = httpdate::fmt_http_date(SystemTime::now() + Duration::from_secs(30));
+        let result = retry_after(&headers(&[("retry-after", &date)])).unwrap();
+
+        assert!(result > Duration::from_secs(25) && result <= Duration::from_secs(30));
+
+        let past = httpdate::fmt_http_date(SystemTime::UNIX_EPOCH);
+        let result = retry_after(&headers(&[("retry-after", &past)]));
+        assert_eq!(result, Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn it_uses_the_longest_rate_limit_reset() {
+        let result = retry_after(&headers(&[
+            ("x-ratelimit-reset-requests", "1s"),
+            ("x-ratelimit-reset-tokens", "6m0s"),
+        ]));
+        assert_eq!(result, Some(Duration::from_secs(360)));
+    }
+
+    #[test]
+    fn it_parses_reset_durations() {
+        assert_eq!(
+            parse_reset_duration("20ms"),
+            Some(Duration::from_millis(20))
+        );
+        assert_eq!(
+            parse_reset_duration("1h2m3.5s"),
+            Some(Duration::from_secs_f64(3723.5))
+        );
+        assert_eq!(parse_reset_duration("soon"), None);
+        assert_eq!(parse_reset_duration(""), None);
+    }
+
+    #[test]
+    fn it_parses_openai_error_bodies() {
+        let body = r#"{"error": {"message": "This model's maximum context length is 128000 tokens.", "type": "invalid_request_error", "param": "input", "code": "context_length_exceeded"}}"#;
+        let error = parse_api_error(400, &headers(&[("x-request-id", "req_123")]), body);
+
+        assert_eq!(
+            error,
+            ApiError::new(400, "This model's maximum context length is 128000 tokens.")
+                .kind(ApiErrorKind::ContextLengthExceeded)
+                .error_type("invalid_request_error")
+                .param("input")
+                .code("context_length_exceeded")
+                .request_id("req_123")
+        );
+    }
+
+    #[test]
+    fn it_parses_quota_errors() {
+        let body = r#"{"error": {"message": "You exceeded your current quota.", "type": "insufficient_quota", "param": null, "code": "insufficient_quota"}}"#;
+        let error = parse_api_error(429, &headers(&[("retry-after", "1")]), body);
+
+        assert_eq!(error.kind, ApiErrorKind::QuotaExceeded);
+        assert_eq!(error.param, None);
+        assert_eq!(error.retry_after, Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn it_does_not_retry_quota_errors() {
+        let body = r#"{"error": {"message": "You exceeded your current quota.", "type": "insufficient_quota", "param": null, "code": "insufficient_quota"}}"#;
+        let quota_error = parse_api_error(429, &HeaderMap::new(), body);
+        let rate_limit_error = parse_api_error(429, &HeaderMap::new(), "{}");
+
+        let policy = RetryPolicy::new();
+        assert!(!policy.is_retryable(&quota_error.into()));
+        assert!(policy.is_retryable(&rate_limit_error.into()));
+    }
+
+    #[test]
+    fn it_parses_numeric_codes_and_plain_messages() {
+        let body = r#"{"error": {"code": 403, "message": "Permission denied.", "status": "PERMISSION_DENIED"}}"#;
+        let error = parse_api_error(403, &HeaderMap::new(), body);
+
+        assert_eq!(error.kind, ApiErrorKind::PermissionDenied);
+        assert_eq!(error.code.as_deref(), Some("403"));
+        assert_eq!(error.error_type.as_deref(), Some("PERMISSION_DENIED"));
+
+        let error = parse_api_error(404, &HeaderMap::new(), r#"{"error": "model not found"}"#);
+        assert_eq!(error.message, "model not found");
+        assert_eq!(error.kind, ApiErrorKind::NotFound);
+    }
+
+    #[test]
+    fn it_keeps_azure_inner_errors() {
+        let body = r#"{"error": {"message": "The response was filtered.", "type": null, "param": "prompt", "code": "content_filter", "status": 400, "innererror": {"code": "ResponsibleAIPolicyViolation"}}}"#;
+        let error = parse_api_error(400, &HeaderMap::new(), body);
+
+        assert_eq!(error.kind, ApiErrorKind::ContentFilter);
+        assert_eq!(error.message, "The response was filtered.");
+        assert_eq!(error.error_type, None);
+        assert_eq!(
+            error.inner_error,
+            Some(serde_json::json!({ "code": "ResponsibleAIPolicyViolation" }))
+        );
+    }
+
+    #[test]
+    fn it_keeps_unstructured_bodies_verbatim() {
+        let error = parse_api_error(502, &HeaderMap::new(), "<html>Bad Gateway</html>");
+
+        assert_eq!(error.message, "<html>Bad Gateway</html>");
+        assert_eq!(error.kind, ApiErrorKind::ServerError);
+        assert_eq!(error.code, None);
+    }
+
+    #[test]
+    fn it_returns_none_without_hints() {
+        assert_eq!(retry_after(&HeaderMap::new()), None);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::utils::errors::ProviderError;
+use bytes::Bytes;
+use futures::stream::{self, StreamExt};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use tokio_stream::Stream;
+
+/// Incremental decoder for newline-delimited JSON.
+///
+/// Bytes can be fed in arbitrary chunks; complete lines are returned as they are
+/// terminated and blank lines are skipped. The lines are not parsed here, so callers
+/// can decide how to treat error objects interleaved with regular values.
+#[derive(Debug, Default)]
+pub struct NdjsonDecoder {
+    line: Vec<u8>,
+}
+
+impl NdjsonDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for &byte in chunk {
+            match byte {
+                b'\n' => lines.extend(self.end_line()),
+                _ => self.line.push(byte),
+            }
+        }
+
+        lines
+    }
+
+    /// Flushes a last line that is not terminated by a newline.
+    pub fn finish(&mut self) -> Option<String> {
+        self.end_line()
+    }
+
+    fn end_line(&mut self) -> Option<String> {
+        let line = String::from_utf8_lossy(&self.line).trim().to_string();
+        self.line.clear();
+
+        if line.is_empty() {
+            None
+        } else {
+            Some(line)
+        }
+    }
+}
+
+/// Turns a raw byte stream (such as `reqwest::Response::bytes_stream`) into a stream
+/// of JSON lines.
+pub fn decode_ndjson_stream<S, E>(stream: S) -> impl Stream<Item = Result<String, ProviderError>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    E: Display,
+{
+    struct State<S> {
+        inner: S,
+        decoder: NdjsonDecoder,
+        pending: VecDeque<String>,
+        done: bool,
+    }
+
+    let state = State {
+        inner: Box::pin(stream),
+        decoder: NdjsonDecoder::new(),
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(line) = state.pending.pop_front() {
+                return Some((Ok(line), state));
+            }
+
+            if state.done {
+                return None;
+            }
+
+            match state.inner.next().await {
+                Some(Ok(chunk)) => state.pending.extend(state.decoder.decode(&chunk)),
+                Some(Err(e)) => {
+                    state.done = true;
+                    return Some((Err(ProviderError::NetworkError(e.to_string())), state));
+                }
+                None => {
+                    state.done = true;
+                    state.pending.extend(state.decoder.finish());
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_buffers_lines_split_across_chunks() {
+        let mut decoder = NdjsonDecoder::new();
+
+        assert!(decoder.decode(b"{\"a\"").is_empty());
+        assert_eq!(
+            decoder.decode(b":1}\n{\"b\":2}\r\n\n{\"c\""),
+            vec!["{\"a\":1}".to_string(), "{\"b\":2}".to_string()]
+        );
+        assert_eq!(decoder.decode(b":3}"), Vec::<String>::new());
+        assert_eq!(decoder.finish(), Some("{\"c\":3}".to_string()));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[tokio::test]
+    async fn it_decodes_a_byte_stream() {
+        let chunks: Vec<Result<Bytes, std::io::Error>> = vec![
+            Ok(Bytes::from_static(b"{\"a\":1}\n{\"b\"")),
+            Ok(Bytes::from_static(b":2}\n")),
+        ];
+
+        let lines: Vec<_> = decode_ndjson_stream(stream::iter(chunks))
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        assert_eq!(lines, vec!["{\"a\":1}", "{\"b\":2}"]);
+    }
+}
@@ -0,0 +1,363 @@
+use crate::utils::errors::{ApiErrorKind, ProviderError};
+use crate::utils::provider_strategy::ProviderStrategy;
+use async_trait::async_trait;
+use std::collections::hash_map::RandomState;
+use std::future::Future;
+use std::hash::{BuildHasher, Hasher};
+use std::pin::Pin;
+use std::time::Duration;
+use tokio_stream::Stream;
+
+/// Controls how failed requests are retried.
+///
+/// Only failures that are safe to repeat are retried: connection errors, which
+/// happen before the request is sent, and responses with status 408, 409, 429 or
+/// 5xx, unless the quota is exhausted. Other network errors are not retried, since
+/// the request may already have been processed. When the server sends a
+/// `Retry-After` (or `x-ratelimit-reset-*`) hint it is used instead of the computed
+/// backoff; a hint longer than the maximum backoff ends the retries.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    multiplier: f64,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            multiplier: 2.0,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_attempts(mut self, value: u32) -> Self {
+        self.max_attempts = value.max(1);
+        self
+    }
+
+    pub fn initial_backoff(mut self, value: Duration) -> Self {
+        self.initial_backoff = value;
+        self
+    }
+
+    pub fn max_backoff(mut self, value: Duration) -> Self {
+        self.max_backoff = value;
+        self
+    }
+
+    pub fn multiplier(mut self, value: f64) -> Self {
+        self.multiplier = value;
+        self
+    }
+
+    pub fn jitter(mut self, value: bool) -> Self {
+        self.jitter = value;
+        self
+    }
+
+    pub fn is_retryable(&self, error: &ProviderError) -> bool {
+        match error {
+            ProviderError::ConnectionError(_) => true,
+            // an exhausted quota is reported as a 429 but won't clear up by retrying
+            ProviderError::ApiError(error) if error.kind == ApiErrorKind::QuotaExceeded => false,
+            ProviderError::ApiError(error) => {
+                matches!(error.status, 408 | 409 | 429) || error.status >= 500
+            }
+            _ => false,
+        }
+    }
+
+    /// Backoff before the given retry, where `retry` starts at 1 for the first retry.
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(i32::MAX as u32) as i32;
+        let backoff = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        let backoff = backoff.min(self.max_backoff.as_secs_f64());
+
+        if self.jitter {
+            // equal jitter: keep half of the delay and randomize the other half
+            Duration::from_secs_f64(backoff / 2.0 + backoff / 2.0 * random_fraction())
+        } else {
+            Duration::from_secs_f64(backoff)
+        }
+    }
+
+    /// Delay before the given retry, or `None` if the server asked to wait longer
+    /// than the maximum backoff.
+    fn delay(&self, retry: u32, error: &ProviderError) -> Option<Duration> {
+        match error {
+            ProviderError::ApiError(error) => match error.retry_after {
+                Some(retry_after) if retry_after > self.max_backoff => None,
+                Some(retry_after) => Some(retry_after),
+                None => Some(self.backoff(retry)),
+            },
+            _ => Some(self.backoff(retry)),
+        }
+    }
+
+    /// Runs `operation` until it succeeds, fails with a non-retryable error or the
+    /// attempts run out. Failures after more than one attempt are wrapped in
+    /// [`ProviderError::RetryError`] so callers can see how many attempts were made.
+    pub async fn run<F, Fut, T>(&self, mut operation: F) -> Result<T, ProviderError>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, ProviderError>>,
+    {
+        let mut attempts = 0;
+
+        loop {
+            attempts += 1;
+
+            let error = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+
+            let delay = self
+                .delay(attempts, &error)
+                .filter(|_| attempts < self.max_attempts && self.is_retryable(&error));
+
+            let Some(delay) = delay else {
+                return Err(if attempts > 1 {
+                    ProviderError::RetryError {
+                        attempts,
+                        last_error: Box::new(error),
+                    }
+                } else {
+                    error
+                });
+            };
+
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+fn random_fraction() -> f64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_nanos())
+            .unwrap_or_default(),
+    );
+
+    (hasher.finish() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Wraps any [`ProviderStrategy`] and retries its requests according to a [`RetryPolicy`].
+///
+/// Streaming requests are only retried while establishing the stream; errors that
+/// occur after the first event has been received are passed through.
+#[derive(Debug, Clone)]
+pub struct Retry<P> {
+    inner: P,
+    policy: RetryPolicy,
+}
+
+impl<P> Retry<P> {
+    pub fn new(inner: P, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+
+    pub fn inner(&self) -> &P {
+        &self.inner
+    }
+
+    pub fn policy(&self) -> &RetryPolicy {
+        &self.policy
+    }
+}
+
+#[async_trait]
+impl<P> ProviderStrategy for Retry<P>
+where
+    P: ProviderStrategy + Send + Sync,
+{
+    type GenerationRequest = P::GenerationRequest;
+    type StreamingRequest = P::StreamingRequest;
+    type GenerationResponse = P::GenerationResponse;
+    type StreamingResponse = P::StreamingResponse;
+
+    fn get_base_url(&self) -> String {
+        self.inner.get_base_url()
+    }
+
+    fn get_api_key(&self) -> String {
+        self.inner.get_api_key()
+    }
+
+    async fn generate(
+        &self,
+        request: &Self::GenerationRequest,
+    ) -> Result<Self::GenerationResponse, ProviderError> {
+        self.policy.run(|| self.inner.generate(request)).await
+    }
+
+    async fn stream(
+        &self,
+        request: &Self::StreamingRequest,
+    ) -> Result<
+        Pin<Box<dyn Stream<Item = Result<Self::StreamingResponse, ProviderError>> + Send>>,
+        ProviderError,
+    > {
+        self.policy.run(|| self.inner.stream(request)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::errors::ApiError;
+    use std::cell::Cell;
+
+    fn api_error(status: u16, retry_after: Option<Duration>) -> ProviderError {
+        let error = ApiError::new(status, "error");
+
+        match retry_after {
+            Some(retry_after) => error.retry_after(retry_after).into(),
+            None => error.into(),
+        }
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(1))
+            .jitter(false)
+    }
+
+    #[test]
+    fn it_retries_only_idempotent_safe_failures() {
+        let policy = RetryPolicy::new();
+
+        for status in [408, 409, 429, 500, 502, 503] {
+            assert!(policy.is_retryable(&api_error(status, None)));
+        }
+
+        for status in [400, 401, 403, 404, 422] {
+            assert!(!policy.is_retryable(&api_error(status, None)));
+        }
+
+        assert!(policy.is_retryable(&ProviderError::ConnectionError("refused".to_string())));
+        assert!(!policy.is_retryable(&ProviderError::NetworkError("reset".to_string())));
+        assert!(!policy.is_retryable(&ProviderError::DeserializationError("bad".to_string())));
+    }
+
+    #[test]
+    fn it_grows_backoff_exponentially_up_to_the_limit() {
+        let policy = RetryPolicy::new()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(350))
+            .jitter(false);
+
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(350));
+        assert_eq!(policy.backoff(40), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn it_keeps_jittered_backoff_within_bounds() {
+        let policy = RetryPolicy::new().initial_backoff(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            let backoff = policy.backoff(1);
+            assert!(backoff >= Duration::from_millis(50) && backoff <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn it_prefers_the_server_retry_hint() {
+        let policy = fast_policy();
+        let error = api_error(429, Some(Duration::from_secs(2)));
+
+        assert_eq!(policy.delay(1, &error), Some(Duration::from_secs(2)));
+    }
+
+    #[tokio::test]
+    async fn it_gives_up_when_the_retry_hint_exceeds_the_maximum_backoff() {
+        let calls = Cell::new(0);
+        let policy = fast_policy().max_backoff(Duration::from_secs(5));
+
+        assert_eq!(
+            policy.delay(1, &api_error(429, Some(Duration::from_secs(60)))),
+            None
+        );
+
+        let result: Result<(), ProviderError> = policy
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err(api_error(429, Some(Duration::from_secs(60)))) }
+            })
+            .await;
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(result.unwrap_err().api_error().unwrap().status, 429);
+    }
+
+    #[tokio::test]
+    async fn it_retries_until_success() {
+        let calls = Cell::new(0);
+
+        let result = fast_policy()
+            .run(|| {
+                calls.set(calls.get() + 1);
+                let attempt = calls.get();
+                async move {
+                    if attempt < 3 {
+                        Err(api_error(503, None))
+                    } else {
+                        Ok(attempt)
+                    }
+                }
+            })
+            .await;
+
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn it_reports_attempts_when_retries_are_exhausted() {
+        let result: Result<(), ProviderError> = fast_policy()
+            .max_attempts(4)
+            .run(|| async { Err(api_error(500, None)) })
+            .await;
+
+        match result {
+            Err(ProviderError::RetryError {
+                attempts,
+                last_error,
+            }) => {
+                assert_eq!(attempts, 4);
+                assert_eq!(last_error.api_error().unwrap().status, 500);
+            }
+            other => panic!("expected a retry error, got {:?}", other),
+        }
+    }
+
+    #[tokio::test]
+    async fn it_does_not_retry_client_errors() {
+        let calls = Cell::new(0);
+
+        let result: Result<(), ProviderError> = fast_policy()
+            .run(|| {
+                calls.set(calls.get() + 1);
+                async { Err(api_error(400, None)) }
+            })
+            .await;
+
+        assert_eq!(calls.get(), 1);
+        assert_eq!(result.unwrap_err().api_error().unwrap().status, 400);
+    }
+}
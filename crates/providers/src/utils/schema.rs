@@ -0,0 +1,269 @@
+use schemars::JsonSchema;
+use serde_json::{Map, Value};
+
+/// Keywords OpenAI rejects in strict mode.
+const UNSUPPORTED_KEYWORDS: &[&str] = &[
+    "$schema",
+    "default",
+    "examples",
+    "format",
+    "pattern",
+    "minLength",
+    "maxLength",
+    "minimum",
+    "maximum",
+    "exclusiveMinimum",
+    "exclusiveMaximum",
+    "multipleOf",
+    "patternProperties",
+    "propertyNames",
+    "unevaluatedProperties",
+    "minProperties",
+    "maxProperties",
+    "unevaluatedItems",
+    "contains",
+    "minContains",
+    "maxContains",
+    "minItems",
+    "maxItems",
+    "uniqueItems",
+];
+
+/// Generates the JSON Schema of `T` as produced by `schemars`.
+pub fn schema_for<T: JsonSchema>() -> Value {
+    schemars::schema_for!(T).into()
+}
+
+/// Generates the JSON Schema of `T` in the shape required by OpenAI strict mode.
+/// Panics if `T` contains a map, see [`to_strict_schema`].
+pub fn strict_schema_for<T: JsonSchema>() -> Value {
+    to_strict_schema(schema_for::<T>())
+}
+
+/// A name for the schema of `T` that is valid as a tool or response format name.
+pub fn schema_name<T: JsonSchema>() -> String {
+    T::schema_name()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '_' | '-' => c,
+            _ => '_',
+        })
+        .collect()
+}
+
+/// The top-level `description` of a schema, which `schemars` takes from the doc
+/// comment of the type.
+pub fn schema_description(schema: &Value) -> Option<String> {
+    schema
+        .get("description")
+        .and_then(|description| description.as_str())
+        .map(str::to_string)
+}
+
+/// Rewrites a schema to follow the OpenAI strict mode rules.
+///
+/// Every object gets `additionalProperties: false` and lists all of its properties
+/// as required, so optional values have to be modelled as `Option<T>`, which
+/// `schemars` already describes as a union with `null`. `oneOf` becomes `anyOf`,
+/// `const` becomes a single-value `enum` and keywords strict mode does not support
+/// are dropped.
+///
+/// # Panics
+///
+/// Panics on maps such as `HashMap` or `BTreeMap`, i.e. objects whose
+/// `additionalProperties` is a schema, since strict mode cannot express them.
+pub fn to_strict_schema(schema: Value) -> Value {
+    match schema {
+        Value::Object(map) => Value::Object(strict_object(map)),
+        other => other,
+    }
+}
+
+fn strict_object(mut schema: Map<String, Value>) -> Map<String, Value> {
+    for keyword in UNSUPPORTED_KEYWORDS {
+        schema.remove(*keyword);
+    }
+
+    if let Some(one_of) = schema.remove("oneOf") {
+        match schema.get_mut("anyOf") {
+            Some(Value::Array(any_of)) => {
+                if let Value::Array(one_of) = one_of {
+                    any_of.extend(one_of);
+                }
+            }
+            _ => {
+                schema.insert("anyOf".to_string(), one_of);
+            }
+        }
+    }
+
+    if let Some(value) = schema.remove("const") {
+        schema.insert("enum".to_string(), Value::Array(vec![value]));
+    }
+
+    for keyword in ["anyOf", "allOf", "prefixItems"] {
+        if let Some(Value::Array(schemas)) = schema.remove(keyword) {
+            let schemas = schemas.into_iter().map(to_strict_schema).collect();
+            schema.insert(keyword.to_string(), Value::Array(schemas));
+        }
+    }
+
+    for keyword in ["items", "not"] {
+        if let Some(value) = schema.remove(keyword) {
+            schema.insert(keyword.to_string(), to_strict_schema(value));
+        }
+    }
+
+    for keyword in ["$defs", "definitions"] {
+        if let Some(Value::Object(definitions)) = schema.remove(keyword) {
+            let definitions = definitions
+                .into_iter()
+                .map(|(name, definition)| (name, to_strict_schema(definition)))
+                .collect();
+            schema.insert(keyword.to_string(), Value::Object(definitions));
+        }
+    }
+
+    if is_object(&schema) {
+        if let Some(additional) = schema.get("additionalProperties") {
+            if *additional != Value::Bool(false) {
+                panic!(
+                    "maps cannot be expressed in OpenAI strict mode, use a struct with \
+                     fixed fields or a list of key-value pairs instead (found \
+                     `additionalProperties: {}`)",
+                    additional
+                );
+            }
+        }
+
+        let properties = match schema.remove("properties") {
+            Some(Value::Object(properties)) => properties,
+            _ => Map::new(),
+        };
+
+        let names: Vec<Value> = properties.keys().cloned().map(Value::String).collect();
+        let properties: Map<String, Value> = properties
+            .into_iter()
+            .map(|(name, property)| (name, to_strict_schema(property)))
+            .collect();
+
+        schema.insert("properties".to_string(), Value::Object(properties));
+        schema.insert("required".to_string(), Value::Array(names));
+        schema.insert("additionalProperties".to_string(), Value::Bool(false));
+    }
+
+    schema
+}
+
+fn is_object(schema: &Map<String, Value>) -> bool {
+    schema.contains_key("properties")
+        || match schema.get("type") {
+            Some(Value::String(kind)) => kind == "object",
+            Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "object"),
+            _ => false,
+        }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+    use serde_json::json;
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    struct Location {
+        city: String,
+        country: Option<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    #[serde(rename_all = "snake_case")]
+    enum Unit {
+        Celsius,
+        Fahrenheit,
+    }
+
+    /// Weather lookup arguments.
+    #[allow(dead_code)]
+    #[derive(Deserialize, JsonSchema)]
+    struct WeatherArgs {
+        location: Location,
+        #[serde(default)]
+        days: u8,
+        unit: Option<Unit>,
+    }
+
+    #[test]
+    fn it_makes_every_property_required() {
+        let schema = strict_schema_for::<Location>();
+
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(schema["required"], json!(["city", "country"]));
+        assert_eq!(
+            schema["properties"]["country"]["type"],
+            json!(["string", "null"])
+        );
+        assert!(schema.get("$schema").is_none());
+    }
+
+    #[test]
+    fn it_applies_the_rules_to_nested_schemas() {
+        let schema = strict_schema_for::<WeatherArgs>();
+
+        assert_eq!(schema["required"], json!(["days", "location", "unit"]));
+        assert_eq!(schema["properties"]["days"], json!({ "type": "integer" }));
+        assert_eq!(
+            schema["properties"]["unit"]["anyOf"][1],
+            json!({ "type": "null" })
+        );
+
+        let location = &schema["$defs"]["Location"];
+        assert_eq!(location["additionalProperties"], json!(false));
+        assert_eq!(location["required"], json!(["city", "country"]));
+    }
+
+    #[test]
+    fn it_rewrites_unsupported_combinators() {
+        let schema = to_strict_schema(json!({
+            "oneOf": [
+                { "const": "a" },
+                { "type": "object", "properties": { "b": { "type": "string", "format": "uri" } } }
+            ]
+        }));
+
+        assert_eq!(
+            schema,
+            json!({
+                "anyOf": [
+                    { "enum": ["a"] },
+                    {
+                        "type": "object",
+                        "properties": { "b": { "type": "string" } },
+                        "required": ["b"],
+                        "additionalProperties": false
+                    }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "maps cannot be expressed in OpenAI strict mode")]
+    fn it_rejects_maps() {
+        #[allow(dead_code)]
+        #[derive(JsonSchema)]
+        struct Scores {
+            by_player: std::collections::HashMap<String, u32>,
+        }
+
+        strict_schema_for::<Scores>();
+    }
+
+    #[test]
+    fn it_sanitizes_schema_names() {
+        assert_eq!(schema_name::<WeatherArgs>(), "WeatherArgs");
+        assert_eq!(schema_name::<Vec<Location>>(), "Array_of_Location");
+    }
+}
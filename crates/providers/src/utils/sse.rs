@@ -0,0 +1,302 @@
+use crate::utils::errors::ProviderError;
+use bytes::Bytes;
+use futures::stream::{self, StreamExt};
+use std::collections::VecDeque;
+use std::fmt::Display;
+use tokio_stream::Stream;
+
+/// A single dispatched Server-Sent Event.
+///
+/// `data` holds every `data:` line of the event joined with `\n`, and `id` holds the
+/// last event id seen on the stream at the time the event was dispatched.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SseEvent {
+    pub event: Option<String>,
+    pub data: String,
+    pub id: Option<String>,
+    pub retry: Option<u64>,
+}
+
+/// Incremental decoder for the `text/event-stream` format.
+///
+/// Bytes can be fed in arbitrary chunks; the decoder buffers partial lines and only
+/// yields events once their terminating blank line has been received.
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    line: Vec<u8>,
+    skip_line_feed: bool,
+    event: Option<String>,
+    data: Option<String>,
+    retry: Option<u64>,
+    last_event_id: Option<String>,
+}
+
+impl SseDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    pub fn decode(&mut self, chunk: &[u8]) -> Vec<SseEvent> {
+        let mut events = Vec::new();
+
+        for &byte in chunk {
+            if self.skip_line_feed {
+                self.skip_line_feed = false;
+                if byte == b'\n' {
+                    continue;
+                }
+            }
+
+            match byte {
+                b'\r' => {
+                    self.skip_line_feed = true;
+                    events.extend(self.end_line());
+                }
+                b'\n' => events.extend(self.end_line()),
+                _ => self.line.push(byte),
+            }
+        }
+
+        events
+    }
+
+    /// Flushes whatever is left once the underlying stream has ended.
+    ///
+    /// Servers are expected to terminate the last event with a blank line, but a
+    /// trailing event that only misses its terminator is still dispatched.
+    pub fn finish(&mut self) -> Option<SseEvent> {
+        self.skip_line_feed = false;
+
+        if !self.line.is_empty() {
+            self.end_line();
+        }
+
+        self.dispatch()
+    }
+
+    fn end_line(&mut self) -> Option<SseEvent> {
+        if self.line.is_empty() {
+            return self.dispatch();
+        }
+
+        let line = String::from_utf8_lossy(&self.line).into_owned();
+        self.line.clear();
+        self.process_line(&line);
+
+        None
+    }
+
+    fn process_line(&mut self, line: &str) {
+        if line.starts_with(':') {
+            return;
+        }
+
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => match self.data {
+                Some(ref mut data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()),
+            "retry" if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) => {
+                self.retry = value.parse().ok();
+            }
+            _ => {}
+        }
+    }
+
+    fn dispatch(&mut self) -> Option<SseEvent> {
+        let event = self.event.take();
+        let retry = self.retry.take();
+
+        self.data.take().map(|data| SseEvent {
+            event,
+            data,
+            id: self.last_event_id.clone(),
+            retry,
+        })
+    }
+}
+
+/// Turns a raw byte stream (such as `reqwest::Response::bytes_stream`) into a stream
+/// of decoded Server-Sent Events.
+pub fn decode_sse_stream<S, E>(stream: S) -> impl Stream<Item = Result<SseEvent, ProviderError>>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    E: Display,
+{
+    struct State<S> {
+        inner: S,
+        decoder: SseDecoder,
+        pending: VecDeque<SseEvent>,
+        done: bool,
+    }
+
+    let state = State {
+        inner: Box::pin(stream),
+        decoder: SseDecoder::new(),
+        pending: VecDeque::new(),
+        done: false,
+    };
+
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                return Some((Ok(event), state));
+            }
+
+            if state.done {
+                return None;
+            }
+
+            match state.inner.next().await {
+                Some(Ok(chunk)) => state.pending.extend(state.decoder.decode(&chunk)),
+                Some(Err(e)) => {
+                    state.done = true;
+                    return Some((Err(ProviderError::NetworkError(e.to_string())), state));
+                }
+                None => {
+                    state.done = true;
+                    state.pending.extend(state.decoder.finish());
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(event: Option<&str>, data: &str) -> SseEvent {
+        SseEvent {
+            event: event.map(|value| value.to_string()),
+            data: data.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn it_decodes_a_complete_event() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b"event: response.created\ndata: {\"a\":1}\n\n");
+
+        assert_eq!(events, vec![event(Some("response.created"), "{\"a\":1}")]);
+    }
+
+    #[test]
+    fn it_buffers_events_split_across_chunks() {
+        let mut decoder = SseDecoder::new();
+
+        assert!(decoder.decode(b"event: resp").is_empty());
+        assert!(decoder.decode(b"onse.created\ndata: {\"a\"").is_empty());
+        assert!(decoder.decode(b":1}\n").is_empty());
+
+        let events = decoder.decode(b"\n");
+        assert_eq!(events, vec![event(Some("response.created"), "{\"a\":1}")]);
+    }
+
+    #[test]
+    fn it_decodes_several_events_in_one_chunk() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b"data: first\n\ndata: second\n\nevent: third\n");
+
+        assert_eq!(events, vec![event(None, "first"), event(None, "second")]);
+    }
+
+    #[test]
+    fn it_joins_multi_line_data() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b"data: line one\ndata:line two\ndata\n\n");
+
+        assert_eq!(events, vec![event(None, "line one\nline two\n")]);
+    }
+
+    #[test]
+    fn it_handles_crlf_and_cr_line_endings() {
+        let mut decoder = SseDecoder::new();
+
+        let mut events = decoder.decode(b"data: crlf\r");
+        events.extend(decoder.decode(b"\n\r\ndata: cr\r\r"));
+
+        assert_eq!(events, vec![event(None, "crlf"), event(None, "cr")]);
+    }
+
+    #[test]
+    fn it_ignores_comments_and_unknown_fields() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b": keep-alive\n\nfoo: bar\ndata: payload\n\n");
+
+        assert_eq!(events, vec![event(None, "payload")]);
+    }
+
+    #[test]
+    fn it_tracks_id_and_retry_fields() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b"id: 42\nretry: 3000\ndata: a\n\nretry: soon\ndata: b\n\n");
+
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: None,
+                    data: "a".to_string(),
+                    id: Some("42".to_string()),
+                    retry: Some(3000),
+                },
+                SseEvent {
+                    event: None,
+                    data: "b".to_string(),
+                    id: Some("42".to_string()),
+                    retry: None,
+                },
+            ]
+        );
+        assert_eq!(decoder.last_event_id(), Some("42"));
+    }
+
+    #[test]
+    fn it_does_not_dispatch_events_without_data() {
+        let mut decoder = SseDecoder::new();
+        let events = decoder.decode(b"event: ping\n\ndata: after\n\n");
+
+        assert_eq!(events, vec![event(None, "after")]);
+    }
+
+    #[test]
+    fn it_flushes_an_unterminated_event_on_finish() {
+        let mut decoder = SseDecoder::new();
+
+        assert!(decoder.decode(b"data: [DONE]").is_empty());
+        assert_eq!(decoder.finish(), Some(event(None, "[DONE]")));
+        assert_eq!(decoder.finish(), None);
+    }
+
+    #[tokio::test]
+    async fn it_decodes_a_byte_stream() {
+        let chunks: Vec<Result<Bytes, std::io::Error>> = vec![
+            Ok(Bytes::from_static(b"event: a\ndata: 1\n")),
+            Ok(Bytes::from_static(b"\nevent: b\nda")),
+            Ok(Bytes::from_static(b"ta: 2\n\n")),
+        ];
+
+        let events: Vec<SseEvent> = decode_sse_stream(stream::iter(chunks))
+            .map(|event| event.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(events, vec![event(Some("a"), "1"), event(Some("b"), "2")]);
+    }
+}
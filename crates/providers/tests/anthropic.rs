@@ -0,0 +1,5 @@
+mod anthropic {
+    mod client;
+    mod fixtures;
+    mod streaming;
+}
@@ -0,0 +1,151 @@
+use super::fixtures::message_json;
+use ai_providers::{
+    anthropic::content::{CacheControl, ContentBlock},
+    anthropic::types::{AnthropicMessage, AnthropicTool, StopReason},
+    utils::errors::ApiErrorKind,
+    AnthropicProvider, AnthropicRequest, ProviderStrategy,
+};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, header, headers, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> AnthropicRequest {
+    AnthropicRequest::new("claude-sonnet-4-5", 1024).add_message(AnthropicMessage::user("Hello"))
+}
+
+#[tokio::test]
+async fn it_sends_requests_with_anthropic_headers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .and(header("x-api-key", "test-key"))
+        .and(header("anthropic-version", "2023-06-01"))
+        .and(headers(
+            "anthropic-beta",
+            vec!["interleaved-thinking-2025-05-14", "extended-cache-ttl-2025-04-11"],
+        ))
+        .and(header("user-agent", "ai-sdk-tests/1.0"))
+        .and(body_partial_json(json!({
+            "model": "claude-sonnet-4-5",
+            "max_tokens": 1024,
+            "system": [{ "type": "text", "text": "Be brief.", "cache_control": { "type": "ephemeral", "ttl": "1h" } }],
+            "messages": [{ "role": "user", "content": "Hello" }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(message_json(
+            json!([{ "type": "text", "text": "Hi there!" }]),
+            "end_turn",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AnthropicProvider::builder("test-key")
+        .base_url(format!("{}/v1/", server.uri()))
+        .beta("interleaved-thinking-2025-05-14")
+        .beta("extended-cache-ttl-2025-04-11")
+        .user_agent("ai-sdk-tests/1.0")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    let request = request().system(vec![
+        ContentBlock::text("Be brief.").cache_control(CacheControl::ephemeral().ttl("1h"))
+    ]);
+    let response = provider.generate(&request).await.unwrap();
+
+    assert_eq!(response.text(), "Hi there!");
+    assert_eq!(response.stop_reason, Some(StopReason::EndTurn));
+    assert_eq!(response.usage.cache_read_input_tokens, Some(20));
+}
+
+#[tokio::test]
+async fn it_round_trips_tool_use_and_thinking_blocks() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(body_partial_json(json!({
+            "thinking": { "type": "enabled", "budget_tokens": 512 },
+            "tools": [{ "name": "get_weather", "input_schema": { "type": "object" } }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(message_json(
+            json!([
+                { "type": "thinking", "thinking": "I should call the tool.", "signature": "sig_1" },
+                { "type": "tool_use", "id": "toolu_1", "name": "get_weather", "input": { "city": "Paris" } }
+            ]),
+            "tool_use",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AnthropicProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = request().thinking(512).add_tool(AnthropicTool::new(
+        "get_weather",
+        json!({ "type": "object", "properties": { "city": { "type": "string" } } }),
+    ));
+    let response = provider.generate(&request).await.unwrap();
+
+    assert_eq!(response.stop_reason, Some(StopReason::ToolUse));
+    let tool_uses: Vec<_> = response.tool_uses().collect();
+    assert_eq!(
+        tool_uses,
+        vec![&ContentBlock::tool_use(
+            "toolu_1",
+            "get_weather",
+            json!({ "city": "Paris" })
+        )]
+    );
+
+    // thinking blocks must be passed back unchanged alongside the tool result
+    let follow_up = request
+        .add_message(AnthropicMessage::assistant(response.content.clone()))
+        .add_message(AnthropicMessage::user(vec![ContentBlock::tool_result(
+            "toolu_1", "18°C",
+        )]));
+    let body = serde_json::to_value(&follow_up).unwrap();
+
+    assert_eq!(body["messages"][1]["content"][0]["signature"], "sig_1");
+    assert_eq!(
+        body["messages"][2]["content"][0],
+        json!({ "type": "tool_result", "tool_use_id": "toolu_1", "content": "18°C" })
+    );
+}
+
+#[tokio::test]
+async fn it_parses_anthropic_api_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .respond_with(
+            ResponseTemplate::new(529)
+                .insert_header("request-id", "req_abc")
+                .set_body_json(json!({
+                    "type": "error",
+                    "error": { "type": "overloaded_error", "message": "Overloaded" }
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = AnthropicProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.status, 529);
+    assert_eq!(api_error.kind, ApiErrorKind::ServerError);
+    assert_eq!(api_error.message, "Overloaded");
+    assert_eq!(api_error.error_type.as_deref(), Some("overloaded_error"));
+    assert_eq!(api_error.request_id.as_deref(), Some("req_abc"));
+}
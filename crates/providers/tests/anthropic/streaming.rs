@@ -0,0 +1,86 @@
+use super::fixtures::{message_json, sse};
+use ai_providers::{
+    anthropic::content::ContentBlock,
+    anthropic::streaming::{AnthropicStreamingEvent, ContentDelta},
+    anthropic::types::{AnthropicMessage, StopReason},
+    AnthropicProvider, AnthropicRequest, ProviderStrategy,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_streams_the_message_event_family() {
+    let server = MockServer::start().await;
+
+    let body = sse(&[
+        json!({ "type": "message_start", "message": message_json(json!([]), "end_turn") }),
+        json!({ "type": "content_block_start", "index": 0, "content_block": { "type": "thinking", "thinking": "", "signature": "" } }),
+        json!({ "type": "content_block_delta", "index": 0, "delta": { "type": "thinking_delta", "thinking": "Hmm." } }),
+        json!({ "type": "content_block_delta", "index": 0, "delta": { "type": "signature_delta", "signature": "sig_1" } }),
+        json!({ "type": "content_block_stop", "index": 0 }),
+        json!({ "type": "ping" }),
+        json!({ "type": "content_block_start", "index": 1, "content_block": { "type": "text", "text": "" } }),
+        json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "text_delta", "text": "Hi" } }),
+        json!({ "type": "content_block_delta", "index": 1, "delta": { "type": "text_delta", "text": " there!" } }),
+        json!({ "type": "content_block_stop", "index": 1 }),
+        json!({ "type": "message_delta", "delta": { "stop_reason": "end_turn", "stop_sequence": null }, "usage": { "output_tokens": 15 } }),
+        json!({ "type": "message_stop" }),
+    ]);
+
+    Mock::given(method("POST"))
+        .and(path("/messages"))
+        .and(header("accept", "text/event-stream"))
+        .and(body_partial_json(json!({ "stream": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let provider = AnthropicProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = AnthropicRequest::new("claude-sonnet-4-5", 1024)
+        .add_message(AnthropicMessage::user("Hello"))
+        .thinking(512);
+    let events: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert!(matches!(
+        &events[0],
+        AnthropicStreamingEvent::MessageStart { message } if message.id == "msg_123"
+    ));
+    assert!(matches!(
+        &events[1],
+        AnthropicStreamingEvent::ContentBlockStart {
+            index: 0,
+            content_block: ContentBlock::Thinking { .. }
+        }
+    ));
+
+    let text: String = events
+        .iter()
+        .filter_map(|event| match event {
+            AnthropicStreamingEvent::ContentBlockDelta {
+                delta: ContentDelta::TextDelta { text },
+                ..
+            } => Some(text.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "Hi there!");
+
+    assert!(matches!(
+        &events[10],
+        AnthropicStreamingEvent::MessageDelta { delta, usage }
+            if delta.stop_reason == Some(StopReason::EndTurn) && usage.output_tokens == 15
+    ));
+    assert_eq!(events.last(), Some(&AnthropicStreamingEvent::MessageStop));
+}
@@ -0,0 +1,4 @@
+mod azure {
+    mod client;
+    mod streaming;
+}
@@ -0,0 +1,199 @@
+use ai_providers::{
+    azure::constants::{resource_endpoint, AZURE_OPENAI_API_VERSION},
+    azure::content_filter::{ContentFilterResults, ContentFilterSeverity},
+    openai::request::input::Input,
+    utils::errors::{ApiErrorKind, ProviderError},
+    AzureOpenAIProvider, OpenAIRequest, ProviderStrategy,
+};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> OpenAIRequest {
+    OpenAIRequest::default().input(Input::from_text("Hello"))
+}
+
+fn response_json() -> Value {
+    json!({
+        "id": "resp_123",
+        "object": "response",
+        "created_at": 1741290958,
+        "status": "completed",
+        "error": null,
+        "incomplete_details": null,
+        "instructions": null,
+        "max_output_tokens": null,
+        "model": "gpt-4o-mini",
+        "output": [{
+            "id": "msg_123",
+            "type": "message",
+            "status": "completed",
+            "role": "assistant",
+            "content": [{ "type": "output_text", "text": "Hi there!", "annotations": [] }]
+        }],
+        "parallel_tool_calls": true,
+        "previous_response_id": null,
+        "reasoning": { "effort": null, "summary": null },
+        "store": true,
+        "temperature": 1.0,
+        "text": { "format": { "type": "text" } },
+        "tool_choice": "auto",
+        "tools": [],
+        "top_p": 1.0,
+        "truncation": "disabled",
+        "usage": {
+            "input_tokens": 37,
+            "input_tokens_details": { "cached_tokens": 0 },
+            "output_tokens": 11,
+            "output_tokens_details": { "reasoning_tokens": 0 },
+            "total_tokens": 48
+        },
+        "user": null,
+        "metadata": {},
+        "prompt_filter_results": [{
+            "prompt_index": 0,
+            "content_filter_results": {
+                "hate": { "filtered": false, "severity": "safe" },
+                "jailbreak": { "filtered": false, "detected": false }
+            }
+        }],
+        "content_filter_results": {
+            "violence": { "filtered": false, "severity": "low" }
+        }
+    })
+}
+
+#[test]
+fn it_builds_resource_endpoints() {
+    assert_eq!(
+        resource_endpoint("contoso"),
+        "https://contoso.openai.azure.com"
+    );
+}
+
+#[test]
+fn it_builds_providers_for_a_resource() {
+    let provider = AzureOpenAIProvider::for_resource("contoso", "my-gpt-4o-mini")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        provider.get_base_url(),
+        "https://contoso.openai.azure.com/openai"
+    );
+    assert_eq!(provider.deployment(), "my-gpt-4o-mini");
+}
+
+#[tokio::test]
+async fn it_sends_deployment_requests_with_an_api_key() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/openai/responses"))
+        .and(query_param("api-version", AZURE_OPENAI_API_VERSION))
+        .and(header("api-key", "test-key"))
+        .and(body_partial_json(json!({
+            "model": "my-gpt-4o-mini",
+            "input": "Hello"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AzureOpenAIProvider::builder(format!("{}/", server.uri()), "my-gpt-4o-mini")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    assert_eq!(provider.get_base_url(), format!("{}/openai", server.uri()));
+
+    let response = provider.generate(&request()).await.unwrap();
+
+    assert_eq!(response.response.id, "resp_123");
+    assert!(!response.prompt_filter_results[0]
+        .content_filter_results
+        .is_filtered());
+    assert_eq!(
+        response
+            .content_filter_results
+            .unwrap()
+            .violence
+            .unwrap()
+            .severity,
+        Some(ContentFilterSeverity::Low)
+    );
+}
+
+#[tokio::test]
+async fn it_authenticates_with_entra_tokens() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/openai/responses"))
+        .and(query_param("api-version", "2025-03-01-preview"))
+        .and(header("authorization", "Bearer entra-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AzureOpenAIProvider::builder(server.uri(), "my-gpt-4o-mini")
+        .entra_token("entra-token")
+        .api_version("2025-03-01-preview")
+        .build()
+        .unwrap();
+
+    provider.generate(&request()).await.unwrap();
+}
+
+#[test]
+fn it_requires_credentials() {
+    let result = AzureOpenAIProvider::builder("https://contoso.openai.azure.com", "gpt").build();
+
+    assert!(matches!(result, Err(ProviderError::ValidationError(_))));
+}
+
+#[tokio::test]
+async fn it_exposes_content_filter_results_on_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/openai/responses"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": {
+                "message": "The response was filtered due to the prompt triggering Azure OpenAI's content management policy.",
+                "type": null,
+                "param": "prompt",
+                "code": "content_filter",
+                "status": 400,
+                "innererror": {
+                    "code": "ResponsibleAIPolicyViolation",
+                    "content_filter_result": {
+                        "hate": { "filtered": false, "severity": "safe" },
+                        "self_harm": { "filtered": false, "severity": "safe" },
+                        "sexual": { "filtered": false, "severity": "safe" },
+                        "violence": { "filtered": true, "severity": "medium" }
+                    }
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = AzureOpenAIProvider::builder(server.uri(), "my-gpt-4o-mini")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.kind, ApiErrorKind::ContentFilter);
+    assert_eq!(api_error.param.as_deref(), Some("prompt"));
+
+    let results = ContentFilterResults::from_api_error(api_error).unwrap();
+    assert!(results.is_filtered());
+    assert!(results.violence.unwrap().filtered);
+}
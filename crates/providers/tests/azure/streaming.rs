@@ -0,0 +1,125 @@
+use ai_providers::{
+    azure::content_filter::ContentFilterSeverity, openai::request::input::Input,
+    openai::response::events::streaming::OpenAIStreamingEvent, AzureOpenAIProvider, OpenAIRequest,
+    ProviderStrategy,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const RESPONSE_STREAM: &str = r#"event: response.output_text.delta
+data: {"type":"response.output_text.delta","sequence_number":3,"item_id":"msg_123","output_index":0,"content_index":0,"delta":"Hi"}
+
+event: response.output_text.delta
+data: {"type":"response.output_text.delta","sequence_number":4,"item_id":"msg_123","output_index":0,"content_index":0,"delta":" there!"}
+
+event: response.output_text.done
+data: {"type":"response.output_text.done","sequence_number":5,"item_id":"msg_123","output_index":0,"content_index":0,"text":"Hi there!"}
+
+"#;
+
+#[tokio::test]
+async fn it_streams_events_from_a_deployment() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/openai/responses"))
+        .and(query_param("api-version", "2025-04-01-preview"))
+        .and(header("api-key", "test-key"))
+        .and(body_partial_json(json!({
+            "model": "my-gpt-4o-mini",
+            "stream": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(RESPONSE_STREAM, "text/event-stream"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AzureOpenAIProvider::builder(server.uri(), "my-gpt-4o-mini")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    let request = OpenAIRequest::default().input(Input::from_text("Hello"));
+    let events: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let text: String = events
+        .iter()
+        .filter_map(|event| match &event.event {
+            OpenAIStreamingEvent::OutputTextDelta { delta, .. } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(events.len(), 3);
+    assert_eq!(text, "Hi there!");
+}
+
+#[tokio::test]
+async fn it_passes_through_content_filter_results_on_events() {
+    let server = MockServer::start().await;
+
+    let body = [
+        json!({
+            "type": "response.output_text.delta", "sequence_number": 3, "item_id": "msg_123",
+            "output_index": 0, "content_index": 0, "delta": "Hi",
+            "prompt_filter_results": [{
+                "prompt_index": 0,
+                "content_filter_results": { "hate": { "filtered": false, "severity": "safe" } }
+            }]
+        }),
+        json!({
+            "type": "response.output_text.delta", "sequence_number": 4, "item_id": "msg_123",
+            "output_index": 0, "content_index": 0, "delta": "",
+            "content_filter_results": { "violence": { "filtered": true, "severity": "high" } }
+        }),
+    ]
+    .iter()
+    .map(|event| {
+        format!(
+            "event: {}\ndata: {}\n\n",
+            event["type"].as_str().unwrap(),
+            event
+        )
+    })
+    .collect::<String>();
+
+    Mock::given(method("POST"))
+        .and(path("/openai/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = AzureOpenAIProvider::builder(server.uri(), "my-gpt-4o-mini")
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    let request = OpenAIRequest::default().input(Input::from_text("Hello"));
+    let events: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].prompt_filter_results.len(), 1);
+    assert!(events[0].content_filter_results.is_none());
+
+    let results = events[1].content_filter_results.as_ref().unwrap();
+    assert!(results.is_filtered());
+    assert_eq!(
+        results.violence.as_ref().unwrap().severity,
+        Some(ContentFilterSeverity::High)
+    );
+}
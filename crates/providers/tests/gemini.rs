@@ -0,0 +1,5 @@
+mod gemini {
+    mod client;
+    mod fixtures;
+    mod streaming;
+}
@@ -0,0 +1,205 @@
+use super::fixtures::response_json;
+use ai_providers::{
+    gemini::client::GeminiAuthScheme,
+    gemini::content::{Content, Part},
+    gemini::types::{
+        FinishReason, FunctionDeclaration, GenerationConfig, HarmBlockThreshold, HarmCategory,
+        SafetySetting,
+    },
+    utils::errors::{ApiErrorKind, ProviderError},
+    GeminiProvider, GeminiRequest, ProviderStrategy,
+};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{
+    body_partial_json, header, header_exists, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> GeminiRequest {
+    GeminiRequest::new(
+        "gemini-2.5-flash",
+        vec![Content::user(vec![Part::text("Hello")])],
+    )
+}
+
+#[tokio::test]
+async fn it_authenticates_with_the_api_key_header() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1beta/models/gemini-2.5-flash:generateContent"))
+        .and(header("x-goog-api-key", "test-key"))
+        .and(query_param_is_missing("key"))
+        .and(header("user-agent", "ai-sdk-tests/1.0"))
+        .and(body_partial_json(json!({
+            "contents": [{ "role": "user", "parts": [{ "text": "Hello" }] }],
+            "systemInstruction": { "parts": [{ "text": "Be brief." }] },
+            "safetySettings": [
+                { "category": "HARM_CATEGORY_DANGEROUS_CONTENT", "threshold": "BLOCK_NONE" }
+            ],
+            "generationConfig": {
+                "responseMimeType": "application/json",
+                "responseSchema": { "type": "OBJECT" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json(
+            json!([{ "text": "{\"greeting\":\"Hi\"}" }]),
+            "STOP",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = GeminiProvider::builder("test-key")
+        .base_url(format!("{}/v1beta/", server.uri()))
+        .user_agent("ai-sdk-tests/1.0")
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    let request = request()
+        .system_instruction("Be brief.")
+        .add_safety_setting(SafetySetting::new(
+            HarmCategory::HarmCategoryDangerousContent,
+            HarmBlockThreshold::BlockNone,
+        ))
+        .generation_config(GenerationConfig::new().response_schema(json!({ "type": "OBJECT" })));
+    let response = provider.generate(&request).await.unwrap();
+
+    assert_eq!(response.text(), "{\"greeting\":\"Hi\"}");
+    assert_eq!(
+        response.candidates[0].finish_reason,
+        Some(FinishReason::Stop)
+    );
+    assert_eq!(response.usage_metadata.unwrap().total_token_count, 17);
+}
+
+#[tokio::test]
+async fn it_authenticates_with_the_key_query_parameter() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .and(query_param("key", "test-key"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response_json(json!([{ "text": "Hi" }]), "STOP")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(header_exists("x-goog-api-key"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = GeminiProvider::builder("test-key")
+        .base_url(server.uri())
+        .auth_scheme(GeminiAuthScheme::Query)
+        .build()
+        .unwrap();
+
+    let response = provider.generate(&request()).await.unwrap();
+
+    assert_eq!(response.text(), "Hi");
+}
+
+#[tokio::test]
+async fn it_returns_function_calls() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .and(body_partial_json(json!({
+            "tools": [{ "functionDeclarations": [{ "name": "get_weather" }] }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json(
+            json!([{ "functionCall": { "name": "get_weather", "args": { "city": "Paris" } } }]),
+            "STOP",
+        )))
+        .mount(&server)
+        .await;
+
+    let provider = GeminiProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = GeminiRequest::new(
+        "models/gemini-2.5-flash",
+        vec![Content::user(vec![Part::text("Weather in Paris?")])],
+    )
+    .add_function(FunctionDeclaration::new("get_weather").parameters(json!({
+        "type": "OBJECT",
+        "properties": { "city": { "type": "STRING" } }
+    })));
+    let response = provider.generate(&request).await.unwrap();
+
+    let calls = response.function_calls();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].name, "get_weather");
+    assert_eq!(calls[0].args, json!({ "city": "Paris" }));
+}
+
+#[tokio::test]
+async fn it_parses_google_api_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:generateContent"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": {
+                "code": 400,
+                "message": "API key not valid. Please pass a valid API key.",
+                "status": "INVALID_ARGUMENT"
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = GeminiProvider::builder("wrong-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.status, 400);
+    assert_eq!(api_error.kind, ApiErrorKind::InvalidRequest);
+    assert_eq!(
+        api_error.message,
+        "API key not valid. Please pass a valid API key."
+    );
+    assert_eq!(api_error.error_type.as_deref(), Some("INVALID_ARGUMENT"));
+}
+
+#[tokio::test]
+async fn it_keeps_the_api_key_out_of_network_errors() {
+    let provider = GeminiProvider::builder("secret-key")
+        .base_url("http://127.0.0.1:1")
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+
+    assert!(matches!(error, ProviderError::ConnectionError(_)));
+    assert!(!error.to_string().contains("secret-key"));
+}
+
+#[tokio::test]
+async fn it_keeps_the_query_api_key_out_of_network_errors() {
+    let provider = GeminiProvider::builder("secret-key")
+        .base_url("http://127.0.0.1:1")
+        .auth_scheme(GeminiAuthScheme::Query)
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+
+    assert!(matches!(error, ProviderError::ConnectionError(_)));
+    assert!(!error.to_string().contains("secret-key"));
+}
@@ -0,0 +1,21 @@
+use serde_json::{json, Value};
+
+pub fn response_json(parts: Value, finish_reason: &str) -> Value {
+    json!({
+        "candidates": [{
+            "content": { "role": "model", "parts": parts },
+            "finishReason": finish_reason,
+            "index": 0,
+            "safetyRatings": [
+                { "category": "HARM_CATEGORY_HARASSMENT", "probability": "NEGLIGIBLE" }
+            ]
+        }],
+        "usageMetadata": {
+            "promptTokenCount": 12,
+            "candidatesTokenCount": 5,
+            "totalTokenCount": 17
+        },
+        "modelVersion": "gemini-2.5-flash",
+        "responseId": "resp_123"
+    })
+}
@@ -0,0 +1,56 @@
+use super::fixtures::response_json;
+use ai_providers::{
+    gemini::content::{Content, Part},
+    gemini::types::FinishReason,
+    GeminiProvider, GeminiRequest, ProviderStrategy,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_streams_generate_content_chunks() {
+    let server = MockServer::start().await;
+
+    let mut first = response_json(json!([{ "text": "Hi" }]), "STOP");
+    first["candidates"][0]
+        .as_object_mut()
+        .unwrap()
+        .remove("finishReason");
+    let second = response_json(json!([{ "text": " there!" }]), "STOP");
+    let body = format!("data: {}\r\n\r\ndata: {}\r\n\r\n", first, second);
+
+    Mock::given(method("POST"))
+        .and(path("/models/gemini-2.5-flash:streamGenerateContent"))
+        .and(query_param("alt", "sse"))
+        .and(header("x-goog-api-key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let provider = GeminiProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = GeminiRequest::new(
+        "gemini-2.5-flash",
+        vec![Content::user(vec![Part::text("Hello")])],
+    );
+    let chunks: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let text: String = chunks.iter().map(|chunk| chunk.text()).collect();
+    assert_eq!(text, "Hi there!");
+    assert_eq!(chunks[0].candidates[0].finish_reason, None);
+    assert_eq!(
+        chunks[1].candidates[0].finish_reason,
+        Some(FinishReason::Stop)
+    );
+}
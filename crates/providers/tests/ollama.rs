@@ -0,0 +1,4 @@
+mod ollama {
+    mod client;
+    mod streaming;
+}
@@ -0,0 +1,199 @@
+use ai_providers::{
+    ollama::types::{
+        KeepAlive, OllamaFormat, OllamaGenerateRequest, OllamaMessage, OllamaOptions, OllamaRole,
+        OllamaTool,
+    },
+    utils::errors::ApiErrorKind,
+    OllamaChatRequest, OllamaProvider, ProviderStrategy,
+};
+use serde_json::json;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn chat_json(message: serde_json::Value) -> serde_json::Value {
+    json!({
+        "model": "llama3.2",
+        "created_at": "2025-01-01T00:00:00Z",
+        "message": message,
+        "done": true,
+        "done_reason": "stop",
+        "total_duration": 5000000,
+        "prompt_eval_count": 26,
+        "eval_count": 12
+    })
+}
+
+#[tokio::test]
+async fn it_chats_without_streaming() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .and(body_partial_json(json!({
+            "model": "llama3.2",
+            "stream": false,
+            "format": { "type": "object" },
+            "options": { "temperature": 0.0, "num_ctx": 4096, "seed": 7 },
+            "keep_alive": -1
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_json(json!({
+            "role": "assistant",
+            "content": "{\"ok\":true}"
+        }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(format!("{}/", server.uri()))
+        .timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    let request = OllamaChatRequest::new("llama3.2", vec![OllamaMessage::user("Hello")])
+        .format(OllamaFormat::schema(json!({ "type": "object" })))
+        .options(OllamaOptions::new().temperature(0.0).num_ctx(4096).seed(7))
+        .keep_alive(KeepAlive::forever());
+    let response = provider.generate(&request).await.unwrap();
+
+    assert!(response.done);
+    assert_eq!(response.message.content, "{\"ok\":true}");
+    assert_eq!(response.metrics.prompt_eval_count, Some(26));
+    assert_eq!(response.metrics.eval_count, Some(12));
+}
+
+#[tokio::test]
+async fn it_returns_tool_calls() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .and(header("authorization", "Bearer proxy-key"))
+        .and(body_partial_json(json!({
+            "tools": [{ "type": "function", "function": { "name": "get_weather" } }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(chat_json(json!({
+            "role": "assistant",
+            "content": "",
+            "tool_calls": [{ "function": { "name": "get_weather", "arguments": { "city": "Paris" } } }]
+        }))))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .api_key("proxy-key")
+        .build()
+        .unwrap();
+
+    let request = OllamaChatRequest::new("llama3.2", vec![OllamaMessage::user("Weather?")])
+        .add_tool(OllamaTool::function(
+            "get_weather",
+            json!({ "type": "object", "properties": { "city": { "type": "string" } } }),
+        ));
+    let response = provider.generate(&request).await.unwrap();
+
+    let calls = response.message.tool_calls.unwrap();
+    assert_eq!(calls[0].function.name, "get_weather");
+    assert_eq!(calls[0].function.arguments, json!({ "city": "Paris" }));
+
+    let follow_up = request.add_message(OllamaMessage::tool("get_weather", "18°C"));
+    let body = serde_json::to_value(&follow_up).unwrap();
+    assert_eq!(body["messages"][1]["role"], json!(OllamaRole::Tool));
+    assert_eq!(body["messages"][1]["tool_name"], "get_weather");
+}
+
+#[tokio::test]
+async fn it_generates_completions_with_images() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/generate"))
+        .and(body_partial_json(json!({
+            "prompt": "Describe the image.",
+            "images": ["aGVsbG8="],
+            "stream": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "model": "llava",
+            "created_at": "2025-01-01T00:00:00Z",
+            "response": "A cat.",
+            "done": true,
+            "context": [1, 2, 3]
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = OllamaGenerateRequest::new("llava", "Describe the image.").add_image("aGVsbG8=");
+    let response = provider.complete(&request).await.unwrap();
+
+    assert_eq!(response.response, "A cat.");
+    assert_eq!(response.context, Some(vec![1, 2, 3]));
+}
+
+#[tokio::test]
+async fn it_lists_local_models() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/tags"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "models": [{
+                "name": "llama3.2:latest",
+                "model": "llama3.2:latest",
+                "modified_at": "2025-01-01T00:00:00Z",
+                "size": 2019393189u64,
+                "digest": "a80c4f17acd5",
+                "details": { "format": "gguf", "family": "llama", "parameter_size": "3.2B", "quantization_level": "Q4_K_M" }
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let models = provider.list_models().await.unwrap().models;
+
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0].name, "llama3.2:latest");
+    assert_eq!(models[0].details.parameter_size.as_deref(), Some("3.2B"));
+}
+
+#[tokio::test]
+async fn it_parses_error_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(
+                json!({ "error": "model \"missing\" not found, try pulling it first" }),
+            ),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = OllamaChatRequest::new("missing", vec![OllamaMessage::user("Hello")]);
+    let error = provider.generate(&request).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.kind, ApiErrorKind::NotFound);
+    assert_eq!(
+        api_error.message,
+        "model \"missing\" not found, try pulling it first"
+    );
+}
@@ -0,0 +1,128 @@
+use ai_providers::{
+    ollama::types::OllamaMessage, utils::errors::ProviderError, OllamaChatRequest, OllamaProvider,
+    ProviderStrategy,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn chunk(content: &str, done: bool) -> String {
+    let mut chunk = json!({
+        "model": "llama3.2",
+        "created_at": "2025-01-01T00:00:00Z",
+        "message": { "role": "assistant", "content": content },
+        "done": done
+    });
+
+    if done {
+        chunk["done_reason"] = json!("stop");
+        chunk["eval_count"] = json!(2);
+    }
+
+    format!("{}\n", chunk)
+}
+
+#[tokio::test]
+async fn it_streams_newline_delimited_chat_chunks() {
+    let server = MockServer::start().await;
+
+    let body = [chunk("Hi", false), chunk(" there!", false), chunk("", true)].concat();
+
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .and(body_partial_json(json!({ "stream": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/x-ndjson"))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = OllamaChatRequest::new("llama3.2", vec![OllamaMessage::user("Hello")]);
+    let chunks: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let text: String = chunks
+        .iter()
+        .map(|chunk| chunk.message.content.as_str())
+        .collect();
+    assert_eq!(text, "Hi there!");
+    assert!(chunks.last().unwrap().done);
+    assert_eq!(chunks.last().unwrap().metrics.eval_count, Some(2));
+}
+
+#[tokio::test]
+async fn it_surfaces_errors_written_into_the_stream() {
+    let server = MockServer::start().await;
+
+    let body = format!(
+        "{}{}\n",
+        chunk("Hi", false),
+        json!({ "error": "out of memory" })
+    );
+
+    Mock::given(method("POST"))
+        .and(path("/api/chat"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/x-ndjson"))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let request = OllamaChatRequest::new("llama3.2", vec![OllamaMessage::user("Hello")]);
+    let chunks: Vec<_> = provider.stream(&request).await.unwrap().collect().await;
+
+    assert!(chunks[0].is_ok());
+    assert!(matches!(&chunks[1], Err(ProviderError::Other(message)) if message == "out of memory"));
+}
+
+#[tokio::test]
+async fn it_reports_pull_progress() {
+    let server = MockServer::start().await;
+
+    let body = [
+        json!({ "status": "pulling manifest" }),
+        json!({ "status": "pulling a80c4f17acd5", "digest": "sha256:a80c4f17acd5", "total": 100, "completed": 50 }),
+        json!({ "status": "success" }),
+    ]
+    .iter()
+    .map(|line| format!("{}\n", line))
+    .collect::<String>();
+
+    Mock::given(method("POST"))
+        .and(path("/api/pull"))
+        .and(body_partial_json(
+            json!({ "model": "llama3.2", "stream": true }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "application/x-ndjson"))
+        .mount(&server)
+        .await;
+
+    let provider = OllamaProvider::builder()
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let progress: Vec<_> = provider
+        .pull_model("llama3.2")
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(progress.len(), 3);
+    assert_eq!(progress[1].completed, Some(50));
+    assert!(progress[2].is_success());
+}
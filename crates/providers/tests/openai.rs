@@ -1,4 +1,15 @@
 mod openai {
+    mod background;
+    mod client;
+    mod embeddings;
+    mod fixtures;
     mod generating;
+    mod images;
+    mod lifecycle;
+    mod moderation;
+    mod retry;
+    mod speech;
     mod streaming;
+    mod tool_executor;
+    mod transcription;
 }
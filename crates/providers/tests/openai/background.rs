@@ -0,0 +1,281 @@
+use super::fixtures::{provider, response_json};
+use ai_providers::{
+    openai::background::BackgroundOptions, openai::common::status::Status,
+    openai::constants::OpenAIModelId, openai::request::input::Input,
+    openai::response::events::streaming::OpenAIStreamingEvent, utils::errors::ProviderError,
+    OpenAIRequest,
+};
+use futures::StreamExt;
+use serde_json::{json, Value};
+use std::time::{Duration, Instant};
+use wiremock::matchers::{body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> OpenAIRequest {
+    OpenAIRequest::new(OpenAIModelId::O1Pro, Input::from_text("Prove it"))
+}
+
+fn response_with_status(status: &str) -> Value {
+    let mut response = response_json("Done.");
+    response["status"] = json!(status);
+    response["background"] = json!(true);
+    response
+}
+
+fn options() -> BackgroundOptions {
+    BackgroundOptions::new()
+        .poll_interval(Duration::from_millis(10))
+        .max_poll_interval(Duration::from_millis(20))
+}
+
+#[tokio::test]
+async fn it_polls_background_responses_until_they_finish() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .and(body_partial_json(json!({ "background": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("queued")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("in_progress")))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("completed")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = provider(&server)
+        .generate_background(&request(), &options())
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, Status::Completed);
+    assert_eq!(response.background, Some(true));
+}
+
+#[tokio::test]
+async fn it_cancels_responses_that_miss_the_deadline() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("in_progress")))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses/resp_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("cancelled")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .wait_for_response("resp_123", &options().deadline(Duration::from_millis(50)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ProviderError::Timeout(_)));
+}
+
+#[tokio::test]
+async fn it_stops_a_hanging_poll_at_the_deadline() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response_with_status("in_progress"))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses/resp_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("cancelled")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let error = provider(&server)
+        .wait_for_response("resp_123", &options().deadline(Duration::from_millis(50)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ProviderError::Timeout(_)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn it_cancels_during_a_hanging_poll() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response_with_status("in_progress"))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses/resp_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("cancelled")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let response = provider(&server)
+        .wait_for_response_until(
+            "resp_123",
+            &options(),
+            tokio::time::sleep(Duration::from_millis(30)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, Status::Cancelled);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn it_applies_the_deadline_to_the_submit() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(response_with_status("queued"))
+                .set_delay(Duration::from_secs(10)),
+        )
+        .mount(&server)
+        .await;
+
+    let started = Instant::now();
+    let error = provider(&server)
+        .generate_background(&request(), &options().deadline(Duration::from_millis(50)))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ProviderError::Timeout(_)));
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[tokio::test]
+async fn it_cancels_when_asked_to() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("in_progress")))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses/resp_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_with_status("cancelled")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = provider(&server)
+        .wait_for_response_until(
+            "resp_123",
+            &options(),
+            tokio::time::sleep(Duration::from_millis(30)),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status, Status::Cancelled);
+}
+
+#[tokio::test]
+async fn it_resumes_streams_after_a_sequence_number() {
+    let server = MockServer::start().await;
+
+    let body = [
+        json!({ "type": "response.output_text.delta", "sequence_number": 5, "item_id": "msg_123", "output_index": 0, "content_index": 0, "delta": " there" }),
+        json!({ "type": "response.output_text.delta", "sequence_number": 6, "item_id": "msg_123", "output_index": 0, "content_index": 0, "delta": "!" }),
+    ]
+    .iter()
+    .map(|event| format!("event: {}\ndata: {}\n\n", event["type"].as_str().unwrap(), event))
+    .collect::<String>();
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .and(query_param("stream", "true"))
+        .and(query_param("starting_after", "4"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let events: Vec<_> = provider(&server)
+        .resume_stream("resp_123", Some(4))
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let sequence_numbers: Vec<_> = events.iter().map(|event| event.sequence_number).collect();
+    let text: String = events
+        .iter()
+        .filter_map(|event| match &event.event {
+            OpenAIStreamingEvent::OutputTextDelta { delta, .. } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect();
+
+    assert_eq!(sequence_numbers, vec![5, 6]);
+    assert_eq!(text, " there!");
+}
+
+#[tokio::test]
+async fn it_starts_background_streams() {
+    let server = MockServer::start().await;
+
+    let body = format!(
+        "event: response.output_text.delta\ndata: {}\n\n",
+        json!({ "type": "response.output_text.delta", "sequence_number": 1, "item_id": "msg_123", "output_index": 0, "content_index": 0, "delta": "Hi" })
+    );
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .and(body_partial_json(
+            json!({ "background": true, "stream": true }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let events: Vec<_> = provider(&server)
+        .stream_background(&request())
+        .await
+        .unwrap()
+        .collect()
+        .await;
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].as_ref().unwrap().sequence_number, 1);
+}
@@ -0,0 +1,193 @@
+use super::fixtures::response_json;
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::request::input::Input,
+    openai::response::events::streaming::OpenAIStreamingEvent,
+    utils::errors::{ApiErrorKind, ProviderError},
+    OpenAIProvider, OpenAIRequest, ProviderStrategy,
+};
+use futures::StreamExt;
+use std::time::Duration;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> OpenAIRequest {
+    OpenAIRequest::new(OpenAIModelId::Gpt4OMini, Input::from_text("Hello"))
+}
+
+#[tokio::test]
+async fn it_sends_requests_to_the_configured_base_url_with_headers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/responses"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(header("openai-organization", "org_123"))
+        .and(header("openai-project", "proj_123"))
+        .and(header("x-gateway-route", "primary"))
+        .and(header("user-agent", "ai-sdk-tests/1.0"))
+        .and(body_partial_json(serde_json::json!({
+            "model": "gpt-4o-mini",
+            "input": "Hello"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(format!("{}/v1/", server.uri()))
+        .organization("org_123")
+        .project("proj_123")
+        .header("x-gateway-route", "primary")
+        .user_agent("ai-sdk-tests/1.0")
+        .timeout(Duration::from_secs(5))
+        .connect_timeout(Duration::from_secs(5))
+        .build()
+        .unwrap();
+
+    assert_eq!(provider.get_base_url(), format!("{}/v1", server.uri()));
+
+    let response = provider.generate(&request()).await.unwrap();
+    let response = serde_json::to_value(&response).unwrap();
+    assert_eq!(response["id"], "resp_123");
+}
+
+#[tokio::test]
+async fn it_reuses_a_shared_http_client() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let client = reqwest::Client::new();
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .http_client(client)
+        .build()
+        .unwrap();
+
+    let cloned = provider.clone();
+
+    provider.generate(&request()).await.unwrap();
+    cloned.generate(&request()).await.unwrap();
+}
+
+#[tokio::test]
+async fn it_rejects_invalid_header_values() {
+    let result = OpenAIProvider::builder("test-key")
+        .header("x-broken", "line\nbreak")
+        .build();
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn it_streams_events_from_the_configured_base_url() {
+    let server = MockServer::start().await;
+
+    let body = concat!(
+        "event: response.output_text.delta\n",
+        "data: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_123\",\"output_index\":0,\"content_index\":0,\"delta\":\"Hi\"}\n\n",
+        "event: response.output_text.delta\n",
+        "data: {\"type\":\"response.output_text.delta\",\"item_id\":\"msg_123\",\"output_index\":0,\"content_index\":0,\"delta\":\" there!\"}\n\n",
+    );
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .and(header("accept", "text/event-stream"))
+        .and(body_partial_json(serde_json::json!({ "stream": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let events: Vec<_> = provider.stream(&request()).await.unwrap().collect().await;
+
+    let text: String = events
+        .into_iter()
+        .map(|event| match event.unwrap() {
+            OpenAIStreamingEvent::OutputTextDelta { delta, .. } => delta,
+            other => panic!("unexpected event {:?}", other),
+        })
+        .collect();
+
+    assert_eq!(text, "Hi there!");
+}
+
+#[tokio::test]
+async fn it_parses_structured_api_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .insert_header("x-request-id", "req_abc")
+                .set_body_json(serde_json::json!({
+                    "error": {
+                        "message": "Incorrect API key provided.",
+                        "type": "invalid_request_error",
+                        "param": null,
+                        "code": "invalid_api_key"
+                    }
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let provider = OpenAIProvider::builder("wrong-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let error = provider.generate(&request()).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.status, 401);
+    assert_eq!(api_error.kind, ApiErrorKind::Authentication);
+    assert_eq!(api_error.message, "Incorrect API key provided.");
+    assert_eq!(
+        api_error.error_type.as_deref(),
+        Some("invalid_request_error")
+    );
+    assert_eq!(api_error.code.as_deref(), Some("invalid_api_key"));
+    assert_eq!(api_error.request_id.as_deref(), Some("req_abc"));
+}
+
+#[tokio::test]
+async fn it_validates_requests_before_sending_them() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    let invalid = request().temperature(3.0);
+    assert!(matches!(
+        provider.generate(&invalid).await.unwrap_err(),
+        ProviderError::ValidationError(_)
+    ));
+
+    let unsupported =
+        OpenAIRequest::new(OpenAIModelId::O4Mini, Input::from_text("Hello")).temperature(0.5);
+    assert!(matches!(
+        provider.stream(&unsupported).await.err().unwrap(),
+        ProviderError::CapabilityError(_)
+    ));
+}
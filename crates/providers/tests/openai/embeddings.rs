@@ -0,0 +1,133 @@
+use super::fixtures::provider;
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::embeddings::{EmbeddingInput, EmbeddingRequest, EncodingFormat},
+    utils::errors::ProviderError,
+};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn embeddings_json(embeddings: Vec<Value>, tokens: usize) -> Value {
+    let data: Vec<Value> = embeddings
+        .into_iter()
+        .enumerate()
+        .map(|(index, embedding)| {
+            json!({ "object": "embedding", "index": index, "embedding": embedding })
+        })
+        .collect();
+
+    json!({
+        "object": "list",
+        "data": data,
+        "model": "text-embedding-3-small",
+        "usage": { "prompt_tokens": tokens, "total_tokens": tokens }
+    })
+}
+
+#[tokio::test]
+async fn it_creates_embeddings() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_json(json!({
+            "input": "The food was delicious",
+            "model": "text-embedding-3-small",
+            "dimensions": 3,
+            "encoding_format": "float"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(embeddings_json(vec![json!([0.25, -0.5, 1.0])], 5)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request =
+        EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, "The food was delicious")
+            .dimensions(3)
+            .encoding_format(EncodingFormat::Float);
+
+    let response = provider(&server).embed(&request).await.unwrap();
+
+    assert_eq!(response.embeddings(), vec![&[0.25, -0.5, 1.0][..]]);
+    assert_eq!(response.usage.prompt_tokens, 5);
+}
+
+#[tokio::test]
+async fn it_decodes_base64_embeddings() {
+    let server = MockServer::start().await;
+
+    // [1.0, -2.0] as little-endian f32s.
+    Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_partial_json(json!({ "encoding_format": "base64" })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(embeddings_json(vec![json!("AACAPwAAAMA=")], 2)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, "hello")
+        .encoding_format(EncodingFormat::Base64);
+
+    let response = provider(&server).embed(&request).await.unwrap();
+
+    assert_eq!(response.data[0].embedding, vec![1.0, -2.0]);
+}
+
+#[tokio::test]
+async fn it_splits_large_inputs_into_batches() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_partial_json(json!({ "input": ["a", "b"] })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(embeddings_json(vec![json!([1.0]), json!([2.0])], 2)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/embeddings"))
+        .and(body_partial_json(json!({ "input": ["c"] })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(embeddings_json(vec![json!([3.0])], 1)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = EmbeddingRequest::new(OpenAIModelId::TextEmbedding3Small, vec!["a", "b", "c"])
+        .batch_size(2);
+
+    let response = provider(&server).embed(&request).await.unwrap();
+
+    assert_eq!(
+        response.embeddings(),
+        vec![&[1.0][..], &[2.0][..], &[3.0][..]]
+    );
+    assert_eq!(response.usage.total_tokens, 3);
+}
+
+#[tokio::test]
+async fn it_rejects_empty_input_without_a_request() {
+    let server = MockServer::start().await;
+
+    let request = EmbeddingRequest::new(
+        OpenAIModelId::TextEmbedding3Small,
+        EmbeddingInput::Texts(vec![]),
+    );
+
+    let error = provider(&server).embed(&request).await.unwrap_err();
+
+    assert!(matches!(error, ProviderError::ValidationError(_)));
+    assert!(server.received_requests().await.unwrap().is_empty());
+}
@@ -0,0 +1,76 @@
+use ai_providers::OpenAIProvider;
+use serde_json::{json, Value};
+use wiremock::MockServer;
+
+pub fn provider(server: &MockServer) -> OpenAIProvider {
+    OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap()
+}
+
+pub fn response_json(text: &str) -> Value {
+    response_with_output(
+        "resp_123",
+        json!([{
+            "id": "msg_123",
+            "type": "message",
+            "status": "completed",
+            "role": "assistant",
+            "content": [{ "type": "output_text", "text": text, "annotations": [] }]
+        }]),
+    )
+}
+
+pub fn function_call_json(id: &str, calls: &[(&str, &str, Value)]) -> Value {
+    response_with_output(
+        id,
+        calls
+            .iter()
+            .map(|(call_id, name, arguments)| {
+                json!({
+                    "type": "function_call",
+                    "id": format!("fc_{}", call_id),
+                    "call_id": call_id,
+                    "name": name,
+                    "arguments": arguments.to_string(),
+                    "status": "completed"
+                })
+            })
+            .collect(),
+    )
+}
+
+pub fn response_with_output(id: &str, output: Value) -> Value {
+    json!({
+        "id": id,
+        "object": "response",
+        "created_at": 1741290958,
+        "status": "completed",
+        "error": null,
+        "incomplete_details": null,
+        "instructions": null,
+        "max_output_tokens": null,
+        "model": "gpt-4o-mini-2024-07-18",
+        "output": output,
+        "parallel_tool_calls": true,
+        "previous_response_id": null,
+        "reasoning": { "effort": null, "summary": null },
+        "store": true,
+        "temperature": 1.0,
+        "text": { "format": { "type": "text" } },
+        "tool_choice": "auto",
+        "tools": [],
+        "top_p": 1.0,
+        "truncation": "disabled",
+        "usage": {
+            "input_tokens": 37,
+            "input_tokens_details": { "cached_tokens": 0 },
+            "output_tokens": 11,
+            "output_tokens_details": { "reasoning_tokens": 0 },
+            "total_tokens": 48
+        },
+        "user": null,
+        "metadata": {}
+    })
+}
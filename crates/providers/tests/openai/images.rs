@@ -0,0 +1,151 @@
+use super::fixtures::provider;
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::images::{
+        ImageBackground, ImageEditRequest, ImageGenerationRequest, ImageOutputFormat, ImageQuality,
+        ImageResponseFormat, ImageSize, ImageVariationRequest,
+    },
+    openai::upload::FileUpload,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_string_contains, header_regex, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+// "hello" base64 encoded.
+const IMAGE_B64: &str = "aGVsbG8=";
+
+#[tokio::test]
+async fn it_generates_images_and_saves_them() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/images/generations"))
+        .and(body_json(json!({
+            "prompt": "A red fox",
+            "model": "gpt-image-1",
+            "size": "1024x1024",
+            "quality": "low",
+            "background": "opaque",
+            "output_format": "jpeg",
+            "output_compression": 50
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "created": 1713833628,
+            "data": [{ "b64_json": IMAGE_B64 }],
+            "background": "opaque",
+            "output_format": "jpeg",
+            "quality": "low",
+            "size": "1024x1024",
+            "usage": {
+                "input_tokens": 50,
+                "input_tokens_details": { "text_tokens": 10, "image_tokens": 40 },
+                "output_tokens": 272,
+                "total_tokens": 322
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ImageGenerationRequest::new(OpenAIModelId::GptImage1, "A red fox")
+        .size(ImageSize::Size1024x1024)
+        .quality(ImageQuality::Low)
+        .background(ImageBackground::Opaque)
+        .output_format(ImageOutputFormat::Jpeg)
+        .output_compression(50);
+
+    let response = provider(&server).generate_image(&request).await.unwrap();
+
+    let usage = response.usage.unwrap();
+    assert_eq!(usage.total_tokens, 322);
+    assert_eq!(usage.input_tokens_details.image_tokens, 40);
+    assert_eq!(response.data[0].bytes().unwrap(), b"hello");
+
+    let file = std::env::temp_dir().join(format!("ai-providers-image-{}.jpeg", std::process::id()));
+    response.data[0].save(&file).await.unwrap();
+    assert_eq!(std::fs::read(&file).unwrap(), b"hello");
+    std::fs::remove_file(&file).unwrap();
+}
+
+#[tokio::test]
+async fn it_uploads_images_and_masks_for_edits() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/images/edits"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains(
+            "name=\"image[]\"; filename=\"room.png\"",
+        ))
+        .and(body_string_contains(
+            "name=\"image[]\"; filename=\"lamp.png\"",
+        ))
+        .and(body_string_contains("name=\"mask\"; filename=\"mask.png\""))
+        .and(body_string_contains("Content-Type: image/png"))
+        .and(body_string_contains(
+            "name=\"prompt\"\r\n\r\nAdd the lamp to the room",
+        ))
+        .and(body_string_contains("name=\"model\"\r\n\r\ngpt-image-1"))
+        .and(body_string_contains("name=\"n\"\r\n\r\n2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "created": 1713833628,
+            "data": [{ "b64_json": IMAGE_B64 }, { "b64_json": IMAGE_B64 }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ImageEditRequest::new(
+        OpenAIModelId::GptImage1,
+        FileUpload::new("room.png", b"room".to_vec()),
+        "Add the lamp to the room",
+    )
+    .add_image(FileUpload::new("lamp.png", b"lamp".to_vec()))
+    .mask(FileUpload::new("mask.png", b"mask".to_vec()))
+    .n(2);
+
+    let response = provider(&server).edit_image(&request).await.unwrap();
+
+    assert_eq!(response.data.len(), 2);
+    assert_eq!(response.usage, None);
+}
+
+#[tokio::test]
+async fn it_creates_image_variations() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/images/variations"))
+        .and(body_string_contains("name=\"image\"; filename=\"fox.png\""))
+        .and(body_string_contains("name=\"model\"\r\n\r\ndall-e-2"))
+        .and(body_string_contains("name=\"size\"\r\n\r\n256x256"))
+        .and(body_string_contains("name=\"response_format\"\r\n\r\nurl"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "created": 1713833628,
+            "data": [{ "url": "https://example.com/fox.png" }]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ImageVariationRequest::new(
+        OpenAIModelId::DallE2,
+        FileUpload::new("fox.png", b"fox".to_vec()),
+    )
+    .size(ImageSize::Size256x256)
+    .response_format(ImageResponseFormat::Url);
+
+    let response = provider(&server)
+        .create_image_variation(&request)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response.data[0].url.as_deref(),
+        Some("https://example.com/fox.png")
+    );
+    assert!(response.data[0].bytes().is_err());
+}
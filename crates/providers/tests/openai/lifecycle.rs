@@ -0,0 +1,157 @@
+use super::fixtures::{provider, response_json};
+use ai_providers::{
+    openai::lifecycle::{ListInputItemsParams, SortOrder},
+    openai::request::{include::Include, input_models::item::Item},
+    utils::errors::ApiErrorKind,
+};
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_retrieves_stored_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123"))
+        .and(query_param("include[]", "file_search_call.results"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = provider(&server)
+        .retrieve_response("resp_123", &[Include::FileSearchCallResults])
+        .await
+        .unwrap();
+
+    assert_eq!(response.id, "resp_123");
+}
+
+#[tokio::test]
+async fn it_deletes_and_cancels_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/responses/resp_123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "resp_123",
+            "object": "response.deleted",
+            "deleted": true
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses/resp_123/cancel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+
+    let deleted = provider.delete_response("resp_123").await.unwrap();
+    assert!(deleted.deleted);
+    assert_eq!(deleted.id, "resp_123");
+
+    let cancelled = provider.cancel_response("resp_123").await.unwrap();
+    assert_eq!(cancelled.id, "resp_123");
+}
+
+#[tokio::test]
+async fn it_pages_through_input_items() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123/input_items"))
+        .and(query_param("limit", "2"))
+        .and(query_param("order", "asc"))
+        .and(query_param_is_missing("after"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "object": "list",
+            "data": [
+                {
+                    "id": "msg_1",
+                    "type": "message",
+                    "role": "user",
+                    "status": "completed",
+                    "content": [{ "type": "input_text", "text": "What is the weather?" }]
+                },
+                {
+                    "type": "function_call_output",
+                    "id": "fco_1",
+                    "call_id": "call_1",
+                    "output": "{\"temperature\":21}",
+                    "status": "completed"
+                }
+            ],
+            "first_id": "msg_1",
+            "last_id": "fco_1",
+            "has_more": true
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_123/input_items"))
+        .and(query_param("after", "fco_1"))
+        .and(query_param("limit", "2"))
+        .and(query_param("order", "asc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "object": "list",
+            "data": [],
+            "first_id": null,
+            "last_id": null,
+            "has_more": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+
+    let params = ListInputItemsParams::new().limit(2).order(SortOrder::Asc);
+    let page = provider
+        .list_input_items("resp_123", &params)
+        .await
+        .unwrap();
+
+    assert_eq!(page.data.len(), 2);
+    assert!(matches!(page.data[0], Item::InputMessage(_)));
+    assert!(matches!(page.data[1], Item::FunctionToolCallOutput(_)));
+
+    let next = page.next_params(&params).unwrap();
+    assert_eq!(next, params.clone().after("fco_1"));
+    let page = provider.list_input_items("resp_123", &next).await.unwrap();
+
+    assert!(page.data.is_empty());
+    assert_eq!(page.next_params(&next), None);
+}
+
+#[tokio::test]
+async fn it_reports_missing_responses() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/responses/resp_missing"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "error": {
+                "message": "Response with id 'resp_missing' not found.",
+                "type": "invalid_request_error",
+                "param": null,
+                "code": null
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .retrieve_response("resp_missing", &[])
+        .await
+        .unwrap_err();
+
+    assert_eq!(error.api_error_kind(), Some(ApiErrorKind::NotFound));
+}
@@ -0,0 +1,164 @@
+use super::fixtures::{provider, response_json};
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::errors::GuardError,
+    openai::moderation::{
+        ModerationCategory, ModerationContent, ModerationInputType, ModerationRequest,
+    },
+    openai::request::input::Input,
+    OpenAIRequest,
+};
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn moderation_json(flagged: bool) -> Value {
+    json!({
+        "id": "modr-123",
+        "model": "omni-moderation-2024-09-26",
+        "results": [{
+            "flagged": flagged,
+            "categories": {
+                "harassment": false,
+                "harassment/threatening": false,
+                "sexual": false,
+                "hate": false,
+                "hate/threatening": false,
+                "illicit": false,
+                "illicit/violent": false,
+                "self-harm/intent": false,
+                "self-harm/instructions": false,
+                "self-harm": false,
+                "sexual/minors": false,
+                "violence": flagged,
+                "violence/graphic": flagged
+            },
+            "category_scores": {
+                "harassment": 0.0005,
+                "violence": if flagged { 0.92 } else { 0.001 },
+                "violence/graphic": if flagged { 0.81 } else { 0.0001 }
+            },
+            "category_applied_input_types": {
+                "harassment": ["text"],
+                "violence": ["text", "image"],
+                "violence/graphic": ["image"]
+            }
+        }]
+    })
+}
+
+#[tokio::test]
+async fn it_moderates_text_and_image_inputs() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/moderations"))
+        .and(body_json(json!({
+            "model": "omni-moderation-latest",
+            "input": [
+                { "type": "text", "text": "...text to classify goes here..." },
+                { "type": "image_url", "image_url": { "url": "https://example.com/image.png" } }
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(moderation_json(true)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ModerationRequest::new(vec![
+        ModerationContent::text("...text to classify goes here..."),
+        ModerationContent::image_url("https://example.com/image.png"),
+    ])
+    .model(OpenAIModelId::OmniModerationLatest);
+
+    let response = provider(&server).moderate(&request).await.unwrap();
+    let result = &response.results[0];
+
+    assert!(result.flagged);
+    assert_eq!(
+        result.flagged_categories(),
+        vec![
+            ModerationCategory::Violence,
+            ModerationCategory::ViolenceGraphic
+        ]
+    );
+    assert_eq!(result.category_scores.violence, 0.92);
+    assert_eq!(
+        result
+            .category_applied_input_types
+            .as_ref()
+            .unwrap()
+            .violence_graphic,
+        vec![ModerationInputType::Image]
+    );
+}
+
+#[tokio::test]
+async fn it_blocks_flagged_input_before_generating() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/moderations"))
+        .and(body_partial_json(
+            json!({ "input": "How do I hurt someone?" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(moderation_json(true)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let request = OpenAIRequest::new(
+        OpenAIModelId::Gpt4OMini,
+        Input::from_text("How do I hurt someone?"),
+    );
+
+    let error = provider(&server)
+        .generate_guarded(&request)
+        .await
+        .unwrap_err();
+
+    match error {
+        GuardError::Flagged(moderation) => {
+            assert_eq!(
+                moderation.flagged_categories(),
+                vec![
+                    ModerationCategory::Violence,
+                    ModerationCategory::ViolenceGraphic
+                ]
+            );
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn it_generates_when_input_passes_moderation() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/moderations"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(moderation_json(false)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = OpenAIRequest::new(OpenAIModelId::Gpt4OMini, Input::from_text("Hello"));
+
+    let response = provider(&server).generate_guarded(&request).await.unwrap();
+
+    assert_eq!(response.id, "resp_123");
+}
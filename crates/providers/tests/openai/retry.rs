@@ -0,0 +1,94 @@
+use super::fixtures::response_json;
+use ai_providers::{
+    openai::constants::OpenAIModelId, openai::request::input::Input, utils::errors::ProviderError,
+    OpenAIProvider, OpenAIRequest, ProviderStrategy, Retry, RetryPolicy,
+};
+use std::time::Duration;
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> OpenAIRequest {
+    OpenAIRequest::new(OpenAIModelId::Gpt4OMini, Input::from_text("Hello"))
+}
+
+async fn provider(server: &MockServer, policy: RetryPolicy) -> Retry<OpenAIProvider> {
+    let provider = OpenAIProvider::builder("test-key")
+        .base_url(server.uri())
+        .build()
+        .unwrap();
+
+    Retry::new(provider, policy)
+}
+
+fn fast_policy() -> RetryPolicy {
+    RetryPolicy::new()
+        .initial_backoff(Duration::from_millis(1))
+        .jitter(false)
+}
+
+#[tokio::test]
+async fn it_retries_rate_limited_requests_honoring_retry_after() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after-ms", "10"))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(response_json("Hi there!")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server, fast_policy()).await;
+
+    assert!(provider.generate(&request()).await.is_ok());
+}
+
+#[tokio::test]
+async fn it_reports_the_attempt_count_when_retries_run_out() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(503).set_body_string("overloaded"))
+        .expect(3)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server, fast_policy().max_attempts(3)).await;
+
+    match provider.generate(&request()).await {
+        Err(ProviderError::RetryError {
+            attempts,
+            last_error,
+        }) => {
+            assert_eq!(attempts, 3);
+            assert_eq!(last_error.api_error().unwrap().status, 503);
+        }
+        other => panic!("expected a retry error, got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn it_does_not_retry_invalid_requests() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/responses"))
+        .respond_with(ResponseTemplate::new(400).set_body_string("bad request"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server, fast_policy()).await;
+
+    let error = provider.generate(&request()).await.unwrap_err();
+    assert!(matches!(error, ProviderError::ApiError(_)));
+    assert_eq!(error.api_error().unwrap().status, 400);
+}
@@ -0,0 +1,105 @@
+use super::fixtures::provider;
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::speech::{write_audio, SpeechFormat, SpeechRequest, Voice},
+    utils::errors::ProviderError,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn mount_speech(server: &MockServer, audio: &[u8]) {
+    Mock::given(method("POST"))
+        .and(path("/audio/speech"))
+        .and(body_json(json!({
+            "model": "gpt-4o-mini-tts",
+            "input": "Today is a wonderful day.",
+            "voice": "coral",
+            "instructions": "Speak in a cheerful tone.",
+            "response_format": "wav"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_raw(audio.to_vec(), SpeechFormat::Wav.mime_type()),
+        )
+        .mount(server)
+        .await;
+}
+
+fn request() -> SpeechRequest {
+    SpeechRequest::new(
+        OpenAIModelId::Gpt4OMiniTts,
+        "Today is a wonderful day.",
+        Voice::Coral,
+    )
+    .instructions("Speak in a cheerful tone.")
+    .response_format(SpeechFormat::Wav)
+}
+
+#[tokio::test]
+async fn it_synthesizes_speech() {
+    let server = MockServer::start().await;
+    mount_speech(&server, b"RIFF....WAVE").await;
+
+    let audio = provider(&server).speech(&request()).await.unwrap();
+
+    assert_eq!(&audio[..], b"RIFF....WAVE");
+}
+
+#[tokio::test]
+async fn it_streams_speech_into_a_writer() {
+    let server = MockServer::start().await;
+    let audio: Vec<u8> = (0..64 * 1024).map(|i| (i % 251) as u8).collect();
+    mount_speech(&server, &audio).await;
+
+    let provider = provider(&server);
+
+    let mut streamed = vec![];
+    let mut stream = provider.speech_stream(&request()).await.unwrap();
+    while let Some(chunk) = stream.next().await {
+        streamed.extend_from_slice(&chunk.unwrap());
+    }
+    assert_eq!(streamed, audio);
+
+    let mut written = vec![];
+    let count = write_audio(
+        provider.speech_stream(&request()).await.unwrap(),
+        &mut written,
+    )
+    .await
+    .unwrap();
+    assert_eq!(count, audio.len() as u64);
+    assert_eq!(written, audio);
+}
+
+#[tokio::test]
+async fn it_returns_api_errors_for_speech() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/audio/speech"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": {
+                "message": "Invalid voice",
+                "type": "invalid_request_error",
+                "param": "voice",
+                "code": null
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let error = provider(&server)
+        .speech_stream(&request())
+        .await
+        .err()
+        .unwrap();
+
+    match error {
+        ProviderError::ApiError(error) => {
+            assert_eq!(error.status, 400);
+            assert_eq!(error.message, "Invalid voice");
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
@@ -1,4 +1,144 @@
+use super::fixtures::{response_json, response_with_output};
+use ai_providers::openai::response::accumulator::{collect_response, ResponseAccumulator};
+use ai_providers::openai::response::events::streaming::OpenAIStreamingEvent;
+use ai_providers::utils::sse::SseDecoder;
+use ai_providers::OpenAIResponse;
+use serde_json::json;
+
 #[test]
 fn test_it_works() {
     assert_eq!("test", "test");
 }
+
+const RESPONSE_STREAM: &str = r#"event: response.created
+data: {"type":"response.created","sequence_number":0,"response":{"id":"resp_123","object":"response","created_at":1741290958,"status":"in_progress","error":null,"incomplete_details":null,"instructions":null,"max_output_tokens":null,"model":"gpt-4o-mini-2024-07-18","output":[],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":null,"user":null,"metadata":{}}}
+
+event: response.output_item.added
+data: {"type":"response.output_item.added","sequence_number":1,"output_index":0,"item":{"id":"msg_123","type":"message","status":"in_progress","role":"assistant","content":[]}}
+
+event: response.content_part.added
+data: {"type":"response.content_part.added","sequence_number":2,"item_id":"msg_123","output_index":0,"content_index":0,"part":{"type":"output_text","text":"","annotations":[]}}
+
+event: response.output_text.delta
+data: {"type":"response.output_text.delta","sequence_number":3,"item_id":"msg_123","output_index":0,"content_index":0,"delta":"Hi"}
+
+event: response.output_text.delta
+data: {"type":"response.output_text.delta","sequence_number":4,"item_id":"msg_123","output_index":0,"content_index":0,"delta":" there!"}
+
+event: response.output_text.done
+data: {"type":"response.output_text.done","sequence_number":5,"item_id":"msg_123","output_index":0,"content_index":0,"text":"Hi there!"}
+
+event: response.completed
+data: {"type":"response.completed","sequence_number":6,"response":{"id":"resp_123","object":"response","created_at":1741290958,"status":"completed","error":null,"incomplete_details":null,"instructions":null,"max_output_tokens":null,"model":"gpt-4o-mini-2024-07-18","output":[{"id":"msg_123","type":"message","status":"completed","role":"assistant","content":[{"type":"output_text","text":"Hi there!","annotations":[]}]}],"parallel_tool_calls":true,"previous_response_id":null,"reasoning":{"effort":null,"summary":null},"store":true,"temperature":1.0,"text":{"format":{"type":"text"}},"tool_choice":"auto","tools":[],"top_p":1.0,"truncation":"disabled","usage":{"input_tokens":37,"input_tokens_details":{"cached_tokens":0},"output_tokens":11,"output_tokens_details":{"reasoning_tokens":0},"total_tokens":48},"user":null,"metadata":{}}}
+
+"#;
+
+#[test]
+fn it_decodes_a_chunked_response_stream() {
+    let mut decoder = SseDecoder::new();
+    let mut events = Vec::new();
+
+    // feed the transcript in small, arbitrarily aligned chunks
+    for chunk in RESPONSE_STREAM.as_bytes().chunks(7) {
+        events.extend(decoder.decode(chunk));
+    }
+    events.extend(decoder.finish());
+
+    let parsed: Vec<OpenAIStreamingEvent> = events
+        .iter()
+        .map(|event| serde_json::from_str(&event.data).unwrap())
+        .collect();
+
+    assert_eq!(parsed.len(), 7);
+    assert_eq!(events[0].event.as_deref(), Some("response.created"));
+
+    let deltas: String = parsed
+        .iter()
+        .filter_map(|event| match event {
+            OpenAIStreamingEvent::OutputTextDelta { delta, .. } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(deltas, "Hi there!");
+
+    match parsed.last().unwrap() {
+        OpenAIStreamingEvent::Completed { response } => {
+            assert_eq!(response.id, "resp_123");
+            assert_eq!(response.usage.as_ref().unwrap().total_tokens, 48);
+        }
+        other => panic!("expected a completed event, got {:?}", other),
+    }
+}
+
+fn decode(transcript: &str) -> Vec<OpenAIStreamingEvent> {
+    let mut decoder = SseDecoder::new();
+    let mut events = decoder.decode(transcript.as_bytes());
+    events.extend(decoder.finish());
+
+    events
+        .iter()
+        .map(|event| serde_json::from_str(&event.data).unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn it_collects_the_same_response_as_generate() {
+    let events = decode(RESPONSE_STREAM).into_iter().map(Ok);
+
+    let response = collect_response(futures::stream::iter(events))
+        .await
+        .unwrap();
+    let expected: OpenAIResponse = serde_json::from_value(response_json("Hi there!")).unwrap();
+
+    assert_eq!(response, expected);
+}
+
+#[test]
+fn it_accumulates_output_items_from_deltas() {
+    let mut completed = response_with_output("resp_123", json!([]));
+    completed["status"] = json!("completed");
+
+    let events: Vec<OpenAIStreamingEvent> = [
+        json!({ "type": "response.output_item.added", "output_index": 0, "item": { "type": "reasoning", "id": "rs_1", "summary": [] } }),
+        json!({ "type": "response.reasoning_summary_part.added", "item_id": "rs_1", "output_index": 0, "summary_index": 0, "part": { "type": "summary_text", "text": "" } }),
+        json!({ "type": "response.reasoning_summary_text.delta", "item_id": "rs_1", "output_index": 0, "summary_index": 0, "delta": "Looking up" }),
+        json!({ "type": "response.reasoning_summary_text.delta", "item_id": "rs_1", "output_index": 0, "summary_index": 0, "delta": " the weather." }),
+        json!({ "type": "response.output_item.added", "output_index": 1, "item": { "type": "message", "id": "msg_1", "status": "in_progress", "role": "assistant", "content": [] } }),
+        json!({ "type": "response.output_text.delta", "item_id": "msg_1", "output_index": 1, "content_index": 0, "delta": "See " }),
+        json!({ "type": "response.output_text.delta", "item_id": "msg_1", "output_index": 1, "content_index": 0, "delta": "the docs." }),
+        json!({ "type": "response.output_text.annotation.added", "item_id": "msg_1", "output_index": 1, "content_index": 0, "annotation_index": 0, "annotation": { "type": "file_citation", "file_id": "file_1", "index": 4 } }),
+        json!({ "type": "response.refusal.delta", "item_id": "msg_1", "output_index": 1, "content_index": 1, "delta": "No." }),
+        json!({ "type": "response.output_item.added", "output_index": 2, "item": { "type": "function_call", "id": "fc_1", "call_id": "call_1", "name": "get_weather", "arguments": "", "status": "in_progress" } }),
+        json!({ "type": "response.function_call_arguments.delta", "item_id": "fc_1", "output_index": 2, "delta": "{\"city\":" }),
+        json!({ "type": "response.function_call_arguments.delta", "item_id": "fc_1", "output_index": 2, "delta": "\"Paris\"}" }),
+    ]
+    .into_iter()
+    .map(|event| serde_json::from_value(event).unwrap())
+    .collect();
+
+    let mut accumulator = ResponseAccumulator::new();
+    for event in events {
+        accumulator.push(event);
+    }
+
+    let snapshot = serde_json::to_value(accumulator.output()).unwrap();
+    assert_eq!(snapshot[0]["summary"][0]["text"], "Looking up the weather.");
+    assert_eq!(snapshot[1]["content"][0]["text"], "See the docs.");
+    assert_eq!(
+        snapshot[1]["content"][0]["annotations"][0]["file_id"],
+        "file_1"
+    );
+    assert_eq!(snapshot[1]["content"][1]["refusal"], "No.");
+    assert_eq!(snapshot[2]["arguments"], "{\"city\":\"Paris\"}");
+    assert!(!accumulator.is_done());
+
+    accumulator.push(
+        serde_json::from_value(json!({ "type": "response.completed", "response": completed }))
+            .unwrap(),
+    );
+
+    let response = accumulator.finish().unwrap();
+    assert_eq!(response.output.len(), 3);
+    assert_eq!(response.usage.total_tokens, 48);
+    assert_eq!(serde_json::to_value(&response.output).unwrap(), snapshot);
+}
@@ -0,0 +1,178 @@
+use super::fixtures::{function_call_json, provider, response_json, response_with_output};
+use ai_providers::{
+    openai::constants::OpenAIModelId, openai::request::input::Input, Continuation, OpenAIRequest,
+    ToolExecutor,
+};
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn request() -> OpenAIRequest {
+    OpenAIRequest::new(
+        OpenAIModelId::Gpt4OMini,
+        Input::from_text("What's the weather?"),
+    )
+}
+
+fn executor() -> ToolExecutor {
+    ToolExecutor::new().register("get_weather", |arguments: Value| async move {
+        Ok(json!({ "city": arguments["city"], "temperature": 21 }))
+    })
+}
+
+async fn mount_sequence(server: &MockServer, responses: Vec<Value>) {
+    let count = responses.len();
+
+    for (index, response) in responses.into_iter().enumerate() {
+        let mock = Mock::given(method("POST"))
+            .and(path("/responses"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(response));
+
+        if index + 1 < count {
+            mock.up_to_n_times(1).mount(server).await;
+        } else {
+            mock.mount(server).await;
+        }
+    }
+}
+
+async fn request_bodies(server: &MockServer) -> Vec<Value> {
+    server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|request| request.body_json().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn it_runs_tools_until_the_model_answers() {
+    let server = MockServer::start().await;
+    mount_sequence(
+        &server,
+        vec![
+            function_call_json(
+                "resp_1",
+                &[
+                    ("call_1", "get_weather", json!({ "city": "Paris" })),
+                    ("call_2", "get_weather", json!({ "city": "Rome" })),
+                ],
+            ),
+            response_json("Sunny in both."),
+        ],
+    )
+    .await;
+
+    let run = executor().run(&provider(&server), request()).await.unwrap();
+
+    assert_eq!(run.steps.len(), 2);
+    assert_eq!(run.steps[0].calls.len(), 2);
+    assert_eq!(
+        run.steps[0].outputs[1].output,
+        json!({ "city": "Rome", "temperature": 21 }).to_string()
+    );
+    assert_eq!(run.final_response().unwrap().id, "resp_123");
+    assert!(!run.is_truncated());
+
+    let bodies = request_bodies(&server).await;
+    let input = bodies[1]["input"].as_array().unwrap();
+    assert_eq!(input.len(), 5);
+    assert_eq!(input[0]["content"], "What's the weather?");
+    assert_eq!(input[1]["type"], "function_call");
+    assert_eq!(input[3]["type"], "function_call_output");
+    assert_eq!(input[3]["call_id"], "call_1");
+    assert!(bodies[1].get("previous_response_id").is_none());
+}
+
+#[tokio::test]
+async fn it_sends_reasoning_items_back_with_the_calls() {
+    let server = MockServer::start().await;
+    let reasoning = json!({
+        "type": "reasoning",
+        "id": "rs_1",
+        "summary": [{ "type": "summary_text", "text": "Look up the weather." }],
+        "encrypted_content": "gAAAA"
+    });
+    let call = json!({
+        "type": "function_call",
+        "id": "fc_1",
+        "call_id": "call_1",
+        "name": "get_weather",
+        "arguments": json!({ "city": "Paris" }).to_string(),
+        "status": "completed"
+    });
+    mount_sequence(
+        &server,
+        vec![
+            response_with_output("resp_1", json!([reasoning, call])),
+            response_json("Sunny."),
+        ],
+    )
+    .await;
+
+    executor().run(&provider(&server), request()).await.unwrap();
+
+    let bodies = request_bodies(&server).await;
+    let input = bodies[1]["input"].as_array().unwrap();
+    assert_eq!(input.len(), 4);
+    assert_eq!(input[1], reasoning);
+    assert_eq!(input[2], call);
+    assert_eq!(input[3]["type"], "function_call_output");
+}
+
+#[tokio::test]
+async fn it_chains_onto_the_previous_response() {
+    let server = MockServer::start().await;
+    mount_sequence(
+        &server,
+        vec![
+            function_call_json(
+                "resp_1",
+                &[("call_1", "get_weather", json!({ "city": "Paris" }))],
+            ),
+            response_json("Sunny."),
+        ],
+    )
+    .await;
+
+    executor()
+        .continuation(Continuation::PreviousResponseId)
+        .run(&provider(&server), request())
+        .await
+        .unwrap();
+
+    let bodies = request_bodies(&server).await;
+    assert_eq!(bodies[1]["previous_response_id"], "resp_1");
+    assert_eq!(
+        bodies[1]["input"],
+        json!([{
+            "call_id": "call_1",
+            "output": json!({ "city": "Paris", "temperature": 21 }).to_string(),
+            "type": "function_call_output"
+        }])
+    );
+}
+
+#[tokio::test]
+async fn it_stops_after_max_steps() {
+    let server = MockServer::start().await;
+    mount_sequence(
+        &server,
+        vec![function_call_json(
+            "resp_1",
+            &[("call_1", "get_weather", json!({ "city": "Paris" }))],
+        )],
+    )
+    .await;
+
+    let run = executor()
+        .max_steps(3)
+        .run(&provider(&server), request())
+        .await
+        .unwrap();
+
+    assert_eq!(run.steps.len(), 3);
+    assert!(run.is_truncated());
+    assert_eq!(request_bodies(&server).await.len(), 3);
+}
@@ -0,0 +1,221 @@
+use super::fixtures::provider;
+use ai_providers::{
+    openai::constants::OpenAIModelId,
+    openai::transcription::{
+        TimestampGranularity, TranscriptionFormat, TranscriptionRequest, TranscriptionResponse,
+        TranscriptionStreamEvent, TranscriptionUsage, TranslationRequest,
+    },
+    openai::upload::FileUpload,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+#[tokio::test]
+async fn it_transcribes_files_from_a_path() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/audio/transcriptions"))
+        .and(body_string_contains(
+            "name=\"file\"; filename=\"ai-providers-speech.mp3\"\r\nContent-Type: audio/mpeg",
+        ))
+        .and(body_string_contains(
+            "name=\"model\"\r\n\r\ngpt-4o-transcribe",
+        ))
+        .and(body_string_contains("name=\"language\"\r\n\r\nen"))
+        .and(body_string_contains(
+            "name=\"prompt\"\r\n\r\nA podcast about Rust.",
+        ))
+        .and(body_string_contains("name=\"include[]\"\r\n\r\nlogprobs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "text": "Hello",
+            "logprobs": [{ "token": "Hello", "logprob": -0.01, "bytes": [72, 101, 108, 108, 111] }],
+            "usage": {
+                "type": "tokens",
+                "input_tokens": 14,
+                "input_token_details": { "text_tokens": 0, "audio_tokens": 14 },
+                "output_tokens": 45,
+                "total_tokens": 59
+            }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("ai-providers-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("ai-providers-speech.mp3");
+    std::fs::write(&file, b"ID3").unwrap();
+
+    let request = TranscriptionRequest::new(
+        OpenAIModelId::Gpt4OTranscribe,
+        FileUpload::from_path(&file).await.unwrap(),
+    )
+    .language("en")
+    .prompt("A podcast about Rust.")
+    .logprobs(true);
+
+    let response = provider(&server).transcribe(&request).await.unwrap();
+    std::fs::remove_dir_all(&dir).unwrap();
+
+    match response {
+        TranscriptionResponse::Json(transcription) => {
+            assert_eq!(transcription.text, "Hello");
+            assert_eq!(transcription.logprobs.unwrap()[0].bytes, b"Hello");
+            assert!(matches!(
+                transcription.usage,
+                Some(TranscriptionUsage::Tokens {
+                    total_tokens: 59,
+                    ..
+                })
+            ));
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn it_returns_word_and_segment_timestamps() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/audio/transcriptions"))
+        .and(body_string_contains(
+            "name=\"response_format\"\r\n\r\nverbose_json",
+        ))
+        .and(body_string_contains(
+            "name=\"timestamp_granularities[]\"\r\n\r\nword",
+        ))
+        .and(body_string_contains(
+            "name=\"timestamp_granularities[]\"\r\n\r\nsegment",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "task": "transcribe",
+            "language": "english",
+            "duration": 1.5,
+            "text": "Hello there",
+            "words": [
+                { "word": "Hello", "start": 0.0, "end": 0.6 },
+                { "word": "there", "start": 0.7, "end": 1.2 }
+            ],
+            "segments": [{
+                "id": 0,
+                "seek": 0,
+                "start": 0.0,
+                "end": 1.5,
+                "text": " Hello there",
+                "tokens": [50364, 2425, 456],
+                "temperature": 0.0,
+                "avg_logprob": -0.28,
+                "compression_ratio": 0.8,
+                "no_speech_prob": 0.01
+            }],
+            "usage": { "type": "duration", "seconds": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let file = FileUpload::from_reader("hello.wav", &b"RIFF....WAVE"[..])
+        .await
+        .unwrap();
+    let request = TranscriptionRequest::new(OpenAIModelId::Whisper1, file)
+        .response_format(TranscriptionFormat::VerboseJson)
+        .add_timestamp_granularity(TimestampGranularity::Word)
+        .add_timestamp_granularity(TimestampGranularity::Segment);
+
+    let response = provider(&server).transcribe(&request).await.unwrap();
+
+    match response {
+        TranscriptionResponse::Verbose(transcription) => {
+            let words = transcription.words.unwrap();
+            assert_eq!(words[1].word, "there");
+            assert_eq!(words[1].end, 1.2);
+            assert_eq!(transcription.segments.unwrap()[0].tokens.len(), 3);
+            assert_eq!(
+                transcription.usage,
+                Some(TranscriptionUsage::Duration { seconds: 2.0 })
+            );
+        }
+        other => panic!("unexpected response: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn it_translates_audio_into_subtitles() {
+    let server = MockServer::start().await;
+    let subtitles = "WEBVTT\n\n00:00:00.000 --> 00:00:01.500\nHello there\n";
+
+    Mock::given(method("POST"))
+        .and(path("/audio/translations"))
+        .and(body_string_contains("name=\"model\"\r\n\r\nwhisper-1"))
+        .and(body_string_contains("name=\"response_format\"\r\n\r\nvtt"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(subtitles, "text/vtt"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = TranslationRequest::new(
+        OpenAIModelId::Whisper1,
+        FileUpload::new("hallo.mp3", b"ID3".to_vec()),
+    )
+    .response_format(TranscriptionFormat::Vtt);
+
+    let response = provider(&server).translate(&request).await.unwrap();
+
+    assert_eq!(response, TranscriptionResponse::Text(subtitles.to_string()));
+}
+
+#[tokio::test]
+async fn it_streams_transcription_events() {
+    let server = MockServer::start().await;
+
+    let body = [
+        json!({ "type": "transcript.text.delta", "delta": "Hello" }),
+        json!({ "type": "transcript.text.delta", "delta": " there" }),
+        json!({
+            "type": "transcript.text.done",
+            "text": "Hello there",
+            "usage": { "type": "tokens", "input_tokens": 14, "output_tokens": 2, "total_tokens": 16 }
+        }),
+    ]
+    .iter()
+    .map(|event| format!("data: {}\n\n", event))
+    .collect::<String>();
+
+    Mock::given(method("POST"))
+        .and(path("/audio/transcriptions"))
+        .and(body_string_contains("name=\"stream\"\r\n\r\ntrue"))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = TranscriptionRequest::new(
+        OpenAIModelId::Gpt4OMiniTranscribe,
+        FileUpload::new("hello.mp3", b"ID3".to_vec()),
+    );
+
+    let events: Vec<_> = provider(&server)
+        .transcribe_stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    let text: String = events
+        .iter()
+        .filter_map(|event| match event {
+            TranscriptionStreamEvent::Delta { delta, .. } => Some(delta.as_str()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(text, "Hello there");
+    assert!(matches!(
+        events.last(),
+        Some(TranscriptionStreamEvent::Done { text, .. }) if text == "Hello there"
+    ));
+}
@@ -0,0 +1,4 @@
+mod openai_compatible {
+    mod client;
+    mod streaming;
+}
@@ -0,0 +1,211 @@
+use ai_providers::{
+    openai_compatible::client::AuthScheme,
+    openai_compatible::quirks::Quirks,
+    openai_compatible::response::ChatFinishReason,
+    openai_compatible::types::{ChatMessage, ChatResponseFormat, ChatTool},
+    utils::errors::{ApiErrorKind, ProviderError},
+    ChatCompletionRequest, OpenAICompatibleProvider, ProviderStrategy,
+};
+use serde_json::json;
+use wiremock::matchers::{body_json, body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn completion_json(message: serde_json::Value, finish_reason: &str) -> serde_json::Value {
+    json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion",
+        "created": 1700000000,
+        "model": "llama-3.1-8b",
+        "choices": [{
+            "index": 0,
+            "message": message,
+            "finish_reason": finish_reason,
+            "logprobs": {
+                "content": [{ "token": "Hi", "logprob": -0.1, "top_logprobs": [{ "token": "Hi", "logprob": -0.1 }] }]
+            }
+        }],
+        "usage": { "prompt_tokens": 9, "completion_tokens": 1, "total_tokens": 10 }
+    })
+}
+
+#[tokio::test]
+async fn it_sends_chat_completions_with_bearer_auth() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer test-key"))
+        .and(body_json(json!({
+            "model": "llama-3.1-8b",
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "logprobs": true,
+            "top_logprobs": 1,
+            "seed": 42,
+            "n": 1
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion_json(
+            json!({ "role": "assistant", "content": "Hi" }),
+            "stop",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OpenAICompatibleProvider::builder(format!("{}/v1/", server.uri()))
+        .api_key("test-key")
+        .build()
+        .unwrap();
+
+    let request = ChatCompletionRequest::new("llama-3.1-8b", vec![ChatMessage::user("Hello")])
+        .logprobs(Some(1))
+        .seed(42)
+        .n(1);
+    let response = provider.generate(&request).await.unwrap();
+
+    assert_eq!(response.text(), Some("Hi"));
+    assert_eq!(
+        response.choices[0].finish_reason,
+        Some(ChatFinishReason::Stop)
+    );
+    assert_eq!(
+        response.choices[0]
+            .logprobs
+            .as_ref()
+            .unwrap()
+            .content
+            .as_ref()
+            .unwrap()[0]
+            .top_logprobs
+            .len(),
+        1
+    );
+    assert_eq!(response.usage.unwrap().total_tokens, 10);
+}
+
+#[tokio::test]
+async fn it_applies_quirks_and_custom_auth_headers() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(header("api-key", "test-key"))
+        .and(body_json(json!({
+            "model": "local",
+            "messages": [{ "role": "user", "content": "Hello" }],
+            "max_tokens": 64,
+            "response_format": { "type": "json_object" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion_json(
+            json!({ "role": "assistant", "content": "{}" }),
+            "stop",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = OpenAICompatibleProvider::builder(server.uri())
+        .api_key("test-key")
+        .auth_scheme(AuthScheme::Header("api-key".to_string()))
+        .quirks(Quirks::minimal())
+        .build()
+        .unwrap();
+
+    let request = ChatCompletionRequest::new("local", vec![ChatMessage::user("Hello")])
+        .max_completion_tokens(64)
+        .parallel_tool_calls(false)
+        .response_format(ChatResponseFormat::json_schema(
+            "answer",
+            json!({ "type": "object" }),
+        ));
+    provider.generate(&request).await.unwrap();
+}
+
+#[test]
+fn it_keeps_invalid_api_keys_out_of_errors() {
+    for scheme in [
+        AuthScheme::Bearer,
+        AuthScheme::Header("api-key".to_string()),
+    ] {
+        let error = OpenAICompatibleProvider::builder("http://localhost:8080")
+            .api_key("secret-key\n")
+            .auth_scheme(scheme)
+            .build()
+            .unwrap_err();
+
+        match error {
+            ProviderError::ValidationError(message) => {
+                assert_eq!(message, "API key is not a valid header value")
+            }
+            other => panic!("unexpected error: {:?}", other),
+        }
+    }
+}
+
+#[tokio::test]
+async fn it_returns_tool_calls() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({
+            "tools": [{ "type": "function", "function": { "name": "get_weather" } }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completion_json(
+            json!({
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{
+                    "id": "call_1",
+                    "type": "function",
+                    "function": { "name": "get_weather", "arguments": "{\"city\":\"Paris\"}" }
+                }]
+            }),
+            "tool_calls",
+        )))
+        .mount(&server)
+        .await;
+
+    let provider = OpenAICompatibleProvider::builder(server.uri())
+        .build()
+        .unwrap();
+
+    let request =
+        ChatCompletionRequest::new("local", vec![ChatMessage::user("Weather?")]).add_tool(
+            ChatTool::function("get_weather", json!({ "type": "object" })),
+        );
+    let response = provider.generate(&request).await.unwrap();
+
+    let choice = &response.choices[0];
+    assert_eq!(choice.finish_reason, Some(ChatFinishReason::ToolCalls));
+    let calls = choice.message.tool_calls.as_ref().unwrap();
+    assert_eq!(calls[0].function.name, "get_weather");
+    assert_eq!(calls[0].function.arguments, "{\"city\":\"Paris\"}");
+}
+
+#[tokio::test]
+async fn it_parses_api_errors() {
+    let server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .respond_with(ResponseTemplate::new(429).insert_header("retry-after", "3").set_body_json(json!({
+            "error": { "message": "Rate limit reached", "type": "rate_limit_exceeded", "code": "rate_limit_exceeded" }
+        })))
+        .mount(&server)
+        .await;
+
+    let provider = OpenAICompatibleProvider::builder(server.uri())
+        .build()
+        .unwrap();
+
+    let request = ChatCompletionRequest::new("local", vec![ChatMessage::user("Hello")]);
+    let error = provider.generate(&request).await.unwrap_err();
+    let api_error = error.api_error().unwrap();
+
+    assert_eq!(api_error.kind, ApiErrorKind::RateLimit);
+    assert_eq!(api_error.message, "Rate limit reached");
+    assert_eq!(
+        api_error.retry_after,
+        Some(std::time::Duration::from_secs(3))
+    );
+}
@@ -0,0 +1,82 @@
+use ai_providers::{
+    openai_compatible::response::ChatFinishReason, openai_compatible::types::ChatMessage,
+    ChatCompletionRequest, OpenAICompatibleProvider, ProviderStrategy,
+};
+use futures::StreamExt;
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn chunk(choices: serde_json::Value, usage: serde_json::Value) -> String {
+    let chunk = json!({
+        "id": "chatcmpl-123",
+        "object": "chat.completion.chunk",
+        "created": 1700000000,
+        "model": "llama-3.1-8b",
+        "choices": choices,
+        "usage": usage
+    });
+
+    format!("data: {}\n\n", chunk)
+}
+
+#[tokio::test]
+async fn it_streams_chunks_with_usage() {
+    let server = MockServer::start().await;
+
+    let body = [
+        chunk(json!([{ "index": 0, "delta": { "role": "assistant", "content": "Hi" } }]), json!(null)),
+        chunk(
+            json!([{ "index": 0, "delta": { "tool_calls": [{ "index": 0, "id": "call_1", "type": "function", "function": { "name": "get_weather", "arguments": "" } }] } }]),
+            json!(null),
+        ),
+        chunk(
+            json!([{ "index": 0, "delta": { "tool_calls": [{ "index": 0, "function": { "arguments": "{\"city\":\"Paris\"}" } }] } }]),
+            json!(null),
+        ),
+        chunk(json!([{ "index": 0, "delta": {}, "finish_reason": "tool_calls" }]), json!(null)),
+        chunk(json!([]), json!({ "prompt_tokens": 9, "completion_tokens": 5, "total_tokens": 14 })),
+        "data: [DONE]\n\n".to_string(),
+    ]
+    .concat();
+
+    Mock::given(method("POST"))
+        .and(path("/chat/completions"))
+        .and(body_partial_json(json!({
+            "stream": true,
+            "stream_options": { "include_usage": true }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_raw(body, "text/event-stream"))
+        .mount(&server)
+        .await;
+
+    let provider = OpenAICompatibleProvider::builder(server.uri())
+        .build()
+        .unwrap();
+
+    let request = ChatCompletionRequest::new("llama-3.1-8b", vec![ChatMessage::user("Hello")]);
+    let chunks: Vec<_> = provider
+        .stream(&request)
+        .await
+        .unwrap()
+        .map(Result::unwrap)
+        .collect()
+        .await;
+
+    assert_eq!(chunks.len(), 5);
+    assert_eq!(chunks[0].choices[0].delta.content.as_deref(), Some("Hi"));
+
+    let arguments: String = chunks
+        .iter()
+        .flat_map(|chunk| &chunk.choices)
+        .flat_map(|choice| choice.delta.tool_calls.iter().flatten())
+        .filter_map(|call| call.function.as_ref()?.arguments.as_deref())
+        .collect();
+    assert_eq!(arguments, "{\"city\":\"Paris\"}");
+
+    assert_eq!(
+        chunks[3].choices[0].finish_reason,
+        Some(ChatFinishReason::ToolCalls)
+    );
+    assert_eq!(chunks[4].usage.as_ref().unwrap().total_tokens, 14);
+}